@@ -0,0 +1,59 @@
+//! Compares `utils::mean_ic_by_key_length` with building the interleaved
+//! columns separately for each key length, on 20,000 pseudo-random letters.
+//! Run with `cargo bench --bench mean_ic`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use cipher_solver::{utils, LowercaseString};
+
+const MAX_KEY_LEN: usize = 40;
+const LEN: usize = 20_000;
+const RUNS: u32 = 20;
+
+/// Averages the index of coincidence of freshly built columns.
+#[allow(clippy::cast_precision_loss)]
+fn naive_mean_ic(text: &LowercaseString, key_len: usize) -> f64 {
+    let columns = text.columns(key_len);
+    columns
+        .iter()
+        .map(LowercaseString::index_of_coincidence)
+        .sum::<f64>()
+        / key_len as f64
+}
+
+fn main() {
+    // A xorshift generator, so the text is the same on every run.
+    let mut state = 0x5eed_u64;
+    let letters = (0..LEN)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            u8::try_from(state % 26).unwrap()
+        })
+        .collect();
+    let text = LowercaseString::from_indices(letters);
+
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        black_box(utils::mean_ic_by_key_length(&text, 1..=MAX_KEY_LEN));
+    }
+    let shared_time = start.elapsed() / RUNS;
+
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        for key_len in 1..=MAX_KEY_LEN {
+            black_box(naive_mean_ic(&text, key_len));
+        }
+    }
+    let naive_time = start.elapsed() / RUNS;
+
+    let shared = utils::mean_ic_by_key_length(&text, 1..=MAX_KEY_LEN);
+    for key_len in 1..=MAX_KEY_LEN {
+        assert!((shared[&key_len] - naive_mean_ic(&text, key_len)).abs() < 1e-12);
+    }
+    println!("{LEN} letters, key lengths 1 to {MAX_KEY_LEN}");
+    println!("shared counts:      {shared_time:?}");
+    println!("columns per length: {naive_time:?}");
+}
@@ -0,0 +1,66 @@
+//! Compares `LowercaseString::repeated_substrings` with the naive approach of
+//! comparing every pair of positions, on 20,000 pseudo-random letters. Run
+//! with `cargo bench --bench repeated_substrings`.
+
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::time::Instant;
+
+use cipher_solver::LowercaseString;
+
+const MIN_LEN: usize = 3;
+const LEN: usize = 20_000;
+
+/// Finds the same repeats by extending the common prefix of every pair of
+/// positions, in `O(n²)` time.
+fn naive_repeated_substrings(text: &[u8], min_len: usize) -> HashMap<Vec<u8>, Vec<usize>> {
+    let mut repeats: HashMap<Vec<u8>, Vec<usize>> = HashMap::new();
+    for i in 0..text.len() {
+        for j in i + 1..text.len() {
+            let common = text[i..]
+                .iter()
+                .zip(&text[j..])
+                .take_while(|(a, b)| a == b)
+                .count();
+            for len in min_len..=common {
+                let positions = repeats.entry(text[i..i + len].to_vec()).or_default();
+                for start in [i, j] {
+                    if !positions.contains(&start) {
+                        positions.push(start);
+                    }
+                }
+            }
+        }
+    }
+    for positions in repeats.values_mut() {
+        positions.sort_unstable();
+    }
+    repeats
+}
+
+fn main() {
+    // A xorshift generator, so the text is the same on every run.
+    let mut state = 0x5eed_u64;
+    let letters = (0..LEN)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            u8::try_from(state % 26).unwrap()
+        })
+        .collect();
+    let text = LowercaseString::from_indices(letters);
+
+    let start = Instant::now();
+    let fast = black_box(text.repeated_substrings(MIN_LEN));
+    let fast_time = start.elapsed();
+
+    let start = Instant::now();
+    let naive = black_box(naive_repeated_substrings(text.to_indices(), MIN_LEN));
+    let naive_time = start.elapsed();
+
+    assert_eq!(fast, naive);
+    println!("{LEN} letters, {} repeated substrings", fast.len());
+    println!("rolling hash: {fast_time:?}");
+    println!("naive:        {naive_time:?}");
+}
@@ -0,0 +1,207 @@
+use std::cmp::Ordering;
+
+use crate::lowercase_string::LowercaseString;
+use crate::substitution::SubstitutionKey;
+use crate::utils;
+
+/// The multipliers with an inverse modulo 26.
+const VALID_A: [u8; 12] = [1, 3, 5, 7, 9, 11, 15, 17, 19, 21, 23, 25];
+
+/// Scores are rounded to a multiple of this before ranking, so that scores
+/// differing only by floating-point error count as tied.
+const SCORE_EPSILON: f64 = 1e-9;
+
+/// Builds the substitution key mapping each letter `x` to `a * x + b`
+/// (mod 26), or `None` unless `a` is coprime with 26.
+fn substitution_key(a: u8, b: u8) -> Option<SubstitutionKey> {
+    let alphabet = (0..26u32)
+        .map(|x| ((x * u32::from(a) + u32::from(b)) % 26) as u8)
+        .collect();
+    SubstitutionKey::from_alphabet(&LowercaseString::from_indices(alphabet))
+}
+
+/// Encrypts a message using an affine cipher, mapping each letter `x` to
+/// `a * x + b` (mod 26). Returns `None` unless `a` is coprime with 26.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::affine;
+///
+/// let encrypted = affine::encrypt("affine cipher", 5, 8).unwrap();
+/// assert_eq!(encrypted, "ihhwvcswfrcp");
+/// assert_eq!(affine::encrypt("affine cipher", 2, 8), None);
+/// ```
+#[must_use]
+pub fn encrypt(text: &str, a: u8, b: u8) -> Option<String> {
+    let key = substitution_key(a, b)?;
+    Some(
+        LowercaseString::normalize(text)
+            .apply_substitution(&key)
+            .to_string(),
+    )
+}
+
+/// Decrypts a message using an affine cipher with the given key.
+/// Returns `None` unless `a` is coprime with 26.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::affine;
+///
+/// let decrypted = affine::decrypt("ihhwvcswfrcp", 5, 8).unwrap();
+/// assert_eq!(decrypted, "affinecipher");
+/// ```
+#[must_use]
+pub fn decrypt(text: &str, a: u8, b: u8) -> Option<String> {
+    let key = substitution_key(a, b)?;
+    Some(
+        LowercaseString::normalize(text)
+            .apply_substitution_inverse(&key)
+            .to_string(),
+    )
+}
+
+/// Decrypts an affine cipher with all 312 valid keys, returning each
+/// plaintext with its key `(a, b)` and chi-squared score, best first.
+/// Scores are compared after rounding to a multiple of 1e-9, and
+/// candidates whose rounded scores are equal are ordered by `a` and then
+/// `b`. Rounding keeps the order total, unlike comparing with a tolerance,
+/// but two scores just either side of a rounding boundary are not tied.
+///
+/// # Example
+/// ```
+/// use cipher_solver::affine;
+///
+/// let text = "The quick brown fox jumps over the lazy dog";
+/// let encrypted = affine::encrypt(text, 7, 3).unwrap();
+/// let candidates = affine::solve_ranked(&encrypted);
+/// assert_eq!(candidates.len(), 312);
+/// let (plaintext, a, b, _) = &candidates[0];
+/// assert_eq!((*a, *b), (7, 3));
+/// assert_eq!(plaintext, "thequickbrownfoxjumpsoverthelazydog");
+/// ```
+#[must_use]
+pub fn solve_ranked(text: &str) -> Vec<(String, u8, u8, f64)> {
+    let text = LowercaseString::normalize(text);
+    let mut candidates: Vec<_> = VALID_A
+        .iter()
+        .flat_map(|&a| (0..26).map(move |b| (a, b)))
+        .map(|(a, b)| {
+            let decrypted = text.apply_substitution_inverse(&substitution_key(a, b).unwrap());
+            let score = utils::chi_squared_english_score(&decrypted);
+            (decrypted.to_string(), a, b, score)
+        })
+        .collect();
+    candidates.sort_by(|(_, a1, b1, score1), (_, a2, b2, score2)| {
+        compare_candidates((*score1, *a1, *b1), (*score2, *a2, *b2))
+    });
+    candidates
+}
+
+/// Orders `(score, a, b)` candidates by score rounded to [`SCORE_EPSILON`],
+/// and then by `a` and `b`.
+fn compare_candidates(first: (f64, u8, u8), second: (f64, u8, u8)) -> Ordering {
+    let rounded = |score: f64| (score / SCORE_EPSILON).round();
+    rounded(first.0)
+        .total_cmp(&rounded(second.0))
+        .then_with(|| (first.1, first.2).cmp(&(second.1, second.2)))
+}
+
+/// Solves an affine cipher using statistical analysis, returning the
+/// plaintext and the key `(a, b)`.
+///
+/// # Example
+/// ```
+/// use cipher_solver::affine;
+///
+/// let text = "The quick brown fox jumps over the lazy dog";
+/// let encrypted = affine::encrypt(text, 11, 20).unwrap();
+/// let (solved, a, b) = affine::solve(&encrypted);
+/// assert_eq!(solved, "thequickbrownfoxjumpsoverthelazydog");
+/// assert_eq!((a, b), (11, 20));
+/// ```
+#[must_use]
+pub fn solve(text: &str) -> (String, u8, u8) {
+    let (plaintext, a, b, _) = solve_ranked(text).swap_remove(0);
+    (plaintext, a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::OZYMANDIAS;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        for &a in &VALID_A {
+            let encrypted = encrypt(OZYMANDIAS, a, 17).unwrap();
+            assert_eq!(decrypt(&encrypted, a, 17).unwrap(), normalized);
+        }
+        assert_eq!(encrypt(OZYMANDIAS, 1, 0).unwrap(), normalized);
+        assert_eq!(encrypt(OZYMANDIAS, 13, 1), None);
+        assert_eq!(decrypt(OZYMANDIAS, 0, 1), None);
+    }
+
+    #[test]
+    fn test_solve_ranked() {
+        let encrypted = encrypt(OZYMANDIAS, 19, 4).unwrap();
+        let candidates = solve_ranked(&encrypted);
+        assert_eq!(candidates.len(), 312);
+        assert_eq!(
+            candidates[0],
+            (
+                LowercaseString::normalize(OZYMANDIAS).to_string(),
+                19,
+                4,
+                candidates[0].3
+            )
+        );
+        assert!(candidates
+            .windows(2)
+            .all(|pair| pair[0].3 <= pair[1].3 + SCORE_EPSILON));
+    }
+
+    #[test]
+    fn test_solve_ranked_ties() {
+        // Every key decrypts an empty text to the same score.
+        let candidates = solve_ranked("");
+        assert_eq!((candidates[0].1, candidates[0].2), (1, 0));
+        assert_eq!((candidates[1].1, candidates[1].2), (1, 1));
+        assert_eq!((candidates[311].1, candidates[311].2), (25, 25));
+
+        // 0.1 + 0.2 is not exactly 0.3, but the scores are tied.
+        assert_eq!(
+            compare_candidates((0.1 + 0.2, 3, 0), (0.3, 1, 5)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            compare_candidates((0.3, 3, 0), (0.3 + 1e-6, 5, 0)),
+            Ordering::Less
+        );
+    }
+}
+
+#[cfg(test)]
+mod prop_roundtrip {
+    use super::*;
+    use crate::test_data::random_text;
+    use crate::utils::Rng;
+
+    #[test]
+    fn encrypt_decrypt() {
+        let mut rng = Rng::new(15);
+        for _ in 0..1000 {
+            let plain = random_text(&mut rng, 50);
+            let a = VALID_A[rng.below(VALID_A.len())];
+            let b = u8::try_from(rng.below(256)).unwrap();
+            let encrypted = encrypt(&plain, a, b).unwrap();
+            assert_eq!(
+                decrypt(&encrypted, a, b),
+                Some(LowercaseString::normalize(&plain).to_string())
+            );
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use crate::lowercase_string::LowercaseString;
+use crate::ngram::NgramModel;
+
+/// The longest text for which [`solve_mixed`] tries every assignment.
+const MAX_EXHAUSTIVE_LEN: usize = 16;
+
+/// The number of candidates [`solve_mixed`] returns for short texts.
+const MIXED_CANDIDATES: usize = 10;
+
+fn reverse(letter: u8) -> u8 {
+    25 - letter
+}
+
+/// Encrypts a message using the Atbash cipher, which replaces each letter
+/// with the one the same distance from the other end of the alphabet.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::atbash;
+///
+/// assert_eq!(atbash::encrypt("hello world"), "svooldliow");
+/// ```
+#[must_use]
+pub fn encrypt(text: &str) -> String {
+    LowercaseString::from_indices(
+        LowercaseString::normalize(text)
+            .to_indices()
+            .iter()
+            .map(|&letter| reverse(letter))
+            .collect(),
+    )
+    .to_string()
+}
+
+/// Decrypts a message using the Atbash cipher. The cipher is reciprocal, so
+/// this is the same operation as encryption.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::atbash;
+///
+/// assert_eq!(atbash::decrypt("svooldliow"), "helloworld");
+/// ```
+#[must_use]
+pub fn decrypt(text: &str) -> String {
+    encrypt(text)
+}
+
+/// Returns the letter at a position, reversed if `reversed` is set.
+fn letter(text: &[u8], position: usize, reversed: bool) -> u8 {
+    if reversed {
+        reverse(text[position])
+    } else {
+        text[position]
+    }
+}
+
+/// Finds the choice of reversed or unchanged letter at each position that
+/// maximises the total log probability of the result's n-grams.
+fn best_assignment(text: &[u8], table: &[f64], n: usize) -> Vec<bool> {
+    // Each state is the choices for the last n - 1 letters, in the low bits
+    // with the latest lowest.
+    let states = 1 << (n - 1);
+    let ngram = |end: usize, choices: usize| {
+        (0..n).fold(0, |index, i| {
+            let position = end + 1 - n + i;
+            index * 26 + usize::from(letter(text, position, choices >> (n - 1 - i) & 1 == 1))
+        })
+    };
+    let mut best = vec![0.0; states];
+    let mut previous = Vec::with_capacity(text.len());
+    for end in n - 1..text.len() {
+        let mut next = vec![f64::NEG_INFINITY; states];
+        let mut from = vec![0; states];
+        for (state, &score) in best.iter().enumerate() {
+            for choice in 0..2 {
+                let choices = state << 1 | choice;
+                let score = score + table[ngram(end, choices)];
+                let state_next = choices & (states - 1);
+                if score > next[state_next] {
+                    next[state_next] = score;
+                    from[state_next] = state;
+                }
+            }
+        }
+        best = next;
+        previous.push(from);
+    }
+
+    let mut state = (0..states)
+        .max_by(|&a, &b| best[a].total_cmp(&best[b]))
+        .unwrap();
+    let mut assignment = vec![false; text.len()];
+    for i in 0..n - 1 {
+        assignment[text.len() - 1 - i] = state >> i & 1 == 1;
+    }
+    for (end, from) in (n - 1..text.len()).zip(previous).rev() {
+        state = from[state];
+        assignment[end - (n - 1)] = state >> (n - 2) & 1 == 1;
+    }
+    assignment
+}
+
+/// Solves a text where only some letters were encrypted with Atbash, as in
+/// some CTF challenges. Each letter is either kept or reversed, and the
+/// results are ranked by the total log probability of their quadgrams under
+/// [`NgramModel::english_quadgrams`], best first.
+///
+/// Texts of up to 16 letters have every assignment tried, and the best ten
+/// are returned. Longer texts have too many assignments, so only the best
+/// one is returned, found by dynamic programming over the positions. Short
+/// texts are often ambiguous, so the intended plaintext may not be first.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::atbash;
+///
+/// let candidates = atbash::solve_mixed("oolponnydlrkh");
+/// assert!(candidates.contains(&"lookonmyworks".to_string()));
+/// ```
+#[must_use]
+pub fn solve_mixed(text: &str) -> Vec<String> {
+    let text = LowercaseString::normalize(text);
+    let text = text.to_indices();
+    let model = NgramModel::english_quadgrams();
+    let table = model.log_prob_table();
+    let apply = |assignment: &dyn Fn(usize) -> bool| {
+        LowercaseString::from_indices(
+            (0..text.len())
+                .map(|position| letter(text, position, assignment(position)))
+                .collect(),
+        )
+    };
+
+    if text.len() > MAX_EXHAUSTIVE_LEN {
+        let assignment = best_assignment(text, &table, model.n());
+        return vec![apply(&|position| assignment[position]).to_string()];
+    }
+
+    let mut candidates: Vec<_> = (0..1u32 << text.len())
+        .map(|mask| {
+            let candidate = apply(&|position| mask >> position & 1 == 1);
+            let log_prob: f64 = candidate
+                .to_indices()
+                .windows(model.n())
+                .map(|ngram| {
+                    table[ngram
+                        .iter()
+                        .fold(0, |index, &letter| index * 26 + usize::from(letter))]
+                })
+                .sum();
+            (log_prob, candidate)
+        })
+        .collect();
+    // Ties, common where a letter is in no quadgram, go alphabetically.
+    candidates.sort_by(|(score1, candidate1), (score2, candidate2)| {
+        score2
+            .total_cmp(score1)
+            .then_with(|| candidate1.to_indices().cmp(candidate2.to_indices()))
+    });
+    candidates
+        .into_iter()
+        .take(MIXED_CANDIDATES)
+        .map(|(_, candidate)| candidate.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::OZYMANDIAS;
+    use crate::utils::Rng;
+
+    /// Reverses the letters of a text selected by `mask`.
+    fn mix(text: &str, mask: impl Fn(usize) -> bool) -> String {
+        let text = LowercaseString::normalize(text);
+        LowercaseString::from_indices(
+            (0..text.to_indices().len())
+                .map(|position| letter(text.to_indices(), position, mask(position)))
+                .collect(),
+        )
+        .to_string()
+    }
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        assert_eq!(decrypt(&encrypt(OZYMANDIAS)), normalized);
+        assert_eq!(encrypt("abcxyz"), "zyxcba");
+    }
+
+    #[test]
+    fn test_solve_mixed_short() {
+        let mixed = mix("hidden treasure", |position| position % 3 == 1);
+        let candidates = solve_mixed(&mixed);
+        assert_eq!(candidates.len(), MIXED_CANDIDATES);
+        assert!(candidates.contains(&"hiddentreasure".to_string()));
+        // Every assignment of the same letters gives the same candidates.
+        assert_eq!(solve_mixed(&encrypt(&mixed)), candidates);
+
+        assert_eq!(solve_mixed("ab").len(), 4);
+        assert_eq!(solve_mixed(""), [""]);
+    }
+
+    #[test]
+    fn test_solve_mixed_long() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        let mut rng = Rng::new(187);
+        let mask: Vec<_> = (0..normalized.len()).map(|_| rng.below(2) == 1).collect();
+        let mixed = mix(&normalized, |position| mask[position]);
+
+        let candidates = solve_mixed(&mixed);
+        assert_eq!(candidates.len(), 1);
+        let correct = candidates[0]
+            .bytes()
+            .zip(normalized.bytes())
+            .filter(|(a, b)| a == b)
+            .count();
+        // Common letters pair up under Atbash (e and v, n and m, o and l), so
+        // some are always ambiguous.
+        assert!(correct * 100 > normalized.len() * 85);
+    }
+}
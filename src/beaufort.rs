@@ -0,0 +1,144 @@
+use crate::lowercase_string::LowercaseString;
+use crate::{utils, vigenere};
+
+/// Subtracts each letter from its key letter, which both encrypts and
+/// decrypts.
+fn apply(text: &LowercaseString, key: &[u8]) -> LowercaseString {
+    LowercaseString::from_indices(
+        text.to_indices()
+            .iter()
+            .zip(key.iter().cycle())
+            .map(|(&c, &k)| (26 + k - c) % 26)
+            .collect(),
+    )
+}
+
+/// Encrypts a message using a Beaufort cipher with a given keyword, where
+/// each ciphertext letter is the key letter minus the plaintext letter
+/// (mod 26). Returns `None` if the keyword has no letters.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::beaufort;
+///
+/// let encrypted = beaufort::encrypt("hello world", "key").unwrap();
+/// assert_eq!(encrypted, "danzqcwnnh");
+/// ```
+#[must_use]
+pub fn encrypt(text: &str, keyword: &str) -> Option<String> {
+    let keyword = LowercaseString::normalize(keyword);
+    if keyword.to_indices().is_empty() {
+        return None;
+    }
+    Some(apply(&LowercaseString::normalize(text), keyword.to_indices()).to_string())
+}
+
+/// Decrypts a message using a Beaufort cipher with a given keyword. The
+/// cipher is reciprocal, so this is the same operation as encryption.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::beaufort;
+///
+/// let decrypted = beaufort::decrypt("danzqcwnnh", "key").unwrap();
+/// assert_eq!(decrypted, "helloworld");
+/// ```
+#[must_use]
+pub fn decrypt(text: &str, keyword: &str) -> Option<String> {
+    encrypt(text, keyword)
+}
+
+/// Finds the key letter `k` that makes `k - column` most English-like.
+fn best_key_letter(column: &LowercaseString) -> u8 {
+    (0..26)
+        .map(|key| {
+            (
+                key,
+                utils::chi_squared_english_score(&apply(column, &[key])),
+            )
+        })
+        .min_by(|(_, score1), (_, score2)| score1.total_cmp(score2))
+        .map(|(key, _)| key)
+        .unwrap()
+}
+
+/// Solves a Beaufort cipher using statistical analysis, trying key lengths
+/// from 1 to `max_key_len`. Returns the plaintext and the key, or `None` if
+/// the text has fewer than twice `max_key_len` letters.
+///
+/// The key length is chosen by index of coincidence, as for Vigenère, since
+/// each column is still a single substitution. Each column is not a Caesar
+/// shift of the plaintext, though, but one of its reverse: the key letter
+/// is found by scoring `k - column` with [`utils::chi_squared_english_score`]
+/// for every `k`, rather than by shifting the column.
+///
+/// # Example
+/// ```
+/// use cipher_solver::beaufort;
+///
+/// let text = "I met a traveller from an antique land, who said: Two vast and \
+///     trunkless legs of stone stand in the desert. Near them, on the sand, \
+///     half sunk a shattered visage lies, whose frown, and wrinkled lip, and \
+///     sneer of cold command, tell that its sculptor well those passions read";
+/// let encrypted = beaufort::encrypt(text, "key").unwrap();
+/// let (_, key) = beaufort::solve(&encrypted, 10).unwrap();
+/// assert_eq!(key, "key");
+/// ```
+#[must_use]
+pub fn solve(text: &str, max_key_len: usize) -> Option<(String, String)> {
+    let text = LowercaseString::normalize(text);
+    if max_key_len == 0 || text.to_indices().len() < 2 * max_key_len {
+        return None;
+    }
+
+    let profile = utils::ic_profile(&text, max_key_len);
+    let (key_len, _) = vigenere::likely_key_lengths(&profile).next()?;
+    let key: Vec<_> = text.columns(key_len).iter().map(best_key_letter).collect();
+    let plaintext = apply(&text, &key).to_string();
+    Some((plaintext, LowercaseString::from_indices(key).to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::OZYMANDIAS;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let original = "The quick brown fox jumps over the lazy dog";
+        let normalized = LowercaseString::normalize(original);
+        let encrypted = encrypt(original, "secret").unwrap();
+        let decrypted = decrypt(&encrypted, "secret");
+        assert_eq!(decrypted, Some(normalized.to_string()));
+
+        assert_eq!(encrypt(original, ""), None);
+        assert_eq!(decrypt(original, "123"), None);
+    }
+
+    #[test]
+    fn test_differs_from_vigenere() {
+        let text = "The quick brown fox jumps over the lazy dog";
+        let encrypted = encrypt(text, "secret").unwrap();
+        assert_ne!(Some(encrypted.clone()), vigenere::encrypt(text, "secret"));
+        assert_ne!(Some(encrypted), vigenere::decrypt(text, "secret"));
+    }
+
+    #[test]
+    fn test_solve() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        let plaintext = &normalized[..200];
+        let encrypted = encrypt(plaintext, "crown").unwrap();
+        let (solved, key) = solve(&encrypted, 10).unwrap();
+        assert_eq!(solved, plaintext);
+        assert_eq!(key, "crown");
+    }
+
+    #[test]
+    fn test_solve_too_short() {
+        let encrypted = encrypt("The quick brown fox", "key").unwrap();
+        assert_eq!(solve(&encrypted, 10), None);
+        assert_eq!(solve(&encrypted, 0), None);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::lowercase_string::LowercaseString;
+use crate::{utils, vigenere};
+
+/// Maps a letter through the reciprocal alphabet selected by a key letter.
+///
+/// Key letters select alphabets in pairs (a/b, c/d, ...). Each alphabet
+/// swaps the first half of the alphabet with the second half, shifted by
+/// one more place for every pair, so applying it twice gives the original
+/// letter back.
+fn reciprocal(letter: u8, key: u8) -> u8 {
+    let row = key / 2;
+    if letter < 13 {
+        13 + (letter + row) % 13
+    } else {
+        (letter - row) % 13
+    }
+}
+
+fn apply(text: &str, keyword: &str) -> Option<String> {
+    let text = LowercaseString::normalize(text);
+    let keyword = LowercaseString::normalize(keyword);
+    let key_indices = keyword.to_indices();
+
+    if key_indices.is_empty() {
+        return None;
+    }
+
+    Some(
+        LowercaseString::from_indices(
+            text.to_indices()
+                .iter()
+                .zip(key_indices.iter().cycle())
+                .map(|(&c, &k)| reciprocal(c, k))
+                .collect(),
+        )
+        .to_string(),
+    )
+}
+
+/// Encrypts a message using Bellaso's 1553 cipher with a given keyword.
+/// Each keyword letter selects one of 13 reciprocal alphabets from Bellaso's
+/// table, extended here to the 26-letter alphabet.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::bellaso;
+///
+/// let encrypted = bellaso::encrypt("hello world", "key").unwrap();
+/// assert_eq!(encrypted, "ztxqmkjcxv");
+/// ```
+#[must_use]
+pub fn encrypt(text: &str, keyword: &str) -> Option<String> {
+    apply(text, keyword)
+}
+
+/// Decrypts a message using Bellaso's 1553 cipher with a given keyword.
+/// The cipher is reciprocal, so this is the same operation as encryption.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::bellaso;
+///
+/// let decrypted = bellaso::decrypt("ztxqmkjcxv", "key").unwrap();
+/// assert_eq!(decrypted, "helloworld");
+/// ```
+#[must_use]
+pub fn decrypt(text: &str, keyword: &str) -> Option<String> {
+    apply(text, keyword)
+}
+
+/// Finds the key letter whose alphabet makes a column most English-like.
+/// Only the first letter of each pair is returned, as both select the same
+/// alphabet.
+fn best_key_letter(column: &LowercaseString) -> u8 {
+    (0..26)
+        .step_by(2)
+        .map(|key| {
+            let decrypted = LowercaseString::from_indices(
+                column
+                    .to_indices()
+                    .iter()
+                    .map(|&c| reciprocal(c, key))
+                    .collect(),
+            );
+            (key, utils::chi_squared_english_score(&decrypted))
+        })
+        .min_by(|(_, score1), (_, score2)| score1.total_cmp(score2))
+        .map(|(key, _)| key)
+        .unwrap()
+}
+
+/// Solves a Bellaso (Porta) cipher using statistical analysis, trying key
+/// lengths from 1 to `max_key_len`. Also available as `porta::solve`.
+/// The key length is chosen by index of coincidence and each key letter by
+/// [`utils::chi_squared_english_score`]. Returns the plaintext and the key,
+/// using the first letter of each key letter pair, or `None` if the text
+/// has fewer than twice `max_key_len` letters.
+///
+/// The digraphic part of the Porta tableau is its key letter pairs (a/b,
+/// c/d, ...), each selecting one alphabet, so every key position is tested
+/// with the 13 pairs rather than all 26 letters. The columns themselves are
+/// single letters, one for each key position: every alphabet maps letters
+/// one at a time, so a letter's decryption never depends on its neighbour
+/// and digraphs would add nothing to the choice of pair. For the same reason
+/// an odd-length text needs no trimming, and its last letter is decrypted
+/// like the others.
+///
+/// # Example
+/// ```
+/// use cipher_solver::bellaso;
+///
+/// let text = "I met a traveller from an antique land, who said: Two vast and \
+///     trunkless legs of stone stand in the desert. Near them, on the sand, \
+///     half sunk a shattered visage lies, whose frown, and wrinkled lip, and \
+///     sneer of cold command, tell that its sculptor well those passions read";
+/// let encrypted = bellaso::encrypt(text, "key").unwrap();
+/// let (_, key) = bellaso::solve(&encrypted, 10).unwrap();
+/// assert_eq!(key, "key");
+/// ```
+#[must_use]
+pub fn solve(text: &str, max_key_len: usize) -> Option<(String, String)> {
+    let text = LowercaseString::normalize(text);
+    if max_key_len == 0 || text.to_indices().len() < 2 * max_key_len {
+        return None;
+    }
+
+    let profile = utils::ic_profile(&text, max_key_len);
+    let (key_len, _) = vigenere::likely_key_lengths(&profile).next()?;
+    let key =
+        LowercaseString::from_indices(text.columns(key_len).iter().map(best_key_letter).collect())
+            .to_string();
+    let plaintext = decrypt(&text.to_string(), &key)?;
+    Some((plaintext, key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::OZYMANDIAS;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let original = "The quick brown fox jumps over the lazy dog";
+        let normalized = LowercaseString::normalize(original);
+        let encrypted = encrypt(original, "secret").unwrap();
+        let decrypted = decrypt(&encrypted, "secret");
+        assert_eq!(decrypted, Some(normalized.to_string()));
+
+        assert_eq!(encrypt(original, ""), None);
+        assert_eq!(decrypt(original, ""), None);
+    }
+
+    #[test]
+    fn test_regression_vector() {
+        // Produced by this implementation: the 26-letter extension of
+        // Bellaso's table has no published test vectors.
+        let encrypted = encrypt("Defend the east wall of the castle", "fortification");
+        assert_eq!(encrypted, Some("synnjscvrnrlahutukucvryrlany".to_string()));
+    }
+
+    #[test]
+    fn test_key_letter_pairs() {
+        let text = "The quick brown fox jumps over the lazy dog";
+        assert_eq!(encrypt(text, "a"), encrypt(text, "b"));
+        assert_eq!(encrypt(text, "yz"), encrypt(text, "zy"));
+        assert_ne!(encrypt(text, "a"), encrypt(text, "c"));
+    }
+
+    #[test]
+    fn test_differs_from_vigenere() {
+        let text = "The quick brown fox jumps over the lazy dog";
+        assert_ne!(encrypt(text, "secret"), vigenere::encrypt(text, "secret"));
+    }
+
+    #[test]
+    fn test_solve() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        for len in [200, 199] {
+            let plaintext = &normalized[..len];
+            let encrypted = encrypt(plaintext, "cipher").unwrap();
+            let (solved, key) = solve(&encrypted, 10).unwrap();
+            assert_eq!(solved, plaintext);
+            assert_eq!(key, "ciogeq");
+            assert_eq!(
+                crate::porta::solve(&encrypted, 10),
+                Some((solved, key.clone()))
+            );
+            assert_eq!(encrypt(plaintext, &key).unwrap(), encrypted);
+        }
+    }
+
+    #[test]
+    fn test_solve_too_short() {
+        let encrypted = encrypt("The quick brown fox", "key").unwrap();
+        assert_eq!(solve(&encrypted, 10), None);
+        assert_eq!(solve(&encrypted, 0), None);
+    }
+}
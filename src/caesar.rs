@@ -1,5 +1,27 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
 use crate::lowercase_string::LowercaseString;
-use crate::utils;
+use crate::substitution::SubstitutionKey;
+use crate::utils::{self, Language};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CaesarError {
+    /// The alphabet does not contain every letter exactly once.
+    InvalidAlphabet,
+}
+
+impl Display for CaesarError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidAlphabet => {
+                write!(f, "alphabet must contain every letter exactly once")
+            }
+        }
+    }
+}
+
+impl Error for CaesarError {}
 
 /// Solves a Caesar cipher using statistical analysis.
 ///
@@ -14,17 +36,147 @@ use crate::utils;
 /// ```
 #[must_use]
 pub fn solve(text: &str) -> String {
+    let text = LowercaseString::normalize(text);
+    text.caesar_shift(text.caesar_shift_to_match_english())
+        .to_string()
+}
+
+/// Solves a Caesar cipher whose plaintext is in the given language,
+/// returning the plaintext and the shift.
+///
+/// # Example
+/// ```
+/// use cipher_solver::caesar;
+/// use cipher_solver::utils::Language;
+///
+/// let text = "Le vieil homme regardait la mer depuis le quai";
+/// let encrypted = caesar::encrypt(text, 3);
+/// let (solved, shift) = caesar::solve_with_language(&encrypted, Language::French);
+/// assert_eq!(solved, "levieilhommeregardaitlamerdepuislequai");
+/// assert_eq!(shift, 3);
+/// ```
+#[must_use]
+pub fn solve_with_language(text: &str, lang: Language) -> (String, i32) {
+    let text = LowercaseString::normalize(text);
+    (0..26)
+        .map(|shift| {
+            let shifted = text.caesar_shift(-shift);
+            let score = utils::chi_squared(&shifted.letter_frequencies(), lang.frequencies());
+            (score, shifted, shift)
+        })
+        .min_by(|(score1, ..), (score2, ..)| score1.total_cmp(score2))
+        .map(|(_, text, shift)| (text.to_string(), shift))
+        .unwrap()
+}
+
+/// Finds the most likely shift of each of the `n_cols` interleaved columns
+/// of a text, as in a Vigenère ciphertext with a known key length.
+///
+/// # Example
+/// ```
+/// use cipher_solver::{caesar, vigenere, LowercaseString};
+///
+/// let text = "I met a traveller from an antique land, who said: Two vast and \
+///     trunkless legs of stone stand in the desert. Near them, on the sand, \
+///     half sunk a shattered visage lies, whose frown";
+/// let encrypted = LowercaseString::normalize(&vigenere::encrypt(text, "dog").unwrap());
+/// assert_eq!(caesar::solve_per_column(&encrypted, 3), [3, 14, 6]);
+/// ```
+#[must_use]
+pub fn solve_per_column(text: &LowercaseString, n_cols: usize) -> Vec<i32> {
+    solve_per_column_with_scores(text, n_cols)
+        .into_iter()
+        .map(|(shift, _)| shift)
+        .collect()
+}
+
+/// Like [`solve_per_column`], also returning the chi-squared score of each
+/// decrypted column.
+#[must_use]
+pub fn solve_per_column_with_scores(text: &LowercaseString, n_cols: usize) -> Vec<(i32, f64)> {
+    text.columns(n_cols)
+        .iter()
+        .map(|column| {
+            let (shift, score) = utils::best_shift(column);
+            (i32::from(shift), score)
+        })
+        .collect()
+}
+
+/// Solves a Caesar cipher, picking the shift whose decryption gets the
+/// lowest score from `scorer`.
+///
+/// # Example
+/// ```
+/// use cipher_solver::{caesar, utils};
+///
+/// let text = "The quick brown fox jumps over the lazy dog";
+/// let encrypted = caesar::encrypt(text, 3);
+/// let solved = caesar::solve_with_scorer(&encrypted, utils::bigram_english_score);
+/// assert_eq!(solved, "thequickbrownfoxjumpsoverthelazydog");
+/// ```
+#[must_use]
+pub fn solve_with_scorer<F: Fn(&LowercaseString) -> f64>(text: &str, scorer: F) -> String {
     let text = LowercaseString::normalize(text);
     (0..26)
         .map(|shift| {
             let shifted = text.caesar_shift(shift);
-            (utils::chi_squared_english_score(&shifted), shifted)
+            (scorer(&shifted), shifted)
         })
         .min_by(|(score1, _), (score2, _)| score1.total_cmp(score2))
         .map(|(_, text)| text.to_string())
         .unwrap()
 }
 
+/// Returns every possible decryption with the shift that produced it,
+/// from most to least English-like according to
+/// [`utils::chi_squared_english_score`].
+///
+/// # Example
+/// ```
+/// use cipher_solver::caesar;
+///
+/// let encrypted = caesar::encrypt("The quick brown fox jumps over the lazy dog", 3);
+/// let ranked = caesar::solve_ranked(&encrypted);
+/// assert_eq!(ranked.len(), 26);
+/// assert_eq!(ranked[0], ("thequickbrownfoxjumpsoverthelazydog".to_string(), 3));
+/// ```
+#[must_use]
+pub fn solve_ranked(text: &str) -> Vec<(String, i32)> {
+    let text = LowercaseString::normalize(text);
+    let mut ranked: Vec<_> = (0..26)
+        .map(|shift| {
+            let shifted = text.caesar_shift(-shift);
+            (utils::chi_squared_english_score(&shifted), shifted, shift)
+        })
+        .collect();
+    ranked.sort_by(|(score1, ..), (score2, ..)| score1.total_cmp(score2));
+    ranked
+        .into_iter()
+        .map(|(_, text, shift)| (text.to_string(), shift))
+        .collect()
+}
+
+/// Solves a Caesar cipher, considering only decryptions that contain a
+/// known piece of the plaintext. Returns the most English-like of them
+/// with its shift, or `None` if no shift produces the fragment.
+///
+/// # Example
+/// ```
+/// use cipher_solver::caesar;
+///
+/// let encrypted = caesar::encrypt("Dear Sir, zzz", 8);
+/// assert_eq!(caesar::solve_constrained(&encrypted, "Dear"), Some(("dearsirzzz".to_string(), 8)));
+/// assert_eq!(caesar::solve_constrained(&encrypted, "Madam"), None);
+/// ```
+#[must_use]
+pub fn solve_constrained(text: &str, must_contain: &str) -> Option<(String, i32)> {
+    let fragment = LowercaseString::normalize(must_contain).to_string();
+    solve_ranked(text)
+        .into_iter()
+        .find(|(plaintext, _)| plaintext.contains(&fragment))
+}
+
 /// Encrypts a message using a Caesar cipher with a given shift.
 /// Punctuation and whitespace are removed.
 ///
@@ -37,10 +189,115 @@ pub fn solve(text: &str) -> String {
 /// assert_eq!(encrypted, "khoorzruog");
 /// ```
 #[must_use]
-pub fn encrypt(text: &str, shift: i32) -> String {
+pub fn encrypt(text: impl AsRef<str>, shift: i32) -> String {
+    LowercaseString::normalize(text.as_ref())
+        .shift_columns(&[shift])
+        .to_string()
+}
+
+/// Encrypts bytes using a Caesar cipher with a given shift. Only the bytes
+/// `b'a'..=b'z'` are shifted; all others, including uppercase letters, are
+/// passed through unchanged.
+///
+/// # Example
+/// ```
+/// use cipher_solver::caesar;
+///
+/// assert_eq!(caesar::encrypt_bytes(b"Hello, world!\0", 3), b"Hhoor, zruog!\0");
+/// ```
+#[must_use]
+pub fn encrypt_bytes(bytes: &[u8], shift: i32) -> Vec<u8> {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let shift = shift.rem_euclid(26) as u8;
+    bytes
+        .iter()
+        .map(|&byte| match byte {
+            b'a'..=b'z' => b'a' + (byte - b'a' + shift) % 26,
+            _ => byte,
+        })
+        .collect()
+}
+
+/// Decrypts bytes using a Caesar cipher with a given shift, the inverse of
+/// [`encrypt_bytes`].
+///
+/// # Example
+/// ```
+/// use cipher_solver::caesar;
+///
+/// assert_eq!(caesar::decrypt_bytes(b"Hhoor, zruog!\0", 3), b"Hello, world!\0");
+/// ```
+#[must_use]
+pub fn decrypt_bytes(bytes: &[u8], shift: i32) -> Vec<u8> {
+    encrypt_bytes(bytes, -shift.rem_euclid(26))
+}
+
+/// Encrypts a message using a Caesar cipher with a given shift, keeping
+/// ASCII digits in place. Letters are lowercased and all other characters
+/// are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::caesar;
+///
+/// assert_eq!(caesar::encrypt_preserve_digits("Hello 2023!", 3), "khoor2023");
+/// ```
+#[must_use]
+pub fn encrypt_preserve_digits(text: &str, shift: i32) -> String {
+    let kept: Vec<_> = text
+        .bytes()
+        .filter(u8::is_ascii_alphanumeric)
+        .map(|byte| byte.to_ascii_lowercase())
+        .collect();
+    String::from_utf8(encrypt_bytes(&kept, shift)).unwrap()
+}
+
+/// Decrypts a message encrypted with [`encrypt_preserve_digits`], keeping
+/// ASCII digits in place. Letters are lowercased and all other characters
+/// are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::caesar;
+///
+/// assert_eq!(caesar::decrypt_preserve_digits("khoor2023", 3), "hello2023");
+/// ```
+#[must_use]
+pub fn decrypt_preserve_digits(text: &str, shift: i32) -> String {
+    encrypt_preserve_digits(text, -shift.rem_euclid(26))
+}
+
+/// Encrypts a message using a Caesar cipher with a given shift, returning
+/// the letter indices of the ciphertext (a = 0).
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::caesar;
+///
+/// assert_eq!(caesar::encrypt_numeric_output("Hi!", 3), [10, 11]);
+/// ```
+#[must_use]
+pub fn encrypt_numeric_output(text: &str, shift: i32) -> Vec<u8> {
     LowercaseString::normalize(text)
         .caesar_shift(shift)
-        .to_string()
+        .to_indices()
+        .to_vec()
+}
+
+/// Decrypts the letter indices of a ciphertext using a Caesar cipher with a
+/// given shift. Indices of 26 or more are reduced modulo 26.
+///
+/// # Example
+/// ```
+/// use cipher_solver::caesar;
+///
+/// let decrypted = caesar::decrypt_numeric_input(&[10, 11], 3);
+/// assert_eq!(decrypted.to_string(), "hi");
+/// ```
+#[must_use]
+pub fn decrypt_numeric_input(indices: &[u8], shift: i32) -> LowercaseString {
+    LowercaseString::from_indices(indices.to_vec()).caesar_shift(-shift)
 }
 
 /// Decrypts a message using a Caesar cipher with a given shift.
@@ -55,13 +312,116 @@ pub fn encrypt(text: &str, shift: i32) -> String {
 /// assert_eq!(decrypted, "helloworld");
 /// ```
 #[must_use]
-pub fn decrypt(text: &str, shift: i32) -> String {
-    encrypt(text, 26 - shift)
+pub fn decrypt(text: impl AsRef<str>, shift: i32) -> String {
+    LowercaseString::normalize(text.as_ref())
+        .shift_columns(&[-shift.rem_euclid(26)])
+        .to_string()
+}
+
+/// Encrypts a message using a Caesar cipher over a custom ordering of the
+/// alphabet, shifting each letter to the one `shift` places later in
+/// `alphabet`. With the standard alphabet this is [`encrypt`].
+/// Punctuation and whitespace are removed.
+///
+/// # Errors
+/// Returns [`CaesarError::InvalidAlphabet`] unless the alphabet contains
+/// every letter exactly once.
+///
+/// # Example
+/// ```
+/// use cipher_solver::{caesar, LowercaseString};
+///
+/// let alphabet = LowercaseString::normalize("zebrascdfghijklmnopqtuvwxy");
+/// let encrypted = caesar::encrypt_with_alphabet("hello world", 1, &alphabet).unwrap();
+/// assert_eq!(encrypted, "ibmmpxpamf");
+/// ```
+pub fn encrypt_with_alphabet(
+    text: &str,
+    shift: i32,
+    alphabet: &LowercaseString,
+) -> Result<String, CaesarError> {
+    // Maps each letter to its position in the alphabet.
+    let positions = SubstitutionKey::from_alphabet(alphabet)
+        .ok_or(CaesarError::InvalidAlphabet)?
+        .inverse();
+    let alphabet = alphabet.to_indices();
+    let text = LowercaseString::normalize(text);
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    let shifted = text.to_indices().iter().map(|&c| {
+        let position = (i32::from(positions[c as usize]) + shift).rem_euclid(26);
+        alphabet[position as usize]
+    });
+    Ok(LowercaseString::from_indices(shifted.collect()).to_string())
+}
+
+/// Decrypts a message using a Caesar cipher over a custom ordering of the
+/// alphabet.
+/// Punctuation and whitespace are removed.
+///
+/// # Errors
+/// Returns [`CaesarError::InvalidAlphabet`] unless the alphabet contains
+/// every letter exactly once.
+///
+/// # Example
+/// ```
+/// use cipher_solver::{caesar, LowercaseString};
+///
+/// let alphabet = LowercaseString::normalize("zebrascdfghijklmnopqtuvwxy");
+/// let decrypted = caesar::decrypt_with_alphabet("ibmmpxpamf", 1, &alphabet).unwrap();
+/// assert_eq!(decrypted, "helloworld");
+/// ```
+pub fn decrypt_with_alphabet(
+    text: &str,
+    shift: i32,
+    alphabet: &LowercaseString,
+) -> Result<String, CaesarError> {
+    encrypt_with_alphabet(text, -shift, alphabet)
+}
+
+/// Detects the shift of a Caesar cipher from a known fragment of the plaintext.
+///
+/// Every position where the fragment could align with the ciphertext is
+/// checked. A shift is returned only if all such alignments agree on it.
+///
+/// # Example
+/// ```
+/// use cipher_solver::caesar;
+///
+/// let encrypted = caesar::encrypt("attack at dawn", 5);
+/// assert_eq!(caesar::detect_shift(&encrypted, "dawn"), Some(5));
+/// ```
+#[must_use]
+pub fn detect_shift(ciphertext: &str, known_plaintext_fragment: &str) -> Option<i32> {
+    let ciphertext = LowercaseString::normalize(ciphertext);
+    let fragment = LowercaseString::normalize(known_plaintext_fragment);
+    let cipher_indices = ciphertext.to_indices();
+    let fragment_indices = fragment.to_indices();
+
+    if fragment_indices.is_empty() || fragment_indices.len() > cipher_indices.len() {
+        return None;
+    }
+
+    let mut shifts = cipher_indices
+        .windows(fragment_indices.len())
+        .filter_map(|window| {
+            let shift = (window[0] + 26 - fragment_indices[0]) % 26;
+            window
+                .iter()
+                .zip(fragment_indices)
+                .all(|(&c, &p)| (c + 26 - p) % 26 == shift)
+                .then_some(shift)
+        });
+
+    let first = shifts.next()?;
+    shifts.all(|shift| shift == first).then_some(i32::from(first))
 }
 
 #[cfg(test)]
 mod tests {
+    use std::borrow::Cow;
+
     use super::*;
+    use crate::test_data::OZYMANDIAS;
 
     #[test]
     fn test_solve() {
@@ -78,6 +438,63 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_solve_with_language() {
+        let text = "Les jeunes aiment jouer au jeu de quilles";
+        let normalized = LowercaseString::normalize(text).to_string();
+        let encrypted = encrypt(text, 7);
+        assert_eq!(
+            solve_with_language(&encrypted, Language::French),
+            (normalized.clone(), 7)
+        );
+        assert_ne!(
+            solve_with_language(&encrypted, Language::English).0,
+            normalized
+        );
+    }
+
+    #[test]
+    fn test_solve_with_scorer() {
+        let text = "The quick brown fox jumps over the lazy dog";
+        let encrypted = encrypt(text, 11);
+        assert_eq!(
+            solve_with_scorer(&encrypted, utils::bigram_english_score),
+            solve(&encrypted)
+        );
+
+        let starts_late = |text: &LowercaseString| -f64::from(text.to_indices()[0]);
+        assert_eq!(solve_with_scorer("abc", starts_late), "zab");
+    }
+
+    #[test]
+    fn test_solve_ranked() {
+        let text = "I met a traveller from an antique land";
+        let ranked = solve_ranked(&encrypt(text, 20));
+        assert_eq!(
+            ranked[0],
+            (LowercaseString::normalize(text).to_string(), 20)
+        );
+
+        let mut shifts: Vec<_> = ranked.iter().map(|&(_, shift)| shift).collect();
+        shifts.sort_unstable();
+        assert_eq!(shifts, (0..26).collect::<Vec<_>>());
+        for (plaintext, shift) in ranked {
+            assert_eq!(encrypt(&plaintext, shift), encrypt(text, 20));
+        }
+    }
+
+    #[test]
+    fn test_solve_constrained() {
+        // Too short for chi-squared to find the right shift on its own.
+        let encrypted = encrypt("Dear Sir, zzz", 8);
+        assert_ne!(solve(&encrypted), "dearsirzzz");
+        assert_eq!(
+            solve_constrained(&encrypted, "dear"),
+            Some(("dearsirzzz".to_string(), 8))
+        );
+        assert_eq!(solve_constrained(&encrypted, "madam"), None);
+    }
+
     #[test]
     fn test_encrypt_decrypt() {
         let original = "The quick brown fox jumps over the lazy dog";
@@ -86,5 +503,207 @@ mod tests {
         let encrypted = encrypt(original, shift);
         let decrypted = decrypt(&encrypted, shift);
         assert_eq!(decrypted, normalized.to_string());
+
+        let owned = String::from(original);
+        assert_eq!(encrypt(owned.clone(), shift), encrypted);
+        assert_eq!(encrypt(&owned, shift), encrypted);
+        assert_eq!(decrypt(Cow::Borrowed(encrypted.as_str()), shift), decrypted);
+        assert_eq!(decrypt(encrypted, shift), decrypted);
+    }
+
+    #[test]
+    fn test_preserve_digits() {
+        assert_eq!(encrypt_preserve_digits("hello 2023", 3), "khoor2023");
+        assert_eq!(decrypt_preserve_digits("khoor2023", 3), "hello2023");
+        assert_eq!(
+            encrypt_preserve_digits("Agent 007, zone 9é", -1),
+            "zfdms007ynmd9"
+        );
+
+        let original = "Meet at 10 Downing St, 4pm";
+        let encrypted = encrypt_preserve_digits(original, 11);
+        assert_eq!(
+            decrypt_preserve_digits(&encrypted, 11),
+            "meetat10downingst4pm"
+        );
+        let letters: String = encrypted
+            .chars()
+            .filter(char::is_ascii_alphabetic)
+            .collect();
+        assert_eq!(letters, encrypt(original, 11));
+    }
+
+    #[test]
+    fn test_solve_per_column() {
+        let text = LowercaseString::normalize(OZYMANDIAS);
+        let shifts = [3, 17, 8, 25];
+        let encrypted: Vec<_> = text
+            .columns(4)
+            .iter()
+            .zip(shifts)
+            .map(|(column, shift)| column.caesar_shift(shift))
+            .collect();
+        let interleaved = LowercaseString::from_indices(
+            (0..text.to_indices().len())
+                .map(|i| encrypted[i % 4].to_indices()[i / 4])
+                .collect(),
+        );
+
+        let solved = solve_per_column_with_scores(&interleaved, 4);
+        assert_eq!(solve_per_column(&interleaved, 4), shifts);
+        let decrypted: Vec<_> = interleaved
+            .columns(4)
+            .iter()
+            .zip(&solved)
+            .map(|(column, &(shift, score))| {
+                assert!(score < 0.5);
+                column.caesar_shift(-shift)
+            })
+            .collect();
+        let deinterleaved = LowercaseString::from_indices(
+            (0..text.to_indices().len())
+                .map(|i| decrypted[i % 4].to_indices()[i / 4])
+                .collect(),
+        );
+        assert_eq!(deinterleaved, text);
+    }
+
+    #[test]
+    fn test_with_alphabet() {
+        let text = "The quick brown fox jumps over the lazy dog";
+        let standard = LowercaseString::normalize("abcdefghijklmnopqrstuvwxyz");
+        assert_eq!(
+            encrypt_with_alphabet(text, 29, &standard),
+            Ok(encrypt(text, 3))
+        );
+
+        let duplicate = LowercaseString::normalize("abcdefghijklmnopqrstuvwxya");
+        let short = LowercaseString::normalize("abc");
+        for alphabet in [duplicate, short] {
+            assert_eq!(
+                encrypt_with_alphabet(text, 3, &alphabet),
+                Err(CaesarError::InvalidAlphabet)
+            );
+            assert_eq!(
+                decrypt_with_alphabet(text, 3, &alphabet),
+                Err(CaesarError::InvalidAlphabet)
+            );
+        }
+    }
+
+    #[test]
+    fn test_detect_shift_unique() {
+        let encrypted = encrypt("I met a traveller from an antique land", 11);
+        assert_eq!(detect_shift(&encrypted, "traveller"), Some(11));
+    }
+
+    #[test]
+    fn test_detect_shift_repeated_same_shift() {
+        let encrypted = encrypt("the sand and the stone and the sky", 4);
+        assert_eq!(detect_shift(&encrypted, "andthe"), Some(4));
+    }
+
+    #[test]
+    fn test_detect_shift_repeated_different_shifts() {
+        let encrypted = encrypt("the stone", 3) + &encrypt("the sand", 9);
+        assert_eq!(detect_shift(&encrypted, "the"), None);
+    }
+
+    #[test]
+    fn test_detect_shift_no_match() {
+        let encrypted = encrypt("hello world", 3);
+        assert_eq!(detect_shift(&encrypted, "goodbye"), None);
+        assert_eq!(detect_shift(&encrypted, ""), None);
+    }
+}
+
+#[cfg(test)]
+mod prop_roundtrip {
+    use super::*;
+    use crate::test_data::{random_substitution_key, random_text, OZYMANDIAS};
+    use crate::utils::Rng;
+
+    #[test]
+    fn encrypt_decrypt() {
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            let plain = random_text(&mut rng, 50);
+            let shift = i32::try_from(rng.below(200)).unwrap() - 100;
+            let encrypted = encrypt(&plain, shift);
+            assert_eq!(
+                decrypt(&encrypted, shift),
+                LowercaseString::normalize(&plain).to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn bytes_encrypt_decrypt() {
+        let mut rng = Rng::new(14);
+        for _ in 0..1000 {
+            let bytes: Vec<_> = (0..50)
+                .map(|_| u8::try_from(rng.below(256)).unwrap())
+                .collect();
+            let shift = i32::try_from(rng.below(200)).unwrap() - 100;
+            let encrypted = encrypt_bytes(&bytes, shift);
+            assert_eq!(decrypt_bytes(&encrypted, shift), bytes);
+            for (&before, &after) in bytes.iter().zip(&encrypted) {
+                assert_eq!(before.is_ascii_lowercase(), after.is_ascii_lowercase());
+                if !before.is_ascii_lowercase() {
+                    assert_eq!(before, after);
+                }
+            }
+
+            let plain = random_text(&mut rng, 50);
+            assert_eq!(
+                encrypt(&plain, shift).into_bytes(),
+                encrypt_bytes(
+                    LowercaseString::normalize(&plain).to_string().as_bytes(),
+                    shift
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn numeric_encrypt_decrypt() {
+        let mut rng = Rng::new(11);
+        for _ in 0..1000 {
+            let plain = random_text(&mut rng, 50);
+            let shift = i32::try_from(rng.below(200)).unwrap() - 100;
+            let encrypted = encrypt_numeric_output(&plain, shift);
+            assert!(encrypted.iter().all(|&i| i < 26));
+            assert_eq!(
+                LowercaseString::from_indices(encrypted.clone()).to_string(),
+                encrypt(&plain, shift)
+            );
+            assert_eq!(
+                decrypt_numeric_input(&encrypted, shift),
+                LowercaseString::normalize(&plain)
+            );
+        }
+    }
+
+    #[test]
+    fn encrypt_decrypt_with_alphabet() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            let plain = random_text(&mut rng, 50);
+            let alphabet = random_substitution_key(&mut rng).to_alphabet();
+            let shift = i32::try_from(rng.below(200)).unwrap() - 100;
+            let encrypted = encrypt_with_alphabet(&plain, shift, &alphabet).unwrap();
+            assert_eq!(
+                decrypt_with_alphabet(&encrypted, shift, &alphabet),
+                Ok(LowercaseString::normalize(&plain).to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn solve_all_shifts() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        for shift in 0..26 {
+            assert_eq!(solve(&encrypt(OZYMANDIAS, shift)), normalized);
+        }
     }
 }
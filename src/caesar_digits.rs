@@ -0,0 +1,152 @@
+use std::f64::consts::LOG10_2;
+
+/// The frequencies of the leading digits of naturally occurring numbers,
+/// following Benford's law. No number starts with 0.
+const LEADING_DIGIT_FREQUENCIES: [f64; 10] = [
+    0.0, LOG10_2, 0.17609, 0.12494, 0.09691, 0.07918, 0.06695, 0.05799, 0.05115, 0.04576,
+];
+
+/// The probability given to digits with an expected frequency of zero.
+const FLOOR: f64 = 1e-4;
+
+fn shift_digits(text: &str, shift: u8) -> String {
+    text.chars()
+        .map(|c| match c.to_digit(10) {
+            Some(d) => char::from_digit((d + u32::from(shift)) % 10, 10).unwrap(),
+            None => c,
+        })
+        .collect()
+}
+
+/// Scores digits by their negative log likelihood under the expected
+/// frequencies. Lower scores are better.
+fn score(digits: impl Iterator<Item = u32>, expected: &[f64; 10]) -> f64 {
+    digits.map(|d| -expected[d as usize].max(FLOOR).ln()).sum()
+}
+
+/// Finds the shift whose decryption gets the lowest score from `scorer`.
+fn solve_with_scorer(text: &str, scorer: impl Fn(&str) -> f64) -> (String, u8) {
+    (0..10)
+        .map(|shift| {
+            let decrypted = decrypt(text, shift);
+            (scorer(&decrypted), decrypted, shift)
+        })
+        .min_by(|(score1, ..), (score2, ..)| score1.total_cmp(score2))
+        .map(|(_, text, shift)| (text, shift))
+        .unwrap()
+}
+
+/// Encrypts a message by shifting each digit by `shift` (mod 10). All other
+/// characters are left unchanged.
+///
+/// # Example
+/// ```
+/// use cipher_solver::caesar_digits;
+///
+/// let encrypted = caesar_digits::encrypt("hello 9 world 0", 3);
+/// assert_eq!(encrypted, "hello 2 world 3");
+/// ```
+#[must_use]
+pub fn encrypt(text: &str, shift: u8) -> String {
+    shift_digits(text, shift % 10)
+}
+
+/// Decrypts a message by shifting each digit back by `shift` (mod 10). All
+/// other characters are left unchanged.
+///
+/// # Example
+/// ```
+/// use cipher_solver::caesar_digits;
+///
+/// let decrypted = caesar_digits::decrypt("hello 2 world 3", 3);
+/// assert_eq!(decrypted, "hello 9 world 0");
+/// ```
+#[must_use]
+pub fn decrypt(text: &str, shift: u8) -> String {
+    shift_digits(text, 10 - shift % 10)
+}
+
+/// Solves a digit Caesar cipher over a text of naturally occurring numbers,
+/// returning the plaintext and the shift.
+///
+/// Shifting only reorders the digit counts, so every shift is equally close
+/// to a uniform distribution. Instead, the first digit of each number is
+/// scored against Benford's law.
+///
+/// # Example
+/// ```
+/// use cipher_solver::caesar_digits;
+///
+/// let text = "Populations: 1420, 1390, 335, 277, 240, 216, 223, 170, 146, 128, \
+///     125, 113, 104, 98, 85, 84, 83, 71, 67, 65, 60, 59, 52, 51, 46, 44, 38, 34";
+/// let encrypted = caesar_digits::encrypt(text, 6);
+/// assert_eq!(caesar_digits::solve(&encrypted), (text.to_string(), 6));
+/// ```
+#[must_use]
+pub fn solve(text: &str) -> (String, u8) {
+    solve_with_scorer(text, |decrypted| {
+        let leading = decrypted
+            .split(|c: char| !c.is_ascii_digit())
+            .filter_map(|number| number.chars().next())
+            .filter_map(|c| c.to_digit(10));
+        score(leading, &LEADING_DIGIT_FREQUENCIES)
+    })
+}
+
+/// Solves a digit Caesar cipher, scoring every digit of each candidate
+/// against the given frequencies of the digits 0 to 9. Returns the
+/// plaintext and the shift.
+///
+/// # Example
+/// ```
+/// use cipher_solver::caesar_digits;
+///
+/// // Mostly small digits, as in a list of dice rolls.
+/// let frequencies = [0.0, 0.2, 0.2, 0.2, 0.2, 0.1, 0.1, 0.0, 0.0, 0.0];
+/// let encrypted = caesar_digits::encrypt("1 4 2 2 3 1 6 4 5 3", 5);
+/// let (solved, shift) = caesar_digits::solve_with_frequencies(&encrypted, &frequencies);
+/// assert_eq!(solved, "1 4 2 2 3 1 6 4 5 3");
+/// assert_eq!(shift, 5);
+/// ```
+#[must_use]
+pub fn solve_with_frequencies(text: &str, frequencies: &[f64; 10]) -> (String, u8) {
+    solve_with_scorer(text, |decrypted| {
+        score(
+            decrypted.chars().filter_map(|c| c.to_digit(10)),
+            frequencies,
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let text = "Call 0123456789 now!";
+        assert_eq!(encrypt(text, 1), "Call 1234567890 now!");
+        assert_eq!(encrypt(text, 13), encrypt(text, 3));
+        assert_eq!(encrypt(text, 0), text);
+        for shift in 0..20 {
+            assert_eq!(decrypt(&encrypt(text, shift), shift), text);
+        }
+        assert_eq!(encrypt("hello 9 world 0", 3), "hello 2 world 3");
+    }
+
+    #[test]
+    fn test_solve() {
+        let mut fibonacci = vec![1u64, 2];
+        while fibonacci.len() < 60 {
+            fibonacci.push(fibonacci[fibonacci.len() - 1] + fibonacci[fibonacci.len() - 2]);
+        }
+        let text = fibonacci
+            .iter()
+            .map(u64::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        for shift in 0..10 {
+            assert_eq!(solve(&encrypt(&text, shift)), (text.clone(), shift));
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::lowercase_string::LowercaseString;
+use crate::substitution::{self, SubstitutionKey};
+use crate::{affine, atbash, beaufort, caesar, rail_fence, utils, vigenere};
+
+/// A cipher together with its key.
+///
+/// Displays as `name:key` and can be parsed back from the same format. An
+/// affine key is written `a,b`, a substitution key as its 26-letter
+/// alphabet, and Atbash has no key.
+///
+/// # Example
+/// ```
+/// use cipher_solver::cipher_kind::CipherKind;
+///
+/// let cipher: CipherKind = "caesar:3".parse().unwrap();
+/// assert_eq!(cipher, CipherKind::Caesar(3));
+/// assert_eq!(cipher.encrypt("hello world").unwrap(), "khoorzruog");
+/// assert_eq!(cipher.to_string(), "caesar:3");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum CipherKind {
+    Caesar(i32),
+    Vigenere(String),
+    Affine(u8, u8),
+    Substitution(SubstitutionKey),
+    Atbash,
+    RailFence(usize),
+    Beaufort(String),
+}
+
+impl CipherKind {
+    /// Encrypts a message with this cipher.
+    /// Returns `None` if the key is invalid for the cipher.
+    #[must_use]
+    pub fn encrypt(&self, text: &str) -> Option<String> {
+        match self {
+            Self::Caesar(shift) => Some(caesar::encrypt(text, *shift)),
+            Self::Vigenere(keyword) => vigenere::encrypt(text, keyword),
+            Self::Affine(a, b) => affine::encrypt(text, *a, *b),
+            Self::Substitution(key) => Some(substitution::encrypt(text, key)),
+            Self::Atbash => Some(atbash::encrypt(text)),
+            Self::RailFence(rails) => rail_fence::encrypt(text, *rails),
+            Self::Beaufort(keyword) => beaufort::encrypt(text, keyword),
+        }
+    }
+
+    /// Decrypts a message with this cipher.
+    /// Returns `None` if the key is invalid for the cipher.
+    #[must_use]
+    pub fn decrypt(&self, text: &str) -> Option<String> {
+        match self {
+            Self::Caesar(shift) => Some(caesar::decrypt(text, *shift)),
+            Self::Vigenere(keyword) => vigenere::decrypt(text, keyword),
+            Self::Affine(a, b) => affine::decrypt(text, *a, *b),
+            Self::Substitution(key) => Some(substitution::decrypt(text, key)),
+            Self::Atbash => Some(atbash::decrypt(text)),
+            Self::RailFence(rails) => rail_fence::decrypt(text, *rails),
+            Self::Beaufort(keyword) => beaufort::decrypt(text, keyword),
+        }
+    }
+}
+
+impl Display for CipherKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Caesar(shift) => write!(f, "caesar:{shift}"),
+            Self::Vigenere(keyword) => write!(f, "vigenere:{keyword}"),
+            Self::Affine(a, b) => write!(f, "affine:{a},{b}"),
+            Self::Substitution(key) => write!(f, "substitution:{key}"),
+            Self::Atbash => write!(f, "atbash"),
+            Self::RailFence(rails) => write!(f, "railfence:{rails}"),
+            Self::Beaufort(keyword) => write!(f, "beaufort:{keyword}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseCipherKindError {
+    UnknownCipher(String),
+    InvalidKey(String),
+}
+
+impl Display for ParseCipherKindError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCipher(name) => write!(f, "unknown cipher: {name}"),
+            Self::InvalidKey(key) => write!(f, "invalid key: {key}"),
+        }
+    }
+}
+
+impl Error for ParseCipherKindError {}
+
+impl FromStr for CipherKind {
+    type Err = ParseCipherKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, key) = s.split_once(':').unwrap_or((s, ""));
+        let invalid_key = || ParseCipherKindError::InvalidKey(key.to_string());
+        let keyword = || {
+            key.chars()
+                .any(|c| c.is_ascii_alphabetic())
+                .then(|| key.to_string())
+                .ok_or_else(invalid_key)
+        };
+        match name {
+            "caesar" => key.parse().map(Self::Caesar).map_err(|_| invalid_key()),
+            "vigenere" => keyword().map(Self::Vigenere),
+            "affine" => {
+                let (a, b) = key.split_once(',').ok_or_else(invalid_key)?;
+                match (a.parse(), b.parse()) {
+                    (Ok(a), Ok(b)) if utils::modular_inverse(a % 26, 26).is_some() => {
+                        Ok(Self::Affine(a, b))
+                    }
+                    _ => Err(invalid_key()),
+                }
+            }
+            "substitution" => (key.len() == 26)
+                .then(|| SubstitutionKey::from_alphabet(&LowercaseString::normalize(key)))
+                .flatten()
+                .map(Self::Substitution)
+                .ok_or_else(invalid_key),
+            "atbash" if key.is_empty() => Ok(Self::Atbash),
+            "atbash" => Err(invalid_key()),
+            "railfence" => match key.parse() {
+                Ok(rails) if rails > 0 => Ok(Self::RailFence(rails)),
+                _ => Err(invalid_key()),
+            },
+            "beaufort" => keyword().map(Self::Beaufort),
+            _ => Err(ParseCipherKindError::UnknownCipher(name.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let original = "The quick brown fox jumps over the lazy dog";
+        let ciphers = [
+            CipherKind::Caesar(7),
+            CipherKind::Vigenere("secret".to_string()),
+            CipherKind::Affine(5, 8),
+            CipherKind::Substitution(utils::keyword_alphabet_permutation("secret")),
+            CipherKind::Atbash,
+            CipherKind::RailFence(3),
+            CipherKind::Beaufort("secret".to_string()),
+        ];
+        for cipher in ciphers {
+            let encrypted = cipher.encrypt(original).unwrap();
+            let decrypted = cipher.decrypt(&encrypted).unwrap();
+            assert_eq!(decrypted, "thequickbrownfoxjumpsoverthelazydog");
+        }
+
+        assert_eq!(CipherKind::Vigenere(String::new()).encrypt(original), None);
+        assert_eq!(CipherKind::Affine(2, 1).encrypt(original), None);
+        assert_eq!(CipherKind::RailFence(0).decrypt(original), None);
+        assert_eq!(
+            CipherKind::Atbash.encrypt("abc"),
+            Some(atbash::encrypt("abc"))
+        );
+    }
+
+    #[test]
+    fn test_display_from_str() {
+        let ciphers = [
+            CipherKind::Caesar(3),
+            CipherKind::Caesar(-5),
+            CipherKind::Vigenere("key".to_string()),
+            CipherKind::Affine(5, 8),
+            CipherKind::Substitution(utils::keyword_alphabet_permutation("zebras")),
+            CipherKind::Atbash,
+            CipherKind::RailFence(4),
+            CipherKind::Beaufort("key".to_string()),
+        ];
+        for cipher in ciphers {
+            assert_eq!(cipher.to_string().parse(), Ok(cipher));
+        }
+        assert_eq!(CipherKind::Affine(5, 8).to_string(), "affine:5,8");
+        assert_eq!(CipherKind::Atbash.to_string(), "atbash");
+
+        for invalid in [
+            "affine:2,1",
+            "affine:5",
+            "substitution:abc",
+            "substitution:aacdefghijklmnopqrstuvwxyz",
+            "atbash:x",
+            "railfence:0",
+            "beaufort:1",
+        ] {
+            let key = invalid.split_once(':').unwrap().1.to_string();
+            assert_eq!(
+                invalid.parse::<CipherKind>(),
+                Err(ParseCipherKindError::InvalidKey(key))
+            );
+        }
+
+        assert_eq!(
+            "rot13".parse::<CipherKind>(),
+            Err(ParseCipherKindError::UnknownCipher("rot13".to_string()))
+        );
+        assert_eq!(
+            "caesar:x".parse::<CipherKind>(),
+            Err(ParseCipherKindError::InvalidKey("x".to_string()))
+        );
+        assert_eq!(
+            "vigenere:".parse::<CipherKind>(),
+            Err(ParseCipherKindError::InvalidKey(String::new()))
+        );
+    }
+}
@@ -0,0 +1,602 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::docs_words::DOCS_WORDS;
+use crate::lowercase_string::LowercaseString;
+use crate::utils::{self, Rng};
+
+/// The letter used to fill the last row of the grid ('x').
+const PADDING: u8 = 23;
+
+/// The number of words of each length tried as keys by [`solve`].
+const CANDIDATES_PER_LENGTH: usize = 100;
+
+/// The longest key tried by [`solve_key_length_first`], at 5040
+/// permutations.
+const MAX_PERMUTED_KEY_LEN: usize = 7;
+
+/// The number of results returned by [`solve_key_length_first`].
+const TOP_PERMUTATIONS: usize = 10;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnarError {
+    EmptyKeyword,
+    /// The text does not fill the grid and padding is disabled. Carries the
+    /// text length and the key length.
+    UnevenLength(usize, usize),
+    /// The key is too long to try every permutation of its columns.
+    KeyLengthTooLarge {
+        max: usize,
+    },
+}
+
+impl Display for ColumnarError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyKeyword => write!(f, "keyword contains no alphabet characters"),
+            Self::UnevenLength(text_len, key_len) => write!(
+                f,
+                "text length {text_len} is not a multiple of key length {key_len}"
+            ),
+            Self::KeyLengthTooLarge { max } => {
+                write!(f, "key length is larger than the maximum of {max}")
+            }
+        }
+    }
+}
+
+impl Error for ColumnarError {}
+
+/// How [`encrypt_with_options`] fills the last row of the grid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PaddingMode {
+    /// Pads with a single letter, given as its index (a = 0).
+    NullChar(u8),
+    /// Requires the text to fill the grid exactly.
+    NoPadding,
+    /// Pads with random letters generated from the given seed, so the
+    /// padding cannot be told apart from the text.
+    RandomPad(u64),
+}
+
+/// A columnar transposition key. Column `i` of the grid is read out in
+/// position `ranks()[i]`.
+///
+/// # Example
+/// ```
+/// use cipher_solver::columnar::Permutation;
+///
+/// let permutation = Permutation::from_keyword("zebras").unwrap();
+/// assert_eq!(permutation.ranks(), [5, 2, 1, 3, 0, 4]);
+/// assert_eq!(permutation.inverse().ranks(), [4, 2, 1, 3, 5, 0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Permutation(Vec<usize>);
+
+impl Permutation {
+    /// Ranks the letters of a keyword alphabetically, with repeated letters
+    /// ranked from left to right. Returns `None` if the keyword has no
+    /// letters.
+    #[must_use]
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        let keyword = LowercaseString::normalize(keyword);
+        let letters = keyword.to_indices();
+        if letters.is_empty() {
+            return None;
+        }
+        let mut order: Vec<usize> = (0..letters.len()).collect();
+        order.sort_by_key(|&i| letters[i]);
+        Some(Self(order).inverse())
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[must_use]
+    pub fn ranks(&self) -> &[usize] {
+        &self.0
+    }
+
+    /// Represents the permutation as a keyword, the letter of each position
+    /// being its rank (a = 0).
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::columnar::Permutation;
+    ///
+    /// let permutation = Permutation::from_keyword("zebras").unwrap();
+    /// assert_eq!(permutation.to_keyword(), "fcbdae");
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_keyword(&self) -> String {
+        self.0
+            .iter()
+            .map(|&rank| char::from(b'a' + rank as u8))
+            .collect()
+    }
+
+    /// Swaps the ranks of positions `a` and `b`.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.0.swap(a, b);
+    }
+
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let mut inverse = vec![0; self.0.len()];
+        for (i, &rank) in self.0.iter().enumerate() {
+            inverse[rank] = i;
+        }
+        Self(inverse)
+    }
+}
+
+pub(crate) fn encrypt_with_permutation(
+    text: &LowercaseString,
+    permutation: &Permutation,
+) -> LowercaseString {
+    let mut grid = text.to_indices().to_vec();
+    grid.resize(grid.len().next_multiple_of(permutation.len()), PADDING);
+    let columns = LowercaseString::from_indices(grid).columns(permutation.len());
+    LowercaseString::from_indices(
+        permutation
+            .inverse()
+            .ranks()
+            .iter()
+            .flat_map(|&column| columns[column].to_indices().to_vec())
+            .collect(),
+    )
+}
+
+/// Reverses [`encrypt_with_permutation`]. If the text does not fill the
+/// grid, the columns on the left are the ones holding an extra letter.
+pub(crate) fn decrypt_with_permutation(
+    text: &LowercaseString,
+    permutation: &Permutation,
+) -> LowercaseString {
+    let indices = text.to_indices();
+    let key_len = permutation.len();
+    let rows = indices.len().div_ceil(key_len);
+    let long_columns = match indices.len() % key_len {
+        0 => key_len,
+        n => n,
+    };
+
+    let mut columns = vec![&indices[..0]; key_len];
+    let mut start = 0;
+    for &column in permutation.inverse().ranks() {
+        let len = if column < long_columns {
+            rows
+        } else {
+            rows - 1
+        };
+        columns[column] = &indices[start..start + len];
+        start += len;
+    }
+    LowercaseString::from_indices(
+        (0..rows)
+            .flat_map(|row| columns.iter().filter_map(move |column| column.get(row)))
+            .copied()
+            .collect(),
+    )
+}
+
+/// Encrypts a message using a columnar transposition with a given keyword.
+/// The text is written in rows as wide as the keyword, padding the last row
+/// with 'x', and the columns are read out in the alphabetical order of the
+/// keyword letters.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::columnar;
+///
+/// let encrypted = columnar::encrypt("hello world", "key").unwrap();
+/// assert_eq!(encrypted, "eorxhlodlwlx");
+/// ```
+#[must_use]
+pub fn encrypt(text: &str, keyword: &str) -> Option<String> {
+    encrypt_with_options(text, keyword, PaddingMode::NullChar(PADDING)).ok()
+}
+
+/// Encrypts a message using a columnar transposition, filling the last row
+/// of the grid as `padding` says.
+/// Punctuation and whitespace are removed.
+///
+/// # Errors
+/// Returns [`ColumnarError::EmptyKeyword`] if the keyword has no letters, and
+/// [`ColumnarError::UnevenLength`] if padding is disabled and the text does
+/// not fill the grid.
+///
+/// # Example
+/// ```
+/// use cipher_solver::columnar::{self, ColumnarError, PaddingMode};
+///
+/// let encrypted = columnar::encrypt_with_options("hello world", "key", PaddingMode::NullChar(16));
+/// assert_eq!(encrypted.unwrap(), "eorqhlodlwlq");
+/// assert_eq!(
+///     columnar::encrypt_with_options("hello world", "key", PaddingMode::NoPadding),
+///     Err(ColumnarError::UnevenLength(10, 3))
+/// );
+/// ```
+pub fn encrypt_with_options(
+    text: &str,
+    keyword: &str,
+    padding: PaddingMode,
+) -> Result<String, ColumnarError> {
+    let permutation = Permutation::from_keyword(keyword).ok_or(ColumnarError::EmptyKeyword)?;
+    let mut grid = LowercaseString::normalize(text).to_indices().to_vec();
+    let padded_len = grid.len().next_multiple_of(permutation.len());
+    match padding {
+        PaddingMode::NullChar(c) => grid.resize(padded_len, c % 26),
+        PaddingMode::NoPadding if grid.len() != padded_len => {
+            return Err(ColumnarError::UnevenLength(grid.len(), permutation.len()));
+        }
+        PaddingMode::NoPadding => {}
+        PaddingMode::RandomPad(seed) => {
+            let mut rng = Rng::new(seed);
+            #[allow(clippy::cast_possible_truncation)]
+            grid.resize_with(padded_len, || rng.below(26) as u8);
+        }
+    }
+    Ok(encrypt_with_permutation(&LowercaseString::from_indices(grid), &permutation).to_string())
+}
+
+/// Decrypts a message using a columnar transposition with a given keyword.
+/// Padding is not removed.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::columnar;
+///
+/// let decrypted = columnar::decrypt("eorxhlodlwlx", "key").unwrap();
+/// assert_eq!(decrypted, "helloworldxx");
+/// ```
+#[must_use]
+pub fn decrypt(text: &str, keyword: &str) -> Option<String> {
+    decrypt_with_options(text, keyword, PaddingMode::NullChar(PADDING), false).ok()
+}
+
+/// Decrypts a message using a columnar transposition. If `strip_padding` is
+/// set, trailing padding letters are removed from the last row; random
+/// padding cannot be told apart from the text, so it is always kept.
+/// Punctuation and whitespace are removed.
+///
+/// # Errors
+/// Returns [`ColumnarError::EmptyKeyword`] if the keyword has no letters.
+///
+/// # Example
+/// ```
+/// use cipher_solver::columnar::{self, PaddingMode};
+///
+/// let decrypted = columnar::decrypt_with_options("eorqhlodlwlq", "key", PaddingMode::NullChar(16), true);
+/// assert_eq!(decrypted.unwrap(), "helloworld");
+/// ```
+pub fn decrypt_with_options(
+    text: &str,
+    keyword: &str,
+    padding: PaddingMode,
+    strip_padding: bool,
+) -> Result<String, ColumnarError> {
+    let permutation = Permutation::from_keyword(keyword).ok_or(ColumnarError::EmptyKeyword)?;
+    let mut decrypted = decrypt_with_permutation(&LowercaseString::normalize(text), &permutation)
+        .to_indices()
+        .to_vec();
+    if let (true, PaddingMode::NullChar(c)) = (strip_padding, padding) {
+        let min_len = decrypted.len().saturating_sub(permutation.len() - 1);
+        while decrypted.len() > min_len && decrypted.last() == Some(&(c % 26)) {
+            decrypted.pop();
+        }
+    }
+    Ok(LowercaseString::from_indices(decrypted).to_string())
+}
+
+/// Solves a columnar transposition by trying the 100 most common words of
+/// each length in the prose of the Rust documentation, from 1 to
+/// `max_key_len` letters, as keys. Returns the plaintext and the key.
+///
+/// A transposition leaves the letter frequencies unchanged, so candidates
+/// are scored with [`utils::bigram_english_score`] rather than by letter
+/// frequencies.
+///
+/// # Example
+/// ```
+/// use cipher_solver::columnar;
+///
+/// let text = "Shall I compare thee to a summer's day? Thou art more lovely and \
+///     more temperate: Rough winds do shake the darling buds of May";
+/// let encrypted = columnar::encrypt(text, "value").unwrap();
+/// let (_, key) = columnar::solve(&encrypted, 8);
+/// assert_eq!(key, "value");
+/// ```
+#[must_use]
+pub fn solve(text: &str, max_key_len: usize) -> (String, String) {
+    let text = LowercaseString::normalize(text);
+    let mut best = (
+        utils::bigram_english_score(&text),
+        text.clone(),
+        String::new(),
+    );
+    for key_len in 1..=max_key_len {
+        let keys = DOCS_WORDS
+            .iter()
+            .filter(|word| word.len() == key_len)
+            .take(CANDIDATES_PER_LENGTH);
+        for &key in keys {
+            let permutation = Permutation::from_keyword(key).unwrap();
+            let decrypted = decrypt_with_permutation(&text, &permutation);
+            let score = utils::bigram_english_score(&decrypted);
+            if score < best.0 {
+                best = (score, decrypted, key.to_string());
+            }
+        }
+    }
+    (best.1.to_string(), best.2)
+}
+
+/// Returns every permutation of `n` columns, generated with Heap's
+/// algorithm.
+fn all_permutations(n: usize) -> Vec<Permutation> {
+    let mut permutation = Permutation((0..n).collect());
+    let mut permutations = vec![permutation.clone()];
+    let mut counters = vec![0; n];
+    let mut i = 1;
+    while i < n {
+        if counters[i] < i {
+            let swapped = if i % 2 == 0 { 0 } else { counters[i] };
+            permutation.swap(swapped, i);
+            permutations.push(permutation.clone());
+            counters[i] += 1;
+            i = 1;
+        } else {
+            counters[i] = 0;
+            i += 1;
+        }
+    }
+    permutations
+}
+
+/// Solves a columnar transposition with a known key length by trying every
+/// permutation of the columns. Returns the 10 best decryptions with their
+/// keys, written as by [`Permutation::to_keyword`], and their scores, best
+/// first.
+///
+/// As in [`solve`], candidates are scored with
+/// [`utils::bigram_english_score`], since every permutation has the same
+/// letter frequencies.
+///
+/// # Errors
+/// Returns [`ColumnarError::EmptyKeyword`] if `key_len` is 0, and
+/// [`ColumnarError::KeyLengthTooLarge`] if it is above 7.
+///
+/// # Example
+/// ```
+/// use cipher_solver::columnar;
+///
+/// let text = "Shall I compare thee to a summer's day? Thou art more lovely and \
+///     more temperate: Rough winds do shake the darling buds of May";
+/// let encrypted = columnar::encrypt(text, "sonnet").unwrap();
+/// let results = columnar::solve_key_length_first(&encrypted, 6).unwrap();
+/// assert_eq!(results[0].1, "edbcaf");
+/// assert!(results[0].0.starts_with("shallicompare"));
+/// ```
+pub fn solve_key_length_first(
+    text: &str,
+    key_len: usize,
+) -> Result<Vec<(String, String, f64)>, ColumnarError> {
+    if key_len == 0 {
+        return Err(ColumnarError::EmptyKeyword);
+    }
+    if key_len > MAX_PERMUTED_KEY_LEN {
+        return Err(ColumnarError::KeyLengthTooLarge {
+            max: MAX_PERMUTED_KEY_LEN,
+        });
+    }
+
+    let text = LowercaseString::normalize(text);
+    let mut results: Vec<_> = all_permutations(key_len)
+        .iter()
+        .map(|permutation| {
+            let decrypted = decrypt_with_permutation(&text, permutation);
+            let score = utils::bigram_english_score(&decrypted);
+            (decrypted.to_string(), permutation.to_keyword(), score)
+        })
+        .collect();
+    results.sort_by(|(_, key1, score1), (_, key2, score2)| {
+        score1.total_cmp(score2).then_with(|| key1.cmp(key2))
+    });
+    results.truncate(TOP_PERMUTATIONS);
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SONNET: &str = "Shall I compare thee to a summer's day? \
+        Thou art more lovely and more temperate: \
+        Rough winds do shake the darling buds of May, \
+        And summer's lease hath all too short a date";
+
+    #[test]
+    fn test_permutation() {
+        assert_eq!(
+            Permutation::from_keyword("Hello!").unwrap().ranks(),
+            [1, 0, 2, 3, 4]
+        );
+        assert_eq!(Permutation::from_keyword("123"), None);
+
+        let mut permutation = Permutation::from_keyword("cipher").unwrap();
+        assert_eq!(permutation.len(), 6);
+        assert_eq!(permutation.inverse().inverse(), permutation);
+        assert_eq!(
+            Permutation::from_keyword(&permutation.to_keyword()),
+            Some(permutation.clone())
+        );
+        permutation.swap(0, 5);
+        assert_eq!(permutation.to_keyword(), "fdecba");
+    }
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let original = "The quick brown fox jumps over the lazy dog";
+        let normalized = LowercaseString::normalize(original).to_string();
+        let encrypted = encrypt(original, "zebras").unwrap();
+        assert_eq!(encrypted.len(), 36);
+        let decrypted = decrypt(&encrypted, "zebras").unwrap();
+        assert_eq!(decrypted, normalized + "x");
+
+        assert_eq!(encrypt(original, ""), None);
+        assert_eq!(decrypt(original, ""), None);
+    }
+
+    #[test]
+    fn test_padding_modes() {
+        let text = "The quick brown fox jumps over the lazy dog";
+        let normalized = LowercaseString::normalize(text).to_string();
+        let x = PaddingMode::NullChar(PADDING);
+        assert_eq!(
+            encrypt_with_options(text, "zebras", x),
+            Ok(encrypt(text, "zebras").unwrap())
+        );
+
+        let q = PaddingMode::NullChar(16);
+        let encrypted = encrypt_with_options(text, "zebras", q).unwrap();
+        assert_eq!(encrypted.matches('q').count(), 2);
+        assert_eq!(
+            decrypt_with_options(&encrypted, "zebras", q, true),
+            Ok(normalized.clone())
+        );
+        assert_eq!(
+            decrypt_with_options(&encrypted, "zebras", q, false),
+            Ok(normalized.clone() + "q")
+        );
+
+        let random = PaddingMode::RandomPad(42);
+        let encrypted = encrypt_with_options(text, "zebras", random).unwrap();
+        assert_eq!(encrypted.len(), 36);
+        assert_eq!(
+            encrypt_with_options(text, "zebras", random),
+            Ok(encrypted.clone())
+        );
+        let decrypted = decrypt_with_options(&encrypted, "zebras", random, true).unwrap();
+        assert!(decrypted.starts_with(&normalized));
+        assert_eq!(decrypted.len(), 36);
+
+        assert_eq!(
+            encrypt_with_options(text, "zebras", PaddingMode::NoPadding),
+            Err(ColumnarError::UnevenLength(35, 6))
+        );
+        let encrypted = encrypt_with_options(text, "seven", PaddingMode::NoPadding).unwrap();
+        assert_eq!(
+            decrypt_with_options(&encrypted, "seven", PaddingMode::NoPadding, true),
+            Ok(normalized)
+        );
+        assert_eq!(
+            encrypt_with_options(text, "", q),
+            Err(ColumnarError::EmptyKeyword)
+        );
+    }
+
+    #[test]
+    fn test_decrypt_without_padding() {
+        let text = LowercaseString::normalize("hello world");
+        let permutation = Permutation::from_keyword("key").unwrap();
+        let encrypted = encrypt_with_permutation(&text, &permutation);
+        let unpadded = LowercaseString::from_indices(
+            encrypted
+                .to_indices()
+                .iter()
+                .copied()
+                .filter(|&c| c != PADDING)
+                .collect(),
+        );
+        assert_eq!(unpadded.to_string(), "eorhlodlwl");
+        assert_eq!(decrypt_with_permutation(&unpadded, &permutation), text);
+    }
+
+    #[test]
+    fn test_solve() {
+        let normalized = LowercaseString::normalize(SONNET).to_string();
+        let encrypted = encrypt(SONNET, "future").unwrap();
+        let (solved, key) = solve(&encrypted, 8);
+        assert_eq!(key, "future");
+        assert!(solved.starts_with(&normalized));
+    }
+
+    #[test]
+    fn test_all_permutations() {
+        for n in 1..=5 {
+            let mut permutations: Vec<_> = all_permutations(n)
+                .iter()
+                .map(Permutation::to_keyword)
+                .collect();
+            permutations.sort();
+            permutations.dedup();
+            assert_eq!(permutations.len(), (1..=n).product::<usize>());
+        }
+    }
+
+    #[test]
+    fn test_solve_key_length_first() {
+        let normalized = LowercaseString::normalize(SONNET).to_string();
+        let encrypted = encrypt(SONNET, "bird").unwrap();
+        let results = solve_key_length_first(&encrypted, 4).unwrap();
+        assert_eq!(results.len(), TOP_PERMUTATIONS);
+        assert!(results.windows(2).all(|pair| pair[0].2 <= pair[1].2));
+
+        let (solved, key, _) = &results[0];
+        assert_eq!(
+            key,
+            &Permutation::from_keyword("bird").unwrap().to_keyword()
+        );
+        assert_eq!(key, "acdb");
+        assert!(solved.starts_with(&normalized));
+        assert_eq!(decrypt(&encrypted, key).unwrap(), *solved);
+
+        assert_eq!(solve_key_length_first(&encrypted, 2).unwrap().len(), 2);
+        assert_eq!(
+            solve_key_length_first(&encrypted, 8),
+            Err(ColumnarError::KeyLengthTooLarge { max: 7 })
+        );
+        assert_eq!(
+            solve_key_length_first(&encrypted, 0),
+            Err(ColumnarError::EmptyKeyword)
+        );
+        assert_eq!(
+            ColumnarError::KeyLengthTooLarge { max: 7 }.to_string(),
+            "key length is larger than the maximum of 7"
+        );
+    }
+}
+
+#[cfg(test)]
+mod prop_roundtrip {
+    use super::*;
+    use crate::test_data::{random_keyword, random_text};
+
+    #[test]
+    fn encrypt_decrypt() {
+        let mut rng = Rng::new(17);
+        for _ in 0..1000 {
+            let plain = random_text(&mut rng, 50);
+            let keyword = random_keyword(&mut rng, 10);
+            let normalized = LowercaseString::normalize(&plain).to_string();
+            let encrypted = encrypt(&plain, &keyword).unwrap();
+            let padding = encrypted.len() - normalized.len();
+            assert!(padding < keyword.len());
+            assert_eq!(
+                decrypt(&encrypted, &keyword),
+                Some(normalized + &"x".repeat(padding))
+            );
+        }
+    }
+}
@@ -0,0 +1,845 @@
+/// Counts of the 5,000 most common quadgrams (four-letter sequences) in the
+/// prose of the Rust documentation (the book, the reference and the other
+/// guides shipped with the toolchain), most common first. Letters are
+/// counted across word boundaries within each paragraph, and code samples
+/// are excluded.
+///
+/// Technical vocabulary is overrepresented compared with general English:
+/// "type" and "rust" are among the 30 most common quadgrams.
+#[rustfmt::skip]
+pub(crate) static DOCS_QUADGRAMS: [(&str, u32); 5000] = [
+    ("tion", 16292), ("that", 7806), ("this", 7221), ("with", 7076), ("ethe", 6681), ("atio", 6677),
+    ("nthe", 6556), ("ther", 6438), ("ment", 6407), ("sthe", 5574), ("thes", 5428), ("ions", 5387),
+    ("fthe", 5309), ("type", 4854), ("thec", 4801), ("ofth", 4770), ("ctio", 4718), ("mple", 4692),
+    ("able", 4648), ("ingt", 4568), ("othe", 4562), ("ting", 4494), ("inth", 4326), ("here", 4269),
+    ("tthe", 4177), ("thef", 3999), ("rust", 3963), ("sion", 3887), ("will", 3641), ("thet", 3568),
+    ("ngth", 3462), ("valu", 3404), ("code", 3389), ("comp", 3382), ("rate", 3338), ("alue", 3176),
+    ("expr", 3021), ("sing", 3016), ("ture", 3006), ("arge", 2945), ("emen", 2911), ("targ", 2880),
+    ("esth", 2872), ("rget", 2845), ("name", 2836), ("ncti", 2819), ("unct", 2817), ("inga", 2812),
+    ("inte", 2783), ("func", 2782), ("edto", 2765), ("gthe", 2732), ("form", 2725), ("test", 2716),
+    ("toth", 2701), ("spec", 2686), ("dthe", 2683), ("when", 2679), ("sand", 2627), ("efor", 2614),
+    ("call", 2606), ("eren", 2583), ("ampl", 2544), ("exam", 2526), ("rthe", 2515), ("heco", 2507),
+    ("sare", 2505), ("tern", 2504), ("xamp", 2503), ("file", 2490), ("thep", 2488), ("tati", 2456),
+    ("stha", 2445), ("pres", 2442), ("inst", 2441), ("them", 2433), ("fere", 2388), ("enta", 2376),
+    ("peci", 2371), ("llow", 2353), ("itio", 2347), ("ding", 2337), ("onth", 2309), ("buil", 2309),
+    ("ally", 2304), ("then", 2295), ("impl", 2287), ("trib", 2273), ("hich", 2255), ("whic", 2254),
+    ("ribu", 2250), ("ibut", 2246), ("have", 2231), ("ress", 2227), ("essi", 2214), ("bute", 2184),
+    ("iono", 2162), ("from", 2161), ("thea", 2159), ("time", 2151), ("crat", 2151), ("thel", 2143),
+    ("stru", 2142), ("ated", 2138), ("ecif", 2127), ("ompi", 2112), ("mpil", 2112), ("nter", 2111),
+    ("carg", 2111), ("edin", 2102), ("fore", 2089), ("rent", 2080), ("argo", 2077), ("orth", 2075),
+    ("erat", 2067), ("iona", 2061), ("port", 2059), ("ence", 2054), ("ents", 2049), ("stin", 2049),
+    ("etha", 2039), ("ould", 2017), ("hthe", 2010), ("item", 2002), ("usin", 2000), ("eand", 1986),
+    ("ruct", 1985), ("truc", 1984), ("ende", 1979), ("cont", 1967), ("ioni", 1962), ("thee", 1953),
+    ("thei", 1951), ("uild", 1948), ("enti", 1947), ("andt", 1943), ("ecom", 1938), ("iont", 1934),
+    ("trai", 1931), ("umen", 1922), ("ever", 1920), ("used", 1919), ("thth", 1913), ("itht", 1907),
+    ("leme", 1906), ("ssio", 1903), ("pend", 1888), ("fort", 1887), ("dwit", 1870), ("xpre", 1866),
+    ("atth", 1854), ("refe", 1852), ("stri", 1847), ("efer", 1843), ("ings", 1838), ("vari", 1837),
+    ("econ", 1835), ("ersi", 1831), ("ntat", 1819), ("thed", 1806), ("theb", 1798), ("ates", 1795),
+    ("diti", 1793), ("epen", 1788), ("hese", 1784), ("depe", 1780), ("allo", 1768), ("ifie", 1765),
+    ("renc", 1764), ("vers", 1752), ("edwi", 1750), ("list", 1744), ("efin", 1741), ("pile", 1736),
+    ("ndth", 1736), ("rait", 1722), ("atte", 1718), ("only", 1716), ("erth", 1715), ("orma", 1707),
+    ("ring", 1704), ("rati", 1693), ("ning", 1683), ("stan", 1683), ("atur", 1682), ("onst", 1676),
+    ("ttri", 1671), ("aria", 1668), ("attr", 1667), ("ecti", 1662), ("plem", 1658), ("sfor", 1650),
+    ("nfor", 1650), ("anda", 1646), ("tems", 1641), ("nden", 1638), ("tter", 1633), ("defi", 1627),
+    ("comm", 1626), ("tabl", 1623), ("work", 1620), ("euse", 1609), ("isti", 1597), ("onof", 1586),
+    ("requ", 1574), ("ythe", 1570), ("nsta", 1566), ("cons", 1564), ("ptio", 1559), ("chan", 1558),
+    ("theo", 1557), ("tand", 1553), ("gene", 1549), ("cifi", 1546), ("hatt", 1541), ("ctor", 1537),
+    ("ener", 1536), ("rect", 1530), ("more", 1524), ("also", 1518), ("ingi", 1515), ("meth", 1512),
+    ("reth", 1509), ("tera", 1508), ("rsio", 1499), ("ypes", 1499), ("your", 1495), ("ures", 1495),
+    ("eint", 1484), ("canb", 1481), ("denc", 1478), ("seth", 1476), ("snot", 1468), ("over", 1461),
+    ("ntro", 1451), ("equi", 1450), ("eatu", 1450), ("ethi", 1445), ("feat", 1445), ("youc", 1444),
+    ("eoft", 1442), ("ause", 1436), ("anbe", 1436), ("opti", 1431), ("supp", 1427), ("enam", 1420),
+    ("acka", 1418), ("pack", 1410), ("ange", 1408), ("ault", 1402), ("faul", 1401), ("some", 1400),
+    ("defa", 1397), ("edby", 1393), ("efau", 1392), ("ames", 1385), ("kage", 1384), ("oper", 1381),
+    ("tfor", 1377), ("sint", 1376), ("than", 1374), ("thin", 1371), ("ckag", 1371), ("eval", 1368),
+    ("etim", 1367), ("estr", 1364), ("read", 1361), ("patt", 1359), ("eral", 1354), ("uppo", 1351),
+    ("ppor", 1347), ("ouca", 1344), ("swit", 1341), ("oint", 1338), ("uset", 1333), ("acro", 1328),
+    ("cess", 1325), ("itha", 1324), ("caus", 1321), ("etyp", 1319), ("fyou", 1317), ("like", 1294),
+    ("ntto", 1294), ("libr", 1289), ("need", 1283), ("para", 1282), ("thev", 1282), ("quir", 1282),
+    ("rmat", 1281), ("hefi", 1264), ("isno", 1264), ("ollo", 1261), ("gram", 1260), ("ters", 1258),
+    ("epro", 1257), ("foll", 1254), ("edit", 1251), ("hang", 1248), ("prog", 1247), ("rogr", 1247),
+    ("flag", 1245), ("uire", 1243), ("ndin", 1231), ("path", 1226), ("edth", 1221), ("king", 1220),
+    ("tain", 1216), ("ogra", 1215), ("irec", 1215), ("erro", 1213), ("macr", 1212), ("dint", 1207),
+    ("heli", 1207), ("esin", 1197), ("esta", 1197), ("rror", 1196), ("lock", 1196), ("dire", 1193),
+    ("link", 1192), ("same", 1191), ("ifyo", 1191), ("hesa", 1187), ("mati", 1185), ("prov", 1183),
+    ("etho", 1181), ("rary", 1181), ("ecto", 1177), ("tsth", 1176), ("docu", 1176), ("ocum", 1175),
+    ("cume", 1173), ("esto", 1171), ("enth", 1168), ("amet", 1163), ("hest", 1160), ("lint", 1157),
+    ("sure", 1156), ("turn", 1154), ("esan", 1153), ("ibra", 1153), ("poin", 1151), ("brar", 1150),
+    ("fied", 1143), ("onfi", 1142), ("espe", 1141), ("stat", 1135), ("conf", 1135), ("star", 1132),
+    ("teth", 1130), ("istr", 1130), ("fine", 1129), ("esam", 1129), ("iabl", 1128), ("erus", 1126),
+    ("ingo", 1125), ("ound", 1124), ("pera", 1123), ("hati", 1121), ("urre", 1120), ("etar", 1120),
+    ("ulti", 1119), ("iter", 1118), ("onsi", 1116), ("orex", 1115), ("reat", 1114), ("icat", 1112),
+    ("eter", 1108), ("ests", 1108), ("isth", 1105), ("rren", 1103), ("atch", 1102), ("riab", 1101),
+    ("beca", 1100), ("curr", 1098), ("utth", 1097), ("ecan", 1096), ("note", 1089), ("synt", 1085),
+    ("ynta", 1083), ("owin", 1080), ("hisi", 1079), ("ingc", 1078), ("ling", 1072), ("ands", 1071),
+    ("thod", 1071), ("epar", 1069), ("ifth", 1068), ("ucan", 1065), ("lues", 1064), ("intr", 1063),
+    ("ewit", 1059), ("etur", 1055), ("ical", 1052), ("rexa", 1051), ("ntax", 1051), ("hefu", 1048),
+    ("into", 1048), ("onal", 1047), ("ield", 1044), ("ouse", 1043), ("wher", 1042), ("retu", 1041),
+    ("elin", 1038), ("hing", 1038), ("ecau", 1036), ("diff", 1033), ("hepr", 1032), ("line", 1032),
+    ("ourc", 1028), ("ffer", 1027), ("cati", 1024), ("utes", 1020), ("heva", 1018), ("efun", 1015),
+    ("lude", 1015), ("ages", 1013), ("chec", 1011), ("safe", 1011), ("heta", 1008), ("fiel", 1007),
+    ("andi", 1003), ("clud", 1002), ("ined", 1001), ("heck", 1001), ("hede", 998), ("noft", 998),
+    ("wing", 993), ("want", 993), ("hepa", 988), ("iler", 986), ("rest", 985), ("ject", 983),
+    ("nthi", 983), ("such", 973), ("ecod", 972), ("fora", 972), ("anin", 972), ("incl", 971),
+    ("gets", 969), ("esar", 968), ("iffe", 968), ("itis", 968), ("tive", 968), ("bles", 967),
+    ("nclu", 967), ("tobe", 967), ("nste", 966), ("side", 965), ("dfor", 963), ("tall", 960),
+    ("rint", 958), ("rovi", 958), ("hisc", 957), ("ehav", 957), ("nfig", 954), ("spac", 953),
+    ("tore", 952), ("each", 951), ("scop", 947), ("shou", 947), ("tead", 946), ("houl", 943),
+    ("cope", 938), ("antt", 938), ("stea", 938), ("nera", 938), ("hefo", 935), ("ionf", 934),
+    ("ntai", 933), ("loca", 933), ("soft", 933), ("outp", 932), ("epre", 931), ("show", 931),
+    ("crea", 929), ("thou", 929), ("plic", 929), ("matc", 927), ("nint", 926), ("ovid", 924),
+    ("must", 924), ("entt", 921), ("indi", 920), ("alle", 919), ("bloc", 919), ("atin", 918),
+    ("ight", 917), ("tool", 915), ("eref", 914), ("data", 912), ("leth", 912), ("essa", 910),
+    ("ator", 906), ("toma", 905), ("lowi", 904), ("ance", 903), ("pace", 903), ("vide", 900),
+    ("init", 899), ("writ", 895), ("ingw", 891), ("onsa", 890), ("nabl", 889), ("utab", 889),
+    ("tput", 888), ("plet", 888), ("exte", 887), ("scan", 885), ("isis", 884), ("tedw", 882),
+    ("rese", 882), ("rame", 879), ("nsth", 878), ("tedt", 878), ("ingl", 878), ("inar", 877),
+    ("itho", 875), ("they", 874), ("utpu", 869), ("hebo", 869), ("cate", 869), ("hand", 868),
+    ("trin", 867), ("tous", 865), ("nmen", 865), ("tory", 865), ("enum", 864), ("info", 864),
+    ("ngan", 863), ("swhe", 862), ("edef", 861), ("main", 859), ("nyou", 858), ("ngto", 857),
+    ("bina", 855), ("mthe", 853), ("avea", 853), ("reso", 853), ("hata", 852), ("scri", 848),
+    ("nstr", 846), ("erto", 842), ("sign", 840), ("tate", 840), ("clos", 840), ("eate", 838),
+    ("ries", 838), ("make", 837), ("romt", 832), ("ible", 831), ("aram", 829), ("ndar", 829),
+    ("thre", 829), ("enab", 828), ("rand", 827), ("mete", 826), ("iles", 826), ("niti", 825),
+    ("illb", 823), ("atic", 823), ("ionw", 820), ("asth", 819), ("llbe", 819), ("iple", 818),
+    ("tcon", 817), ("blet", 817), ("hent", 817), ("noth", 816), ("case", 815), ("expl", 815),
+    ("etra", 815), ("enci", 814), ("tthi", 812), ("arch", 811), ("regi", 810), ("rodu", 809),
+    ("oduc", 809), ("onta", 808), ("ntin", 807), ("part", 807), ("ingf", 805), ("ucha", 805),
+    ("outt", 805), ("ecur", 803), ("erea", 803), ("ocat", 802), ("book", 800), ("epat", 799),
+    ("mber", 798), ("swil", 798), ("life", 798), ("hist", 796), ("erna", 796), ("hein", 796),
+    ("irst", 795), ("eres", 793), ("eare", 792), ("mand", 792), ("lity", 790), ("firs", 789),
+    ("sthi", 788), ("mult", 787), ("dent", 787), ("orre", 786), ("iate", 785), ("aren", 783),
+    ("efol", 783), ("nota", 782), ("ency", 782), ("erns", 782), ("does", 781), ("abou", 778),
+    ("cies", 777), ("edas", 775), ("havi", 775), ("eedt", 775), ("ifet", 775), ("rtha", 773),
+    ("feti", 773), ("andl", 772), ("ract", 769), ("befo", 768), ("eexp", 766), ("ncie", 766),
+    ("bein", 764), ("isco", 762), ("suse", 762), ("hema", 760), ("imes", 760), ("onin", 758),
+    ("mean", 758), ("dand", 758), ("toft", 758), ("nand", 757), ("ough", 754), ("acce", 754),
+    ("mess", 753), ("gist", 753), ("omth", 751), ("dist", 750), ("area", 750), ("nces", 750),
+    ("atet", 749), ("rfor", 749), ("chas", 747), ("lowe", 746), ("lite", 745), ("eric", 744),
+    ("esof", 743), ("emor", 743), ("nsid", 740), ("weca", 736), ("onis", 734), ("hena", 733),
+    ("bout", 733), ("andc", 733), ("rein", 732), ("ltip", 732), ("tipl", 732), ("woul", 732),
+    ("aybe", 731), ("egis", 731), ("otha", 729), ("heru", 729), ("allt", 729), ("mayb", 728),
+    ("pass", 727), ("beus", 726), ("anex", 725), ("tedi", 724), ("argu", 723), ("sage", 722),
+    ("eran", 721), ("ngin", 721), ("ormo", 720), ("ains", 719), ("arti", 719), ("addi", 719),
+    ("eyou", 717), ("rgum", 717), ("gume", 717), ("long", 716), ("esno", 713), ("ecra", 713),
+    ("uses", 712), ("uted", 712), ("ithi", 711), ("eatt", 711), ("orde", 709), ("hatc", 708),
+    ("duce", 707), ("orin", 707), ("toco", 707), ("edfo", 707), ("omma", 704), ("bili", 704),
+    ("beha", 704), ("ustc", 703), ("osur", 703), ("hety", 702), ("what", 701), ("cept", 700),
+    ("ects", 699), ("losu", 699), ("deth", 698), ("dded", 698), ("esti", 696), ("eeth", 695),
+    ("modu", 695), ("hatw", 694), ("llin", 694), ("atea", 694), ("lthe", 693), ("dule", 693),
+    ("fini", 692), ("sinc", 692), ("odul", 691), ("reco", 690), ("heme", 687), ("ntha", 686),
+    ("twil", 686), ("tert", 685), ("inde", 685), ("unde", 684), ("sibl", 684), ("edon", 682),
+    ("heex", 681), ("hene", 680), ("decl", 679), ("ules", 678), ("toke", 678), ("owed", 677),
+    ("ntst", 677), ("resu", 677), ("eboo", 677), ("even", 675), ("lled", 673), ("eins", 673),
+    ("seet", 673), ("ontr", 673), ("repr", 672), ("ente", 671), ("ples", 671), ("tica", 670),
+    ("syou", 669), ("etai", 668), ("onte", 668), ("erin", 667), ("asse", 667), ("inli", 667),
+    ("tint", 665), ("twit", 664), ("hert", 664), ("entv", 662), ("onto", 661), ("reme", 661),
+    ("leto", 660), ("cces", 660), ("rede", 659), ("ared", 658), ("very", 658), ("neri", 657),
+    ("tail", 656), ("cond", 654), ("simp", 654), ("oken", 653), ("rato", 653), ("stor", 652),
+    ("odet", 650), ("hisf", 650), ("scon", 648), ("sear", 648), ("take", 647), ("sedt", 647),
+    ("stra", 645), ("onme", 645), ("tect", 645), ("ewhe", 644), ("hout", 643), ("ctur", 641),
+    ("deta", 639), ("uret", 639), ("nnot", 638), ("ntth", 638), ("isto", 636), ("proc", 636),
+    ("stem", 636), ("erst", 635), ("ingr", 635), ("onfo", 633), ("avio", 633), ("lena", 632),
+    ("hecu", 632), ("appe", 632), ("plac", 631), ("ored", 631), ("ooki", 631), ("tpro", 630),
+    ("mman", 630), ("figu", 629), ("oret", 629), ("ssib", 629), ("orks", 629), ("ilit", 628),
+    ("ecut", 627), ("igur", 627), ("alin", 627), ("andr", 626), ("hetr", 626), ("nuse", 626),
+    ("inge", 624), ("aint", 623), ("look", 622), ("hisw", 622), ("nati", 622), ("tcan", 621),
+    ("exec", 621), ("xecu", 621), ("rmor", 621), ("erei", 620), ("nall", 620), ("byth", 619),
+    ("atis", 619), ("icit", 619), ("chap", 619), ("apte", 619), ("youm", 618), ("esen", 617),
+    ("ldin", 617), ("arat", 616), ("erfo", 616), ("soth", 616), ("llth", 615), ("pter", 615),
+    ("eret", 614), ("alli", 614), ("lici", 614), ("alid", 614), ("revi", 614), ("leas", 611),
+    ("efil", 611), ("lati", 611), ("vent", 610), ("ngco", 610), ("inal", 610), ("vali", 610),
+    ("appl", 610), ("ails", 610), ("give", 609), ("envi", 609), ("nvir", 609), ("hrea", 608),
+    ("move", 608), ("ndit", 607), ("heen", 606), ("ronm", 606), ("tyou", 605), ("ossi", 605),
+    ("vior", 604), ("iron", 604), ("hecr", 603), ("ebui", 603), ("nexp", 602), ("viro", 602),
+    ("char", 601), ("ondi", 601), ("echa", 601), ("yste", 601), ("omat", 600), ("lace", 599),
+    ("eani", 599), ("lest", 598), ("sour", 597), ("runt", 597), ("hapt", 597), ("eque", 597),
+    ("poss", 596), ("ecla", 595), ("cify", 595), ("earc", 594), ("clar", 593), ("anno", 592),
+    ("ific", 592), ("rule", 592), ("ionc", 588), ("reis", 588), ("enot", 588), ("syst", 588),
+    ("inan", 587), ("iste", 587), ("muta", 585), ("isal", 584), ("ingd", 584), ("orte", 584),
+    ("onan", 583), ("onso", 583), ("tent", 583), ("aila", 583), ("edan", 582), ("trac", 582),
+    ("tors", 582), ("uldb", 581), ("prod", 579), ("ldbe", 579), ("esse", 578), ("term", 578),
+    ("adde", 577), ("esol", 577), ("andw", 576), ("yout", 575), ("lyth", 571), ("nted", 571),
+    ("veth", 571), ("thew", 570), ("asin", 570), ("know", 570), ("umbe", 570), ("ilds", 570),
+    ("ares", 569), ("tial", 569), ("ssag", 568), ("toan", 568), ("ewil", 567), ("ilen", 565),
+    ("numb", 565), ("sect", 565), ("emet", 565), ("egen", 565), ("stab", 565), ("hose", 564),
+    ("sent", 564), ("nger", 564), ("mpor", 563), ("resp", 563), ("vert", 562), ("ript", 562),
+    ("eisa", 561), ("arie", 561), ("ilab", 561), ("avet", 560), ("ctly", 560), ("ando", 559),
+    ("ndex", 558), ("stot", 558), ("ssin", 557), ("lean", 557), ("crip", 557), ("rthi", 556),
+    ("eimp", 556), ("labl", 556), ("uall", 555), ("cros", 555), ("akes", 555), ("avai", 555),
+    ("vail", 555), ("thas", 554), ("oesn", 552), ("esca", 552), ("prin", 550), ("dete", 550),
+    ("usea", 549), ("eadi", 549), ("ttin", 547), ("ione", 546), ("ingb", 546), ("sult", 545),
+    ("proj", 544), ("roje", 544), ("ojec", 544), ("tcom", 544), ("xter", 544), ("eall", 542),
+    ("rnin", 542), ("word", 541), ("urce", 541), ("smay", 541), ("efla", 541), ("esec", 539),
+    ("itin", 539), ("ying", 539), ("ttha", 539), ("teda", 539), ("tart", 538), ("theu", 538),
+    ("rian", 538), ("memo", 538), ("late", 538), ("ydef", 537), ("dest", 537), ("sett", 537),
+    ("ande", 536), ("ered", 536), ("rite", 536), ("ways", 536), ("onge", 535), ("nary", 533),
+    ("dbyt", 532), ("stal", 532), ("esul", 531), ("boun", 531), ("erwi", 531), ("evel", 530),
+    ("ithe", 529), ("sall", 529), ("asso", 529), ("nsaf", 529), ("ceth", 528), ("eswi", 528),
+    ("eman", 527), ("roce", 527), ("ento", 527), ("ecal", 524), ("efir", 524), ("entl", 524),
+    ("howe", 524), ("esco", 524), ("user", 523), ("rted", 523), ("assi", 522), ("oran", 522),
+    ("owth", 522), ("ista", 522), ("bled", 522), ("nder", 521), ("esfo", 521), ("eout", 521),
+    ("pect", 521), ("chin", 521), ("toch", 520), ("onwi", 519), ("isin", 519), ("ncet", 518),
+    ("orts", 518), ("tsin", 517), ("olon", 517), ("ubli", 517), ("ases", 516), ("fier", 516),
+    ("erve", 516), ("nded", 516), ("stco", 515), ("lect", 515), ("aits", 515), ("scom", 515),
+    ("publ", 515), ("dard", 514), ("been", 514), ("sedi", 513), ("reno", 512), ("afte", 511),
+    ("atem", 511), ("fter", 510), ("keth", 508), ("owev", 508), ("weve", 508), ("notb", 508),
+    ("geth", 507), ("ersa", 507), ("atar", 505), ("orei", 505), ("temp", 504), ("erof", 504),
+    ("aret", 503), ("ease", 503), ("remo", 503), ("eedi", 503), ("orea", 502), ("odei", 502),
+    ("iden", 502), ("ildi", 502), ("reas", 501), ("tanc", 501), ("mory", 500), ("nten", 499),
+    ("tche", 499), ("ustb", 498), ("onse", 498), ("unti", 498), ("ints", 497), ("arto", 497),
+    ("ompa", 497), ("howt", 496), ("hasa", 496), ("ides", 495), ("slik", 494), ("issu", 494),
+    ("isca", 492), ("uest", 492), ("astr", 492), ("rder", 491), ("ntot", 491), ("cove", 491),
+    ("sfro", 491), ("etot", 491), ("gett", 490), ("tuse", 490), ("fixe", 490), ("next", 489),
+    ("corr", 488), ("swhi", 487), ("ider", 487), ("einf", 487), ("nteg", 487), ("isre", 486),
+    ("forc", 486), ("lang", 485), ("heir", 485), ("emov", 485), ("tori", 485), ("auto", 484),
+    ("ichi", 483), ("nsan", 483), ("nlis", 483), ("ermi", 483), ("theg", 483), ("reca", 482),
+    ("ocon", 482), ("heth", 481), ("copy", 481), ("rdis", 480), ("ngwi", 480), ("eswh", 480),
+    ("tisa", 479), ("ithr", 479), ("itte", 479), ("seto", 478), ("just", 478), ("tsto", 478),
+    ("youh", 477), ("iant", 477), ("ocal", 477), ("lein", 476), ("inin", 476), ("isan", 476),
+    ("ythi", 476), ("usta", 476), ("eset", 476), ("disc", 475), ("text", 474), ("stof", 474),
+    ("trol", 474), ("heat", 474), ("tver", 474), ("ywor", 473), ("aval", 472), ("byde", 471),
+    ("loop", 471), ("odec", 470), ("amed", 470), ("vean", 470), ("sist", 470), ("rall", 470),
+    ("aths", 470), ("date", 470), ("acti", 469), ("mesr", 468), ("dere", 468), ("lpro", 468),
+    ("hain", 468), ("ssoc", 468), ("ckin", 467), ("unsa", 467), ("ativ", 467), ("spla", 467),
+    ("lcha", 466), ("heca", 465), ("anew", 465), ("ouha", 465), ("youw", 465), ("hete", 465),
+    ("spro", 464), ("scod", 464), ("chai", 464), ("otet", 463), ("heop", 463), ("eexa", 462),
+    ("tare", 462), ("edat", 462), ("ners", 462), ("isus", 462), ("soci", 462), ("ocia", 462),
+    ("nnin", 461), ("ypei", 461), ("pdat", 461), ("erre", 461), ("find", 461), ("eing", 460),
+    ("ciat", 460), ("rict", 460), ("teri", 459), ("runn", 459), ("lyin", 459), ("ngle", 459),
+    ("usei", 458), ("nwit", 458), ("ving", 458), ("disp", 458), ("nrus", 457), ("oneo", 457),
+    ("sest", 457), ("seco", 457), ("cted", 457), ("ntly", 455), ("ompl", 455), ("otbe", 455),
+    ("tsan", 454), ("gest", 454), ("sted", 454), ("elds", 453), ("prev", 452), ("athe", 452),
+    ("rpro", 452), ("most", 451), ("erne", 451), ("uhav", 451), ("serv", 451), ("upda", 451),
+    ("etes", 451), ("vera", 450), ("warn", 450), ("impo", 449), ("lows", 449), ("onve", 449),
+    ("eass", 449), ("asan", 449), ("rmin", 449), ("esso", 449), ("rele", 449), ("stth", 448),
+    ("onot", 448), ("lesa", 448), ("usto", 448), ("uctu", 448), ("irem", 448), ("ucti", 447),
+    ("tsof", 447), ("utom", 447), ("sync", 447), ("theh", 446), ("utur", 446), ("ized", 446),
+    ("fail", 446), ("play", 445), ("iven", 444), ("embe", 444), ("afun", 443), ("tedb", 443),
+    ("utio", 443), ("futu", 443), ("urat", 443), ("conc", 442), ("esst", 442), ("anot", 442),
+    ("eful", 441), ("thos", 440), ("efea", 440), ("ingp", 440), ("timp", 439), ("etwo", 439),
+    ("ming", 439), ("ngua", 438), ("both", 438), ("gand", 438), ("adif", 438), ("besp", 438),
+    ("ncon", 437), ("atyp", 437), ("nolo", 437), ("angu", 436), ("eite", 436), ("less", 436),
+    ("ngli", 436), ("iled", 436), ("ttoc", 436), ("back", 436), ("efie", 435), ("ntsa", 434),
+    ("stbe", 434), ("conv", 433), ("redi", 433), ("hisa", 433), ("omme", 433), ("endi", 432),
+    ("ofan", 432), ("uple", 432), ("size", 431), ("ecar", 431), ("outo", 430), ("real", 430),
+    ("twor", 430), ("olve", 430), ("disa", 429), ("atha", 429), ("edif", 429), ("ious", 429),
+    ("guag", 427), ("uage", 427), ("keyw", 427), ("sepa", 426), ("ndre", 426), ("itwi", 425),
+    ("edep", 425), ("ppli", 425), ("tesa", 424), ("eofa", 424), ("illp", 424), ("ispl", 424),
+    ("iswi", 423), ("kspa", 423), ("yand", 422), ("dify", 422), ("pani", 422), ("mmen", 422),
+    ("ines", 421), ("ustp", 421), ("thav", 421), ("espa", 420), ("ilet", 420), ("alwa", 420),
+    ("migh", 420), ("fori", 420), ("ssed", 419), ("anic", 419), ("tant", 418), ("esrc", 417),
+    ("neve", 417), ("ingm", 417), ("ypet", 417), ("stoa", 417), ("lway", 417), ("dlib", 417),
+    ("ouma", 417), ("rksp", 417), ("eywo", 416), ("sane", 416), ("epac", 416), ("empt", 415),
+    ("tode", 414), ("heni", 414), ("ngon", 414), ("iest", 414), ("tesi", 414), ("lice", 414),
+    ("ardl", 413), ("arac", 413), ("host", 413), ("onyo", 412), ("ectl", 412), ("toin", 412),
+    ("hint", 412), ("efro", 412), ("nsar", 411), ("andm", 411), ("once", 411), ("tedf", 411),
+    ("ecte", 411), ("helo", 411), ("tsar", 410), ("abil", 410), ("ntim", 410), ("aref", 409),
+    ("esit", 409), ("anal", 409), ("orun", 409), ("mark", 409), ("dedt", 409), ("prop", 408),
+    ("gwit", 408), ("repo", 408), ("unni", 407), ("iket", 407), ("ewhi", 407), ("etti", 407),
+    ("ween", 406), ("enin", 406), ("ryth", 406), ("tupl", 406), ("rear", 404), ("llpr", 404),
+    ("eado", 404), ("dtha", 404), ("rcod", 404), ("umay", 404), ("dtot", 403), ("ssue", 403),
+    ("seof", 403), ("grat", 403), ("evar", 402), ("etth", 402), ("eris", 402), ("reve", 402),
+    ("lica", 402), ("stpr", 401), ("nceo", 401), ("hism", 401), ("dica", 401), ("nges", 401),
+    ("onne", 401), ("oolc", 401), ("hare", 400), ("acte", 400), ("ddit", 400), ("tvar", 400),
+    ("eith", 399), ("epla", 399), ("teme", 399), ("twee", 399), ("nnec", 399), ("eopt", 399),
+    ("henu", 398), ("ixed", 398), ("ntva", 397), ("dval", 397), ("wind", 397), ("hedi", 396),
+    ("leti", 396), ("hrus", 396), ("elea", 396), ("expe", 395), ("etwe", 395), ("enco", 395),
+    ("usef", 394), ("rack", 393), ("alls", 393), ("roma", 393), ("hera", 393), ("omet", 393),
+    ("gura", 393), ("ndef", 392), ("pare", 392), ("nerr", 392), ("betw", 392), ("luei", 392),
+    ("ster", 392), ("esli", 391), ("edde", 391), ("eope", 391), ("fact", 391), ("emod", 391),
+    ("rrow", 390), ("indo", 390), ("cter", 390), ("ngis", 389), ("hefl", 389), ("etoa", 388),
+    ("dtoa", 388), ("simi", 388), ("imil", 388), ("mila", 388), ("ilar", 388), ("exce", 388),
+    ("oces", 388), ("aces", 388), ("cann", 388), ("olch", 388), ("thek", 386), ("dthi", 386),
+    ("aryt", 386), ("ekey", 385), ("atco", 385), ("tual", 385), ("resa", 385), ("erri", 385),
+    ("bjec", 385), ("gthi", 384), ("perf", 384), ("verr", 384), ("ithd", 384), ("rnal", 384),
+    ("none", 383), ("ndle", 383), ("erun", 383), ("thru", 383), ("exis", 383), ("hara", 383),
+    ("roug", 382), ("dedi", 382), ("erdi", 382), ("ford", 382), ("iond", 381), ("hats", 381),
+    ("down", 381), ("edoc", 381), ("capt", 381), ("aptu", 381), ("ptur", 381), ("prec", 380),
+    ("ired", 380), ("blef", 380), ("eclo", 380), ("sfla", 380), ("utin", 379), ("othi", 379),
+    ("dwhe", 379), ("nali", 379), ("lloc", 379), ("dtyp", 379), ("toru", 379), ("arra", 379),
+    ("escr", 379), ("eans", 378), ("hean", 378), ("sofa", 378), ("esho", 378), ("atei", 378),
+    ("ssho", 378), ("arni", 378), ("onif", 378), ("ngit", 377), ("emac", 377), ("ngas", 377),
+    ("illn", 377), ("mina", 376), ("ndso", 376), ("ceof", 376), ("load", 376), ("wthe", 375),
+    ("ensu", 375), ("xpli", 375), ("inta", 375), ("plei", 374), ("ypea", 374), ("debu", 374),
+    ("eloc", 374), ("nsin", 373), ("edis", 373), ("nari", 373), ("andf", 373), ("many", 372),
+    ("ainr", 372), ("itse", 372), ("este", 372), ("ingu", 372), ("orki", 372), ("xist", 372),
+    ("orro", 372), ("etec", 372), ("onwh", 371), ("insi", 371), ("onex", 371), ("hemo", 370),
+    ("ustt", 370), ("edwh", 370), ("nite", 370), ("ommo", 370), ("deri", 369), ("endo", 369),
+    ("rtyp", 369), ("ssth", 369), ("till", 369), ("eadd", 368), ("lewi", 368), ("stry", 368),
+    ("xcep", 368), ("rtof", 367), ("hast", 367), ("herw", 367), ("borr", 367), ("sequ", 367),
+    ("solv", 367), ("eent", 366), ("hath", 366), ("ourp", 366), ("ndli", 366), ("stom", 366),
+    ("isfl", 366), ("mani", 366), ("okin", 365), ("eser", 365), ("xpla", 365), ("sina", 364),
+    ("hods", 364), ("eads", 364), ("atca", 363), ("ionp", 363), ("yfor", 363), ("ular", 363),
+    ("imit", 363), ("neof", 362), ("gfor", 362), ("stil", 362), ("bleo", 362), ("rver", 362),
+    ("rray", 362), ("rsta", 361), ("ater", 361), ("uilt", 361), ("ewor", 360), ("ntoa", 360),
+    ("cana", 360), ("ramm", 360), ("useo", 360), ("dtob", 360), ("erta", 360), ("deve", 360),
+    ("ebug", 360), ("tmay", 360), ("esou", 359), ("henw", 359), ("ttyp", 359), ("beco", 359),
+    ("odif", 359), ("slin", 359), ("lags", 359), ("owne", 358), ("nfin", 358), ("sins", 358),
+    ("andb", 358), ("hear", 358), ("chis", 357), ("ncha", 357), ("mmon", 357), ("rrec", 357),
+    ("ncom", 357), ("nlin", 357), ("tier", 357), ("oche", 356), ("leve", 356), ("nore", 356),
+    ("opro", 356), ("siti", 356), ("self", 355), ("edet", 355), ("esyn", 355), ("rdli", 355),
+    ("dont", 354), ("anat", 354), ("asyn", 354), ("nedi", 353), ("esee", 353), ("erep", 353),
+    ("ssuc", 353), ("dein", 353), ("ocom", 353), ("elat", 353), ("hyou", 353), ("layo", 353),
+    ("pest", 352), ("nval", 352), ("mpli", 352), ("adof", 352), ("lere", 352), ("veri", 352),
+    ("inru", 351), ("sexp", 351), ("eane", 351), ("nsur", 351), ("slic", 351), ("conn", 351),
+    ("nect", 351), ("heke", 350), ("edbe", 350), ("ndic", 350), ("hela", 349), ("iesa", 349),
+    ("tifi", 349), ("ucto", 349), ("rsin", 349), ("tric", 349), ("onde", 348), ("inea", 348),
+    ("aner", 348), ("ttot", 348), ("thro", 348), ("ntof", 347), ("aswe", 347), ("sonl", 347),
+    ("elis", 347), ("ided", 347), ("ques", 347), ("rere", 347), ("andp", 347), ("site", 347),
+    ("plat", 347), ("obje", 346), ("hatm", 346), ("sele", 346), ("nots", 346), ("ntia", 345),
+    ("anif", 345), ("fect", 344), ("iony", 344), ("tswi", 344), ("ldsc", 344), ("sdef", 343),
+    ("sexa", 343), ("bind", 343), ("inda", 343), ("ship", 343), ("blei", 343), ("ntde", 343),
+    ("ionb", 342), ("coul", 342), ("dono", 342), ("help", 342), ("acon", 342), ("nals", 341),
+    ("lefo", 341), ("tend", 341), ("hiss", 341), ("owto", 341), ("guar", 341), ("dina", 341),
+    ("velo", 341), ("nify", 341), ("alit", 340), ("esas", 340), ("fest", 340), ("east", 339),
+    ("inrs", 339), ("ante", 339), ("noti", 338), ("erev", 338), ("ount", 338), ("tran", 338),
+    ("redt", 338), ("fica", 338), ("nife", 338), ("alre", 337), ("eonl", 337), ("dsto", 337),
+    ("stre", 337), ("edco", 337), ("hero", 337), ("snol", 337), ("heed", 337), ("ires", 336),
+    ("ersh", 336), ("orus", 336), ("inke", 336), ("twhe", 336), ("inco", 336), ("meof", 336),
+    ("ders", 335), ("andd", 335), ("onen", 335), ("ware", 335), ("geta", 334), ("eist", 334),
+    ("ywan", 334), ("ntif", 334), ("urco", 333), ("heto", 333), ("efut", 333), ("udes", 332),
+    ("lsob", 332), ("olut", 332), ("atfo", 332), ("atwe", 331), ("arec", 331), ("rean", 331),
+    ("qual", 331), ("ifes", 331), ("srcm", 330), ("rcma", 330), ("cmai", 330), ("orto", 330),
+    ("ults", 330), ("rtin", 330), ("hite", 330), ("rkin", 330), ("root", 330), ("espo", 329),
+    ("ants", 329), ("sext", 329), ("sisa", 328), ("ging", 328), ("ntex", 328), ("tely", 328),
+    ("eson", 328), ("ambi", 328), ("ofil", 328), ("nwhi", 327), ("invo", 327), ("eind", 327),
+    ("solu", 327), ("eher", 327), ("ddin", 327), ("mode", 327), ("toen", 326), ("dcon", 326),
+    ("erso", 325), ("illa", 325), ("dfro", 324), ("isab", 324), ("tnot", 324), ("gain", 324),
+    ("dert", 324), ("ydis", 324), ("ngfo", 323), ("aine", 323), ("hrou", 323), ("ives", 323),
+    ("nbeu", 323), ("ches", 323), ("mbig", 323), ("bigu", 323), ("peth", 322), ("canf", 322),
+    ("ctiv", 322), ("kout", 322), ("ores", 321), ("unit", 321), ("terf", 321), ("ndis", 321),
+    ("fthi", 321), ("odef", 321), ("topr", 321), ("lish", 321), ("dscr", 321), ("amea", 320),
+    ("sone", 320), ("lize", 320), ("sefu", 320), ("ison", 320), ("edir", 320), ("trea", 320),
+    ("dasa", 320), ("heri", 320), ("rofi", 320), ("mpat", 320), ("ionr", 319), ("ebut", 319),
+    ("hatr", 319), ("icul", 319), ("reak", 319), ("lybe", 319), ("whil", 318), ("mayw", 318),
+    ("prob", 318), ("akin", 318), ("ispr", 317), ("yuse", 317), ("tsho", 317), ("butt", 317),
+    ("hile", 317), ("emse", 317), ("ionm", 317), ("seda", 317), ("stto", 317), ("urin", 317),
+    ("ayou", 317), ("rrid", 317), ("ecas", 316), ("tofa", 316), ("iedt", 316), ("lyco", 316),
+    ("reus", 316), ("ereg", 316), ("webs", 316), ("rams", 315), ("stst", 315), ("uati", 315),
+    ("gval", 315), ("brea", 315), ("toge", 315), ("spon", 315), ("eusi", 315), ("ecko", 315),
+    ("eals", 314), ("anon", 314), ("orwe", 314), ("sobe", 314), ("fors", 314), ("oadd", 314),
+    ("veto", 314), ("exto", 314), ("stic", 314), ("ffec", 313), ("ncei", 313), ("ivel", 313),
+    ("anti", 313), ("stob", 313), ("afet", 313), ("riti", 313), ("rchi", 313), ("aini", 312),
+    ("enee", 312), ("rela", 312), ("inat", 312), ("bero", 312), ("desc", 312), ("ckou", 312),
+    ("ustd", 311), ("arei", 311), ("anfi", 311), ("dlin", 311), ("posi", 311), ("buti", 310),
+    ("usti", 310), ("tsel", 310), ("yoft", 310), ("hesi", 310), ("anus", 310), ("xten", 310),
+    ("ytha", 309), ("nsof", 309), ("ifyi", 309), ("evia", 309), ("etco", 309), ("drop", 309),
+    ("hisp", 308), ("ason", 308), ("leis", 308), ("ndof", 308), ("rans", 308), ("eali", 308),
+    ("eisn", 308), ("ctth", 307), ("tean", 307), ("seit", 307), ("tose", 307), ("ngru", 307),
+    ("doth", 306), ("blea", 306), ("ordi", 306), ("lueo", 306), ("wner", 306), ("reac", 306),
+    ("viaa", 306), ("rals", 306), ("ssig", 305), ("aiti", 305), ("canu", 305), ("yint", 305),
+    ("rsto", 305), ("modi", 305), ("orta", 304), ("odew", 304), ("texp", 304), ("acts", 304),
+    ("lyon", 304), ("full", 304), ("erel", 304), ("ools", 304), ("atht", 304), ("igna", 303),
+    ("citl", 303), ("llre", 303), ("ryto", 303), ("nget", 303), ("nins", 303), ("cial", 302),
+    ("onsw", 302), ("eany", 302), ("wayt", 302), ("ndst", 302), ("ynam", 302), ("plan", 302),
+    ("nedb", 301), ("nwhe", 301), ("urns", 301), ("anyo", 301), ("bler", 301), ("easo", 301),
+    ("hatd", 300), ("rors", 300), ("dsth", 300), ("epos", 300), ("gnor", 300), ("tifa", 300),
+    ("etof", 299), ("shav", 299), ("houg", 299), ("assu", 299), ("elec", 299), ("xprl", 299),
+    ("etst", 298), ("lesi", 298), ("ceto", 298), ("aywa", 298), ("odes", 298), ("osit", 298),
+    ("atel", 298), ("quen", 298), ("pati", 298), ("blis", 298), ("come", 297), ("tsfo", 297),
+    ("erco", 297), ("kisn", 297), ("xpec", 296), ("styp", 296), ("extr", 296), ("hesy", 296),
+    ("aran", 296), ("igno", 296), ("tege", 296), ("prof", 296), ("peof", 295), ("rece", 295),
+    ("cula", 295), ("pose", 295), ("ibil", 295), ("entw", 295), ("cest", 295), ("hard", 295),
+    ("tics", 295), ("spar", 294), ("onco", 294), ("agai", 294), ("ycon", 294), ("nput", 294),
+    ("erpr", 294), ("stdo", 294), ("ofco", 294), ("ndac", 294), ("ntve", 294), ("atib", 294),
+    ("tsta", 293), ("blem", 293), ("idea", 293), ("ndco", 293), ("toca", 293), ("plie", 293),
+    ("tofi", 292), ("colo", 292), ("ichc", 292), ("sont", 292), ("ught", 292), ("orar", 292),
+    ("chyo", 292), ("eena", 291), ("cesa", 291), ("luet", 291), ("fyin", 291), ("ntyp", 291),
+    ("ssco", 291), ("seve", 291), ("itth", 291), ("dtoc", 291), ("dnot", 291), ("ebse", 291),
+    ("ypeo", 290), ("ngre", 290), ("orco", 290), ("ypep", 290), ("toac", 290), ("acop", 290),
+    ("vere", 289), ("ppen", 289), ("ewan", 289), ("easi", 289), ("mesp", 289), ("ghtl", 289),
+    ("ince", 289), ("orme", 288), ("andh", 288), ("ectt", 288), ("sedo", 288), ("tero", 288),
+    ("henc", 288), ("okis", 288), ("ecre", 287), ("ueto", 287), ("onca", 287), ("ndan", 287),
+    ("scha", 287), ("olle", 287), ("iers", 287), ("hdoc", 287), ("ersc", 286), ("canc", 286),
+    ("elib", 286), ("tdoe", 286), ("esma", 286), ("viou", 286), ("chit", 285), ("acom", 285),
+    ("urei", 285), ("blew", 285), ("eroo", 285), ("itly", 284), ("asbe", 284), ("tsup", 284),
+    ("onit", 284), ("eger", 284), ("rtif", 284), ("gerd", 284), ("well", 283), ("haty", 283),
+    ("einc", 283), ("ameh", 283), ("ntio", 282), ("wedb", 282), ("ritt", 282), ("orec", 282),
+    ("iert", 282), ("enus", 282), ("dpro", 282), ("bere", 282), ("vect", 282), ("osti", 281),
+    ("plea", 281), ("onas", 281), ("ssee", 281), ("outa", 281), ("aliz", 281), ("ndon", 281),
+    ("rins", 281), ("elop", 281), ("inko", 281), ("sals", 280), ("sava", 280), ("dres", 280),
+    ("utof", 280), ("illc", 280), ("ospe", 280), ("kins", 280), ("inpu", 280), ("rweb", 280),
+    ("lete", 279), ("atra", 279), ("geti", 279), ("edty", 279), ("heus", 279), ("thdo", 279),
+    ("ocks", 279), ("tena", 279), ("hisb", 279), ("tcod", 278), ("odea", 278), ("toha", 278),
+    ("rsth", 278), ("edst", 278), ("isas", 278), ("shar", 278), ("iscu", 278), ("nlya", 278),
+    ("ryin", 278), ("hech", 278), ("iver", 278), ("ilei", 278), ("ifac", 278), ("came", 278),
+    ("daco", 278), ("rsan", 277), ("leof", 277), ("ngst", 277), ("hold", 277), ("itec", 277),
+    ("gtha", 277), ("rchy", 277), ("rgow", 277), ("erca", 276), ("nexa", 276), ("areu", 276),
+    ("omak", 276), ("tcha", 276), ("ride", 276), ("isde", 275), ("anst", 275), ("eend", 275),
+    ("rtic", 275), ("lear", 275), ("eapp", 275), ("erec", 275), ("rope", 275), ("kind", 275),
+    ("tten", 274), ("elem", 274), ("whet", 274), ("ndow", 274), ("lana", 274), ("coun", 274),
+    ("izat", 274), ("zati", 274), ("dout", 274), ("rgot", 274), ("lara", 273), ("todo", 273),
+    ("anyt", 273), ("emai", 273), ("tist", 273), ("oryo", 273), ("oved", 273), ("orti", 273),
+    ("void", 273), ("rnat", 273), ("luti", 273), ("tspe", 273), ("maya", 273), ("enew", 272),
+    ("alco", 272), ("anth", 272), ("isex", 272), ("actu", 272), ("ctua", 272), ("ustu", 272),
+    ("avoi", 272), ("apat", 272), ("eeds", 271), ("hise", 271), ("orms", 271), ("heas", 271),
+    ("atyo", 271), ("inti", 270), ("ppro", 270), ("ypec", 270), ("ehas", 270), ("nadd", 270),
+    ("ectu", 270), ("obes", 270), ("mehe", 270), ("rnet", 270), ("lign", 270), ("lare", 269),
+    ("sabl", 269), ("oset", 269), ("isma", 269), ("ping", 269), ("rres", 269), ("llno", 269),
+    ("netc", 269), ("ncre", 268), ("sual", 268), ("rema", 268), ("ilin", 268), ("illr", 268),
+    ("rath", 268), ("ttoo", 268), ("hesp", 268), ("yals", 268), ("veni", 268), ("issi", 267),
+    ("toad", 267), ("glob", 267), ("etoo", 267), ("owil", 267), ("obui", 267), ("arep", 266),
+    ("hate", 266), ("doft", 266), ("atit", 266), ("eden", 266), ("ryou", 266), ("isli", 266),
+    ("meta", 266), ("srus", 265), ("edfr", 265), ("idet", 265), ("scal", 265), ("agei", 265),
+    ("enwe", 265), ("sein", 265), ("spre", 265), ("onon", 265), ("refo", 264), ("ignm", 264),
+    ("ticu", 264), ("edva", 264), ("appr", 264), ("dins", 264), ("leso", 264), ("nusi", 264),
+    ("lext", 264), ("ebin", 264), ("matt", 264), ("iaal", 264), ("aali", 264), ("pear", 263),
+    ("otat", 263), ("lsth", 263), ("lart", 263), ("htly", 263), ("lyre", 263), ("apro", 262),
+    ("ywit", 262), ("xtra", 262), ("urpr", 262), ("erit", 262), ("mesa", 262), ("ereq", 262),
+    ("nkor", 262), ("korw", 262), ("bsea", 262), ("opyd", 262), ("gnme", 261), ("ifyt", 261),
+    ("ucts", 261), ("eoth", 261), ("tere", 261), ("ache", 261), ("tabi", 261), ("hecl", 261),
+    ("bers", 261), ("ucam", 261), ("pydi", 261), ("cand", 260), ("nimp", 260), ("entd", 260),
+    ("ensi", 260), ("tedo", 260), ("base", 260), ("gint", 260), ("uali", 260), ("clib", 260),
+    ("onre", 259), ("rcon", 259), ("ngwh", 259), ("itia", 258), ("wewa", 258), ("fyth", 258),
+    ("roun", 258), ("rove", 258), ("erty", 258), ("sadd", 258), ("duse", 258), ("pped", 258),
+    ("bove", 258), ("ceco", 257), ("desi", 257), ("owst", 257), ("ofin", 257), ("tobu", 257),
+    ("hasb", 257), ("thto", 257), ("dege", 257), ("ybes", 257), ("abov", 257), ("sbut", 256),
+    ("ntso", 256), ("llst", 256), ("were", 256), ("pref", 256), ("limi", 256), ("emem", 256),
+    ("ctin", 256), ("stoc", 256), ("eblo", 255), ("pleo", 255), ("ntal", 255), ("rant", 255),
+    ("alig", 255), ("docs", 255), ("nven", 254), ("nest", 254), ("ueof", 254), ("tisn", 254),
+    ("ferr", 254), ("weha", 254), ("yare", 254), ("llyi", 254), ("egra", 254), ("hisl", 254),
+    ("nopt", 254), ("chth", 253), ("hiso", 253), ("nown", 253), ("gcon", 253), ("eiti", 253),
+    ("cust", 253), ("coll", 253), ("odeg", 253), ("ayal", 253), ("tcar", 252), ("teto", 252),
+    ("tost", 252), ("arer", 252), ("itto", 252), ("wise", 252), ("aget", 251), ("ntis", 251),
+    ("heou", 251), ("iali", 251), ("ctst", 251), ("hedo", 251), ("ivat", 251), ("natu", 250),
+    ("ifit", 250), ("sani", 250), ("atre", 250), ("urea", 250), ("onab", 250), ("tosp", 250),
+    ("adin", 250), ("entr", 249), ("andu", 249), ("eati", 249), ("neth", 249), ("mall", 249),
+    ("vely", 249), ("eone", 249), ("leco", 249), ("evio", 249), ("easa", 249), ("ssom", 249),
+    ("ethr", 249), ("scar", 249), ("erse", 249), ("heya", 248), ("asta", 248), ("desa", 248),
+    ("ofor", 248), ("heve", 248), ("ssum", 248), ("nion", 248), ("alif", 248), ("hebu", 248),
+    ("ngex", 247), ("ywhe", 247), ("eava", 247), ("trus", 247), ("sreq", 247), ("byte", 247),
+    ("coer", 247), ("oerc", 247), ("rimi", 247), ("hthi", 246), ("esal", 246), ("iedw", 246),
+    ("ldth", 246), ("toal", 246), ("twhi", 246), ("atal", 246), ("sesa", 246), ("sass", 246),
+    ("esor", 246), ("sers", 246), ("rive", 246), ("latf", 246), ("eerr", 245), ("stac", 245),
+    ("cans", 245), ("robl", 245), ("rang", 245), ("nver", 244), ("onsc", 244), ("exac", 244),
+    ("xact", 244), ("nabo", 244), ("atef", 244), ("heit", 244), ("susi", 244), ("esus", 244),
+    ("igne", 244), ("nsio", 244), ("tdet", 244), ("eady", 243), ("itca", 243), ("ssta", 243),
+    ("oget", 243), ("natt", 243), ("inch", 243), ("emin", 243), ("terr", 243), ("inor", 243),
+    ("lfor", 243), ("rwit", 242), ("oble", 242), ("unio", 242), ("earg", 242), ("unds", 242),
+    ("cuta", 242), ("edpa", 241), ("ecia", 241), ("last", 241), ("epoi", 241), ("ayto", 241),
+    ("ndal", 241), ("elif", 241), ("sean", 240), ("alla", 240), ("nott", 240), ("ndca", 240),
+    ("sbee", 240), ("titi", 240), ("trun", 240), ("stop", 240), ("chma", 240), ("alia", 240),
+    ("swel", 239), ("cein", 239), ("ctsa", 239), ("aree", 239), ("timi", 239), ("egiv", 239),
+    ("ilat", 239), ("rari", 239), ("nsit", 239), ("lias", 239), ("vate", 239), ("trep", 239),
+    ("aset", 238), ("rwhe", 238), ("body", 238), ("eina", 238), ("gnat", 238), ("ndto", 238),
+    ("happ", 238), ("sana", 238), ("hefe", 238), ("eway", 238), ("pila", 238), ("sres", 238),
+    ("deto", 237), ("dare", 237), ("difi", 237), ("mest", 237), ("nost", 237), ("medi", 236),
+    ("nits", 236), ("youa", 236), ("ntan", 236), ("dyou", 236), ("eabo", 236), ("ynot", 236),
+    ("notc", 236), ("tdep", 236), ("ntes", 236), ("rshi", 235), ("akea", 235), ("nsis", 235),
+    ("arts", 235), ("ngde", 235), ("nigh", 235), ("apac", 235), ("esde", 235), ("unst", 235),
+    ("gowi", 235), ("nwin", 235), ("unth", 234), ("ichw", 234), ("tens", 234), ("ustw", 234),
+    ("done", 234), ("grus", 234), ("dbya", 233), ("nsto", 233), ("gina", 233), ("toav", 233),
+    ("intt", 233), ("rtot", 233), ("ofte", 233), ("lyus", 233), ("snow", 233), ("ndif", 233),
+    ("erif", 233), ("nker", 233), ("xtok", 233), ("nswi", 232), ("llya", 232), ("iner", 232),
+    ("ecor", 232), ("scus", 232), ("ropr", 232), ("ghth", 232), ("eces", 232), ("emul", 232),
+    ("toex", 231), ("onsu", 231), ("achi", 231), ("wene", 231), ("esim", 231), ("esre", 231),
+    ("ched", 231), ("blic", 231), ("ecou", 230), ("tsco", 230), ("esig", 230), ("inva", 230),
+    ("reto", 230), ("eadt", 230), ("trod", 230), ("oand", 230), ("drus", 230), ("andn", 230),
+    ("memb", 230), ("roft", 230), ("anit", 230), ("ured", 230), ("ouwa", 229), ("atat", 229),
+    ("enex", 229), ("forr", 229), ("ishe", 229), ("forb", 229), ("nami", 229), ("sali", 229),
+    ("peri", 229), ("ntsi", 228), ("rman", 228), ("uwan", 228), ("ameo", 228), ("reex", 228),
+    ("sgen", 228), ("eenv", 228), ("heor", 227), ("tinc", 227), ("tfro", 227), ("hows", 227),
+    ("eont", 227), ("ased", 227), ("kthe", 227), ("sedw", 227), ("ssar", 227), ("ansi", 227),
+    ("esbe", 226), ("alua", 226), ("ehow", 226), ("sert", 226), ("iths", 226), ("ntee", 226),
+    ("emit", 226), ("sets", 226), ("itiv", 226), ("ulat", 226), ("yous", 225), ("puts", 225),
+    ("ntit", 225), ("dean", 225), ("itso", 225), ("ills", 225), ("rlit", 225), ("etob", 225),
+    ("ncal", 224), ("inas", 224), ("owor", 224), ("onor", 224), ("luat", 224), ("eiss", 224),
+    ("acki", 224), ("ngof", 224), ("hato", 224), ("fall", 224), ("ltin", 224), ("styl", 223),
+    ("atew", 223), ("ohav", 223), ("ifwe", 223), ("heun", 223), ("ortf", 223), ("cano", 223),
+    ("usth", 223), ("hisr", 223), ("esio", 223), ("uenc", 223), ("dows", 223), ("leon", 222),
+    ("terw", 222), ("ttem", 222), ("dsof", 222), ("emay", 222), ("ngen", 222), ("semb", 222),
+    ("ncea", 222), ("edre", 222), ("olor", 222), ("rtar", 222), ("ooka", 221), ("bleb", 221),
+    ("oman", 221), ("tema", 221), ("pesa", 221), ("ngat", 221), ("tchi", 221), ("tesc", 221),
+    ("erwh", 220), ("epri", 220), ("atus", 220), ("pein", 220), ("clea", 220), ("tocr", 220),
+    ("nate", 220), ("iall", 220), ("ench", 220), ("belo", 220), ("diag", 220), ("ledt", 219),
+    ("npar", 219), ("llco", 219), ("ille", 219), ("esom", 219), ("erem", 219), ("notp", 219),
+    ("stup", 219), ("miti", 219), ("intd", 219), ("tyle", 218), ("rsar", 218), ("deda", 218),
+    ("enan", 218), ("entf", 218), ("toas", 218), ("ebec", 218), ("situ", 218), ("nher", 218),
+    ("etos", 218), ("land", 218), ("uara", 218), ("eror", 218), ("amei", 218), ("getm", 218),
+    ("agno", 218), ("tein", 217), ("sopt", 217), ("avar", 217), ("urnt", 217), ("oimp", 217),
+    ("eove", 217), ("ofit", 217), ("resi", 217), ("isse", 217), ("dcar", 217), ("onsf", 216),
+    ("ends", 216), ("esex", 216), ("ooks", 216), ("rnsa", 216), ("tuat", 216), ("tdoc", 216),
+    ("uste", 215), ("sout", 215), ("ruse", 215), ("cuss", 215), ("redo", 215), ("erti", 215),
+    ("ledw", 215), ("usts", 215), ("pepa", 215), ("ccep", 215), ("ptim", 215), ("imiz", 215),
+    ("heim", 215), ("tedc", 215), ("anne", 215), ("edru", 215), ("gnos", 215), ("refu", 214),
+    ("ppea", 214), ("forw", 214), ("ists", 214), ("tany", 214), ("espr", 214), ("itsa", 214),
+    ("prel", 214), ("oing", 214), ("anun", 214), ("lter", 214), ("ssup", 214), ("itor", 214),
+    ("ndwi", 214), ("pand", 214), ("rwis", 214), ("iagn", 214), ("icha", 213), ("nsco", 213),
+    ("yusi", 213), ("inis", 213), ("fert", 213), ("itst", 213), ("henr", 213), ("sses", 213),
+    ("orst", 213), ("epti", 213), ("etoc", 213), ("ssem", 213), ("hemi", 213), ("oris", 213),
+    ("rmal", 213), ("tdir", 213), ("mitt", 213), ("figv", 213), ("igva", 213), ("alen", 212),
+    ("ionn", 212), ("npro", 212), ("rori", 212), ("nwil", 212), ("usua", 212), ("dcom", 212),
+    ("ueth", 211), ("ndma", 211), ("plew", 211), ("esfr", 211), ("rrus", 211), ("nced", 211),
+    ("ours", 211), ("rali", 211), ("gned", 211), ("mayn", 211), ("open", 211), ("scap", 211),
+    ("elow", 210), ("nbec", 210), ("esha", 210), ("tval", 210), ("etop", 210), ("trat", 210),
+    ("lcom", 210), ("outs", 210), ("prim", 210), ("ytes", 210), ("ownl", 210), ("gure", 209),
+    ("meas", 209), ("sive", 209), ("leva", 209), ("stwi", 209), ("seea", 209), ("eded", 209),
+    ("eift", 209), ("migr", 209), ("igra", 209), ("lent", 208), ("uded", 208), ("ntra", 208),
+    ("nonl", 208), ("tnee", 208), ("relu", 208), ("elud", 208), ("rwil", 208), ("nsfo", 208),
+    ("ords", 207), ("tbec", 207), ("tack", 207), ("orit", 207), ("rtfo", 207), ("cing", 207),
+    ("nged", 207), ("stsu", 207), ("dwil", 207), ("otes", 207), ("goto", 207), ("lcon", 206),
+    ("dtou", 206), ("edec", 206), ("anim", 206), ("osta", 206), ("peis", 206), ("ngar", 206),
+    ("edes", 206), ("orwh", 206), ("herc", 206), ("orsi", 206), ("teso", 206), ("embl", 206),
+    ("hesc", 206), ("logi", 206), ("ntic", 206), ("rete", 205), ("arly", 205), ("ammi", 205),
+    ("saco", 205), ("tort", 205), ("ypro", 204), ("inyo", 204), ("aito", 204), ("opri", 204),
+    ("omes", 204), ("ncod", 204), ("esea", 204), ("nent", 204), ("orno", 204), ("eede", 204),
+    ("ionh", 203), ("llyt", 203), ("euni", 203), ("cant", 203), ("rics", 203), ("grou", 203),
+    ("teve", 203), ("ngor", 203), ("ityt", 203), ("tegr", 203), ("sdep", 203), ("sbui", 203),
+    ("urec", 203), ("lrea", 202), ("acke", 202), ("osto", 202), ("ndwh", 202), ("inet", 202),
+    ("ugge", 202), ("heap", 202), ("itua", 202), ("ivet", 202), ("mmin", 202), ("eloo", 202),
+    ("atma", 202), ("entp", 202), ("repe", 202), ("acra", 202), ("ureo", 201), ("stis", 201),
+    ("stos", 201), ("etou", 201), ("areg", 201), ("eext", 201), ("agen", 201), ("emst", 201),
+    ("sere", 201), ("llec", 201), ("succ", 201), ("ostt", 201), ("repl", 200), ("onss", 200),
+    ("ieso", 200), ("refi", 200), ("norm", 200), ("nece", 200), ("mmar", 200), ("rcra", 200),
+    ("cefo", 200), ("elan", 199), ("nlyt", 199), ("oras", 199), ("aspe", 199), ("lyto", 199),
+    ("sstr", 199), ("blec", 199), ("eyar", 199), ("fety", 199), ("entc", 199), ("ewri", 199),
+    ("rtoa", 199), ("symb", 199), ("ymbo", 199), ("mbol", 199), ("spat", 199), ("msex", 199),
+    ("dwhi", 198), ("tell", 198), ("arin", 198), ("tswh", 198), ("entb", 198), ("heda", 198),
+    ("satt", 198), ("tins", 198), ("nowt", 198), ("swor", 198), ("efix", 198), ("isac", 198),
+    ("wrap", 198), ("forp", 198), ("ucce", 198), ("opes", 198), ("rgoc", 198), ("ycha", 198),
+    ("rgoa", 198), ("epas", 197), ("cean", 197), ("ikea", 197), ("aket", 197), ("ingn", 197),
+    ("imet", 197), ("ices", 197), ("hebe", 197), ("topa", 197), ("dcan", 197), ("sfun", 196),
+    ("anar", 196), ("lexp", 196), ("eper", 196), ("meti", 196), ("dtos", 196), ("ustr", 196),
+    ("esys", 196), ("etor", 196), ("smus", 196), ("ithm", 196), ("ecap", 196), ("lnot", 196),
+    ("ludi", 196), ("send", 196), ("ispa", 196), ("tesd", 196), ("rail", 196), ("apar", 195),
+    ("ckth", 195), ("eits", 195), ("head", 195), ("ario", 195), ("lyde", 195), ("ncur", 195),
+    ("otal", 195), ("visi", 195), ("icti", 195), ("udin", 195), ("cuti", 195), ("lver", 195),
+    ("mesi", 194), ("core", 194), ("essu", 194), ("otin", 194), ("undi", 194), ("tryt", 194),
+    ("oall", 194), ("nedt", 194), ("inds", 194), ("weus", 194), ("inki", 194), ("ssec", 194),
+    ("mpty", 194), ("illi", 194), ("angl", 194), ("ngso", 194), ("tomi", 194), ("oral", 194),
+    ("xpan", 194), ("mini", 194), ("rces", 194), ("leda", 193), ("hatp", 193), ("hatu", 193),
+    ("tlin", 193), ("inso", 193), ("hepo", 193), ("alis", 193), ("ross", 193), ("ompo", 193),
+    ("orig", 193), ("tout", 193), ("onop", 193), ("linu", 193), ("insa", 192), ("ithc", 192),
+    ("pean", 192), ("atim", 192), ("ecta", 192), ("temi", 192), ("mbed", 192), ("rapp", 192),
+    ("ccod", 192), ("ionu", 192), ("chca", 192), ("rtth", 192), ("expa", 192), ("tdef", 191),
+    ("ityo", 191), ("tusi", 191), ("emus", 191), ("aryc", 191), ("riva", 191), ("isio", 190),
+    ("arem", 190), ("yhav", 190), ("dbeh", 190), ("pply", 190), ("ingv", 190), ("llyc", 190),
+    ("hown", 190), ("rloo", 190), ("erli", 190), ("ageo", 190), ("iesi", 190), ("dedb", 190),
+    ("runs", 190), ("esuc", 190), ("sten", 190), ("rify", 190), ("edia", 190), ("ella", 190),
+    ("epor", 190), ("inux", 190), ("sbec", 189), ("gcod", 189), ("areo", 189), ("wedi", 189),
+    ("rval", 189), ("ngac", 189), ("einl", 189), ("nalc", 189), ("ultt", 189), ("dfun", 188),
+    ("sabo", 188), ("ssto", 188), ("onti", 188), ("isst", 188), ("erru", 188), ("nkin", 188),
+    ("emat", 188), ("ledi", 188), ("enre", 188), ("pora", 188), ("vale", 187), ("anta", 187),
+    ("dson", 187), ("hers", 187), ("omec", 187), ("nner", 187), ("rfac", 187), ("llof", 187),
+    ("sary", 187), ("lowt", 187), ("rsco", 186), ("eabl", 186), ("esaf", 186), ("wsth", 186),
+    ("ocha", 186), ("ackt", 186), ("itre", 186), ("oyou", 186), ("essc", 186), ("adth", 186),
+    ("sume", 186), ("mein", 186), ("etto", 186), ("ffic", 186), ("mend", 186), ("ateo", 186),
+    ("rred", 186), ("rany", 186), ("estf", 186), ("eexe", 186), ("tibl", 186), ("redr", 186),
+    ("hewo", 185), ("enyo", 185), ("scas", 185), ("isun", 185), ("urne", 185), ("pthe", 185),
+    ("ctyp", 185), ("reof", 185), ("ttos", 185), ("dall", 185), ("team", 185), ("igin", 185),
+    ("ival", 185), ("tsca", 185), ("lifi", 185), ("eriv", 185), ("rtan", 184), ("oref", 184),
+    ("cute", 184), ("uate", 184), ("trie", 184), ("treq", 184), ("rewe", 184), ("dtod", 184),
+    ("emsi", 184), ("twou", 184), ("amen", 184), ("aina", 184), ("ense", 184), ("dwar", 184),
+    ("face", 184), ("onet", 184), ("ogic", 184), ("verb", 184), ("brac", 183), ("ilew", 183),
+    ("anen", 183), ("fina", 183), ("ueis", 183), ("heon", 183), ("flow", 183), ("uldn", 183),
+    ("iscr", 183), ("quiv", 183), ("uiva", 183), ("netw", 183), ("subs", 183), ("dsin", 183),
+    ("ilea", 183), ("priv", 183), ("teis", 183), ("empo", 183), ("lepa", 182), ("tire", 182),
+    ("geto", 182), ("ntos", 182), ("estt", 182), ("lert", 182), ("inhe", 182), ("glin", 182),
+    ("odis", 182), ("tonl", 182), ("manu", 182), ("oncu", 182), ("ston", 182), ("isbe", 182),
+    ("heny", 181), ("rned", 181), ("ypew", 181), ("nofa", 181), ("utan", 181), ("sdis", 181),
+    ("ousl", 181), ("sdoc", 181), ("erfa", 181), ("terp", 181), ("lsto", 181), ("aryi", 181),
+    ("getf", 181), ("crib", 181), ("etab", 181), ("ofas", 180), ("usew", 180), ("ntir", 180),
+    ("larg", 180), ("arou", 180), ("mpro", 180), ("tinu", 180), ("edli", 180), ("stas", 180),
+    ("rcom", 180), ("isbo", 180), ("idin", 180), ("unwi", 180), ("nane", 179), ("nisa", 179),
+    ("thow", 179), ("equa", 179), ("atwi", 179), ("lese", 179), ("tals", 179), ("ngdi", 179),
+    ("ngal", 179), ("imin", 179), ("alte", 179), ("ryan", 179), ("pret", 179), ("enif", 179),
+    ("unco", 179), ("benc", 179), ("dexp", 178), ("reda", 178), ("icty", 178), ("fits", 178),
+    ("esup", 178), ("bedd", 178), ("allb", 178), ("ldno", 178), ("tone", 178), ("ient", 178),
+    ("ildt", 178), ("care", 177), ("ndru", 177), ("esyo", 177), ("erac", 177), ("inse", 177),
+    ("ncep", 177), ("ngus", 177), ("asar", 177), ("sedf", 177), ("yadd", 177), ("omeo", 177),
+    ("ecks", 177), ("ndpr", 177), ("mbly", 177), ("nloa", 177), ("esel", 177), ("emsu", 177),
+    ("ddep", 177), ("ilda", 177), ("alst", 176), ("affe", 176), ("ndus", 176), ("uein", 176),
+    ("tlyt", 176), ("unda", 176), ("pesi", 176), ("yimp", 176), ("leor", 176), ("hele", 176),
+    ("ichm", 176), ("mbin", 176), ("nsee", 176), ("etma", 176), ("okat", 175), ("ttoa", 175),
+    ("chco", 175), ("avin", 175), ("etin", 175), ("ingg", 175), ("alto", 175), ("umer", 175),
+    ("tisi", 175), ("ulto", 175), ("ycom", 175), ("leif", 175), ("infe", 175), ("uare", 175),
+    ("ardw", 175), ("tiss", 175), ("anua", 175), ("nual", 175), ("rpre", 175), ("llys", 175),
+    ("newe", 175), ("yonl", 175), ("igui", 175), ("guit", 175), ("xpra", 175), ("dhav", 174),
+    ("herp", 174), ("reit", 174), ("sast", 174), ("ties", 174), ("tyof", 174), ("ninl", 174),
+    ("owni", 174), ("howr", 174), ("ycan", 174), ("ntor", 174), ("tina", 174), ("nsom", 174),
+    ("atru", 174), ("euns", 174), ("ited", 174), ("emsa", 174), ("yall", 174), ("wait", 174),
+    ("enli", 174), ("bugg", 174), ("prli", 174), ("urew", 173), ("rorm", 173), ("toit", 173),
+    ("hant", 173), ("ften", 173), ("sano", 173), ("nume", 173), ("tcht", 173), ("ocre", 173),
+    ("amic", 173), ("isop", 173), ("ebeh", 173), ("areb", 173), ("terc", 172), ("luea", 172),
+    ("foun", 172), ("nswh", 172), ("rcan", 172), ("tapp", 172), ("arya", 172), ("miss", 172),
+    ("race", 172), ("nbui", 172), ("rigi", 172), ("ngfr", 172), ("itan", 172), ("sona", 172),
+    ("itet", 172), ("inwh", 171), ("ofal", 171), ("adat", 171), ("onsh", 171), ("inpa", 171),
+    ("isch", 171), ("addr", 171), ("ddre", 171), ("ofwh", 171), ("asas", 171), ("hatf", 171),
+    ("atec", 171), ("stso", 171), ("isfo", 171), ("owra", 171), ("wnlo", 171), ("ower", 170),
+    ("eofi", 170), ("sspe", 170), ("ecis", 170), ("totr", 170), ("tite", 170), ("rpos", 170),
+    ("ftyp", 170), ("nord", 170), ("gein", 170), ("tryi", 170), ("pond", 170), ("oacc", 170),
+    ("ngsa", 170), ("gfro", 170), ("eacc", 170), ("typi", 170), ("etsa", 170), ("hesu", 170),
+    ("dmul", 170), ("ncan", 170), ("shed", 170), ("ersw", 169), ("adda", 169), ("rmes", 169),
+    ("dusi", 169), ("seca", 169), ("putt", 169), ("eenu", 169), ("usly", 169), ("nass", 169),
+    ("nfer", 169), ("orya", 169), ("loft", 169), ("lson", 169), ("pars", 169), ("acet", 168),
+    ("dhow", 168), ("sapa", 168), ("deis", 168), ("itco", 168), ("enit", 168), ("addt", 168),
+    ("eade", 168), ("orsa", 168), ("hann", 168), ("ribe", 168), ("tset", 168), ("edca", 168),
+    ("uity", 168), ("edmu", 168), ("tfun", 167), ("itea", 167), ("ovin", 167), ("toim", 167),
+    ("usec", 167), ("wnin", 167), ("yone", 167), ("anch", 167), ("ledb", 167), ("anyc", 167),
+    ("ycod", 167), ("ombi", 167), ("mpar", 167), ("lewh", 167), ("ttes", 167), ("ngaf", 166),
+    ("nist", 166), ("asno", 166), ("dewi", 166), ("anas", 166), ("lari", 166), ("lesc", 166),
+    ("erew", 166), ("owri", 166), ("stou", 166), ("expo", 166), ("comb", 166), ("tofo", 166),
+    ("todi", 166), ("rebu", 166), ("olde", 166), ("yspe", 166), ("nset", 166), ("lied", 166),
+    ("dinc", 165), ("atan", 165), ("sval", 165), ("ofty", 165), ("isme", 165), ("sibi", 165),
+    ("ceis", 165), ("rdet", 165), ("dret", 165), ("rdwa", 165), ("beth", 165), ("dyna", 165),
+    ("nany", 165), ("pers", 165), ("tted", 165), ("unin", 165), ("tane", 164), ("kean", 164),
+    ("puti", 164), ("reli", 164), ("yoth", 164), ("nrun", 164), ("tlyo", 164), ("amew", 164),
+    ("stoo", 164), ("entm", 164), ("stsa", 164), ("mary", 164), ("nspe", 164), ("sset", 164),
+    ("ncar", 164), ("inca", 163), ("smea", 163), ("ntho", 163), ("luew", 163), ("rtra", 163),
+    ("ewou", 163), ("hisd", 163), ("gtot", 163), ("illu", 163), ("ofru", 163), ("frus", 163),
+    ("gcom", 163), ("onma", 163), ("isam", 163), ("edex", 163), ("ewer", 163), ("leli", 163),
+    ("itma", 163), ("tnam", 162), ("keep", 162), ("enst", 162), ("tpoi", 162), ("sesi", 162),
+    ("ldsa", 162), ("ites", 162), ("sasi", 162), ("enow", 162), ("odeb", 162), ("ceso", 162),
+    ("esis", 162), ("dtoi", 162), ("outr", 162), ("kfor", 162), ("ully", 162), ("heab", 162),
+    ("yyou", 162), ("olin", 162), ("fand", 162), ("eana", 162), ("tbui", 162), ("tfea", 162),
+    ("afil", 162), ("osup", 162), ("crod", 162), ("nnam", 161), ("ewec", 161), ("oexp", 161),
+    ("utwh", 161), ("hort", 161), ("ness", 161), ("sfol", 161), ("hwil", 161), ("meco", 161),
+    ("hano", 161), ("towr", 161), ("ayno", 161), ("arus", 161), ("nhow", 161), ("anop", 161),
+    ("nalt", 161), ("lies", 161), ("dles", 161), ("adep", 161), ("ledo", 161), ("tbin", 161),
+    ("kens", 161), ("heso", 160), ("ayth", 160), ("sito", 160), ("oavo", 160), ("oute", 160),
+    ("isat", 160), ("ovet", 160), ("ockf", 160), ("forf", 159), ("rfun", 159), ("sfil", 159),
+    ("plec", 159), ("mine", 159), ("imea", 159), ("tobj", 159), ("ustf", 159), ("donl", 159),
+    ("stoi", 159), ("foru", 159), ("yres", 159), ("goin", 159), ("rain", 159), ("tmod", 159),
+    ("lder", 159), ("lope", 159), ("cach", 159), ("road", 159), ("rode", 159), ("lagt", 159),
+    ("ndas", 158), ("temt", 158), ("rwhi", 158), ("sits", 158), ("ttob", 158), ("eras", 158),
+    ("cesi", 158), ("ngpr", 158), ("ocki", 158), ("stes", 158), ("obea", 158), ("oseo", 158),
+    ("edor", 158), ("ropp", 158), ("hehe", 157), ("pens", 157), ("earl", 157), ("tora", 157),
+    ("sena", 157), ("atas", 157), ("geof", 157), ("roup", 157), ("orso", 157), ("cert", 157),
+    ("vest", 157), ("iesw", 157), ("tisu", 157), ("dtra", 157), ("nnel", 157), ("orie", 157),
+    ("gger", 157), ("tmai", 157), ("runi", 156), ("ytyp", 156), ("chwi", 156), ("llus", 156),
+    ("ssim", 156), ("sown", 156), ("tope", 156), ("roth", 156), ("tbea", 156), ("ngca", 156),
+    ("nchm", 156), ("ysup", 156), ("ipts", 156), ("nfil", 155), ("drun", 155), ("eord", 155),
+    ("ulta", 155), ("ayst", 155), ("rown", 155), ("impr", 155), ("ngsh", 155), ("edse", 155),
+    ("eswe", 155), ("owit", 155), ("lesw", 155), ("ourt", 155), ("thor", 155), ("acha", 155),
+    ("orse", 155), ("cape", 155), ("hmar", 155), ("rbui", 155), ("yset", 155), ("weco", 154),
+    ("icht", 154), ("tpar", 154), ("amin", 154), ("ftha", 154), ("hasi", 154), ("tsee", 154),
+    ("isap", 154), ("eeve", 154), ("llex", 154), ("afea", 154), ("hash", 154), ("rnot", 154),
+    ("alth", 154), ("cked", 154), ("ityi", 154), ("actl", 154), ("ncew", 154), ("sboo", 154),
+    ("guid", 154), ("gati", 154), ("icli", 154), ("mepa", 154), ("etis", 154), ("andv", 153),
+    ("stca", 153), ("inad", 153), ("ofar", 153), ("byus", 153), ("iswh", 153), ("agea", 153),
+    ("dsan", 153), ("esdi", 153), ("nlyb", 153), ("ttak", 153), ("beab", 153), ("sort", 153),
+    ("upli", 153), ("asst", 153), ("ipti", 153), ("utre", 153), ("itde", 153), ("seta", 153),
+    ("edou", 153), ("osee", 152), ("tstr", 152), ("shor", 152), ("ofat", 152), ("smor", 152),
+    ("etod", 152), ("anys", 152), ("itsi", 152), ("pert", 152), ("toro", 152), ("llas", 152),
+    ("duet", 152), ("gyou", 152), ("ngbu", 152), ("etup", 152), ("lede", 152), ("etok", 152),
+    ("ecei", 152), ("ceiv", 152), ("rpac", 152), ("anyp", 151), ("ette", 151), ("ller", 151),
+    ("tede", 151), ("utho", 151), ("dsar", 151), ("utit", 151), ("rawp", 151), ("ssor", 151),
+    ("cast", 151), ("oten", 151), ("verw", 151), ("rows", 151), ("derr", 151), ("erte", 151),
+    ("teds", 151), ("hegi", 151), ("rved", 151), ("amef", 150), ("llye", 150), ("erva", 150),
+    ("asfo", 150), ("erpa", 150), ("gesa", 150), ("sdes", 150), ("rsof", 150), ("ntfo", 150),
+    ("mmut", 150), ("mana", 150), ("teit", 150), ("dupl", 150), ("erce", 150), ("unic", 150),
+    ("onpa", 150), ("ncec", 150), ("etad", 150), ("emis", 150), ("urna", 149), ("ones", 149),
+    ("tofs", 149), ("illt", 149), ("alon", 149), ("tfil", 149), ("sedb", 149), ("reon", 149),
+    ("cide", 149), ("acho", 149), ("ayin", 149), ("reen", 149), ("youn", 149), ("msth", 149),
+    ("dite", 149), ("rdin", 149), ("gmen", 149), ("ldca", 149), ("orat", 149), ("sind", 149),
+    ("dcod", 149), ("exit", 149), ("ratt", 149), ("voca", 149), ("ntil", 149), ("llyd", 149),
+    ("erbo", 149), ("atev", 148), ("tbut", 148), ("stit", 148), ("onli", 148), ("outh", 148),
+    ("ntco", 148), ("dver", 148), ("onee", 148), ("dbut", 148), ("asto", 148), ("twas", 148),
+    ("inne", 148), ("onpr", 148), ("odel", 148), ("heis", 148), ("foro", 148), ("esub", 148),
+    ("lexi", 148), ("heho", 148), ("tbeu", 148), ("rcha", 147), ("deci", 147), ("lyal", 147),
+    ("asit", 147), ("ouar", 147), ("immu", 147), ("ndfo", 147), ("sche", 147), ("mula", 147),
+    ("ttar", 147), ("http", 147), ("oade", 147), ("deal", 146), ("enon", 146), ("tlyi", 146),
+    ("esac", 146), ("onat", 146), ("erex", 146), ("stsi", 146), ("twec", 146), ("aced", 146),
+    ("isen", 146), ("easy", 146), ("fusi", 146), ("esur", 146), ("ntwo", 146), ("ycra", 146),
+    ("erul", 146), ("erma", 146), ("ngsl", 146), ("gsli", 146), ("edus", 146), ("diat", 146),
+    ("ngpa", 146), ("dtor", 146), ("page", 146), ("shin", 146), ("iedm", 146), ("scor", 145),
+    ("eses", 145), ("edso", 145), ("lers", 145), ("dsee", 145), ("erde", 145), ("fast", 145),
+    ("dsta", 145), ("otth", 145), ("ward", 145), ("ards", 145), ("sefo", 145), ("ofre", 145),
+    ("uesi", 145), ("teco", 145), ("edun", 145), ("pone", 145), ("asde", 145), ("aten", 145),
+    ("stus", 145), ("yreq", 145), ("leit", 145), ("srun", 145), ("umma", 145), ("dona", 145),
+    ("scra", 145), ("dsup", 145), ("tsom", 144), ("stod", 144), ("nlik", 144), ("hebl", 144),
+    ("rage", 144), ("hina", 144), ("stli", 144), ("nowa", 144), ("hani", 144), ("anto", 144),
+    ("oins", 144), ("ninc", 144), ("ecki", 144), ("ebor", 144), ("suit", 144), ("anag", 144),
+    ("depr", 144), ("nvoc", 144), ("imum", 144), ("orca", 144), ("oppe", 144), ("ailr", 144),
+    ("mefo", 143), ("aves", 143), ("otre", 143), ("etoi", 143), ("lyan", 143), ("yval", 143),
+    ("larl", 143), ("gwhe", 143), ("puta", 143), ("ntsw", 143), ("tava", 143), ("ccur", 143),
+    ("rtai", 143), ("ingy", 143), ("onus", 143), ("prot", 143), ("osed", 143), ("orfo", 143),
+    ("sema", 143), ("erci", 143), ("ntar", 143), ("tmat", 143), ("ortt", 143), ("urep", 143),
+    ("bool", 143), ("wrai", 143), ("ilro", 143), ("lroa", 143), ("lagm", 143), ("swec", 142),
+    ("seis", 142), ("oncr", 142), ("notr", 142), ("rnth", 142), ("atai", 142), ("alpr", 142),
+    ("uced", 142), ("dnam", 142), ("isav", 142), ("llyb", 142), ("uter", 142), ("wpoi", 142),
+    ("pons", 142), ("agew", 142), ("gani", 142), ("uide", 142), ("rort", 142), ("meis", 142),
+    ("rcli", 142), ("tsde", 142), ("lors", 142), ("aile", 142), ("kfil", 142), ("floa", 142),
+    ("reci", 142), ("ndha", 141), ("butw", 141), ("pili", 141), ("emth", 141), ("shel", 141),
+    ("dbec", 141), ("nums", 141), ("hebi", 141), ("ersu", 141), ("task", 141), ("ivea", 141),
+    ("awpo", 141), ("eaki", 141), ("bedi", 141), ("nare", 141), ("rtst", 141), ("enev", 141),
+    ("aded", 141), ("glit", 141), ("nift", 141), ("orem", 141), ("inim", 141), ("nage", 141),
+    ("hege", 141), ("yexp", 141), ("ttps", 141), ("ckfi", 141), ("hall", 140), ("ueso", 140),
+    ("tlya", 140), ("ttou", 140), ("smal", 140), ("itob", 140), ("riou", 140), ("ionl", 140),
+    ("deco", 140), ("seso", 140), ("emig", 140), ("llal", 140), ("orof", 140), ("toli", 140),
+    ("free", 140), ("erop", 140), ("ultf", 140), ("ehos", 140), ("alma", 140), ("loat", 140),
+    ("suff", 140), ("agma", 140), ("neda", 139), ("repa", 139), ("esiz", 139), ("esat", 139),
+    ("gane", 139), ("hatb", 139), ("rref", 139), ("oens", 139), ("etsu", 139), ("ngyo", 139),
+    ("yinc", 139), ("dati", 139), ("tmak", 139), ("summ", 139), ("tpat", 139), ("ceme", 139),
+    ("sbef", 139), ("tada", 139), ("ream", 139), ("ncef", 139), ("uref", 139), ("oena", 139),
+    ("dpac", 139), ("uffi", 139), ("edfu", 138), ("deli", 138), ("cedi", 138), ("scre", 138),
+    ("canr", 138), ("alex", 138), ("acei", 138), ("erbe", 138), ("seac", 138), ("imei", 138),
+    ("lywh", 138), ("suns", 138), ("oryi", 138), ("eryt", 138), ("otof", 138), ("arys", 138),
+    ("rnsi", 138), ("awor", 138), ("alde", 138), ("goco", 138), ("ctes", 138), ("onun", 138),
+    ("rgos", 138), ("arel", 137), ("tlyc", 137), ("hatl", 137), ("oren", 137), ("inac", 137),
+    ("sasa", 137), ("allp", 137), ("ouro", 137), ("erge", 137), ("effe", 137), ("incr", 137),
+    ("ngbe", 137), ("ecke", 137), ("sapp", 137), ("nfro", 137), ("teas", 137), ("ndat", 137),
+    ("aitt", 137), ("labe", 137), ("abel", 137), ("orif", 137), ("enet", 137), ("ibrs", 137),
+    ("auth", 137), ("dits", 136), ("lsoa", 136), ("stpa", 136), ("ebod", 136), ("eact", 136),
+    ("tast", 136), ("nsca", 136), ("anco", 136), ("ebas", 136), ("iesf", 136), ("adva", 136),
+    ("hana", 136), ("eenc", 136), ("orpr", 136), ("oune", 136), ("lwhe", 136), ("elyt", 136),
+    ("ikel", 136), ("arke", 136), ("tsui", 136), ("ingh", 136), ("datt", 136), ("ndsu", 136),
+    ("ilso", 136), ("eive", 136), ("ofyo", 136), ("ltfo", 136), ("ntri", 136), ("seas", 135),
+    ("lerw", 135), ("begi", 135), ("urth", 135), ("onha", 135), ("etan", 135), ("owna", 135),
+    ("otra", 135), ("much", 135), ("plex", 135), ("oryt", 135), ("aver", 135), ("noww", 135),
+    ("pted", 135), ("sors", 135), ("hcan", 135), ("orss", 135), ("opme", 135), ("erni", 135),
+    ("high", 135), ("rior", 135), ("srcl", 135), ("rtes", 135), ("heal", 135), ("ildc", 135),
+    ("rgof", 135), ("snam", 134), ("nica", 134), ("undt", 134), ("tcal", 134), ("mere", 134),
+    ("movi", 134), ("ngva", 134), ("eano", 134), ("tits", 134), ("etyo", 134), ("luse", 134),
+    ("dtop", 134), ("tmus", 134), ("isib", 134), ("fici", 134), ("ouwi", 134), ("erar", 134),
+    ("pmen", 134), ("rewi", 134), ("eexc", 134), ("duri", 134), ("ssof", 134), ("verf", 134),
+    ("esty", 134), ("iess", 134), ("rcec", 133), ("aspa", 133), ("uesa", 133), ("anre", 133),
+    ("elas", 133), ("lone", 133), ("ngch", 133), ("riat", 133), ("erwe", 133), ("hoft", 133),
+    ("herr", 133), ("heot", 133), ("ngsi", 133), ("enfo", 133), ("dvan", 133), ("teac", 133),
+    ("edbu", 133), ("tisc", 133), ("nowi", 133), ("elim", 133), ("tote", 133), ("occu", 133),
+    ("blep", 133), ("dimp", 133), ("rely", 133), ("tesl", 133), ("lopm", 133), ("suni", 133),
+    ("nles", 133), ("lesy", 133), ("epub", 133), ("ybea", 133), ("sver", 132), ("onsm", 132),
+    ("esif", 132), ("ngse", 132), ("angi", 132), ("manc", 132), ("gsho", 132), ("urpo", 132),
+    ("eaut", 132), ("erha", 132), ("avec", 132), ("orce", 132), ("asep", 132), ("asap", 132),
+    ("nina", 132), ("rked", 132), ("sift", 132), ("oane", 132), ("ynee", 132), ("fies", 132),
+    ("ebou", 132), ("sofc", 132), ("croa", 132), ("ndno", 132), ("deof", 132), ("ural", 132),
+    ("emos", 131), ("gins", 131), ("omea", 131), ("ustn", 131), ("acea", 131), ("itie", 131),
+    ("oses", 131), ("gsto", 131), ("tefo", 131), ("ngap", 131), ("heyc", 131), ("tsus", 131),
+    ("aili", 131), ("sens", 131), ("gtoa", 131), ("oded", 131), ("orli", 131), ("tewi", 131),
+    ("ntpa", 131), ("eldi", 131), ("napp", 131), ("lesf", 131), ("osin", 131), ("oree", 131),
+    ("nasi", 131), ("righ", 131), ("orbu", 131), ("dbin", 131), ("nifi", 131), ("neas", 131),
+    ("ncov", 131), ("erfl", 131), ("gmay", 131), ("egin", 130), ("reyo", 130), ("nson", 130),
+    ("lyca", 130), ("heer", 130), ("onwe", 130), ("ismo", 130), ("yins", 130), ("amec", 130),
+    ("enru", 130), ("towo", 130), ("orad", 130), ("four", 130), ("blev", 130), ("ngmo", 130),
+    ("rwor", 130), ("ryis", 130), ("dcal", 130), ("romo", 130), ("towh", 130), ("elet", 130),
+    ("rblo", 130), ("hane", 130), ("abin", 130), ("gfil", 130), ("stsw", 130), ("doct", 130),
+    ("else", 129), ("gcha", 129), ("redb", 129), ("heac", 129), ("mpti", 129), ("tspa", 129),
+    ("llyw", 129), ("rena", 129), ("cede", 129), ("sany", 129), ("aged", 129), ("cani", 129),
+    ("eche", 129), ("cewi", 129), ("dedc", 129), ("ypeb", 129), ("buta", 129), ("notu", 129),
+    ("lyex", 129), ("allc", 129), ("mena", 129), ("drul", 129), ("kenl", 129), ("omin", 128),
+    ("tedp", 128), ("sewh", 128), ("dast", 128), ("made", 128), ("herl", 128), ("rnst", 128),
+    ("ttor", 128), ("smar", 128), ("ebet", 128), ("assh", 128), ("cesw", 128), ("ndse", 128),
+    ("senu", 128), ("stak", 128), ("unee", 128), ("utma", 128), ("subt", 128), ("iedi", 128),
+    ("onho", 128), ("eofc", 128), ("dsyn", 128), ("acco", 128), ("ndep", 128), ("nbef", 128),
+    ("arse", 128), ("rgor", 128), ("bcom", 128), ("newf", 127), ("eann", 127), ("ctsw", 127),
+    ("tret", 127), ("thap", 127), ("kand", 127), ("ustl", 127), ("arde", 127), ("usse", 127),
+    ("ived", 127), ("nvar", 127), ("orwi", 127), ("kesa", 127), ("vedi", 127), ("mpon", 127),
+    ("eify", 127), ("ndbe", 127), ("maki", 127), ("ewha", 127), ("udet", 127), ("seei", 127),
+    ("tind", 127), ("choo", 127), ("ique", 127), ("ngfi", 127), ("eron", 127), ("imeo", 127),
+    ("samb", 127), ("icpa", 127), ("rare", 127), ("eeal", 127), ("gdir", 127), ("edur", 127),
+    ("dtar", 127), ("utei", 126), ("nfun", 126), ("scov", 126), ("nyth", 126), ("inot", 126),
+    ("hfor", 126), ("pria", 126), ("slis", 126), ("tsma", 126), ("onea", 126), ("tsas", 126),
+    ("onfl", 126), ("tact", 126), ("etom", 126), ("eada", 126), ("owsa", 126), ("niqu", 126),
+    ("itfo", 126), ("nico", 126), ("ltth", 126), ("olea", 126), ("emsf", 126), ("unle", 126),
+    ("miza", 126), ("gcar", 126), ("intc", 126), ("stoe", 125), ("rexp", 125), ("esbu", 125),
+    ("niss", 125), ("eryo", 125), ("ghtb", 125), ("obec", 125), ("peso", 125), ("nsex", 125),
+    ("orru", 125), ("ifya", 125), ("essf", 125), ("newt", 125), ("ndsi", 125), ("elev", 125),
+    ("tesw", 125), ("ifan", 125), ("ecov", 125), ("dreq", 125), ("lysu", 125), ("notf", 125),
+    ("otsu", 125), ("ubco", 125), ("dedf", 125), ("prlo", 125), ("anca", 124), ("ledf", 124),
+    ("ntre", 124), ("dera", 124), ("newi", 124), ("swri", 124), ("nwec", 124), ("purp", 124),
+    ("llne", 124), ("utno", 124), ("outi", 124), ("ngsy", 124), ("onew", 124), ("ywhi", 124),
+    ("rtsa", 124), ("utis", 124), ("hoos", 124), ("asor", 124), ("toyo", 124), ("thar", 124),
+    ("gwil", 124), ("llto", 124), ("ncra", 124), ("otpr", 124), ("step", 124), ("snee", 124),
+    ("leru", 124), ("iliz", 124), ("ybeu", 124), ("mtha", 123), ("lyfo", 123), ("erss", 123),
+    ("esot", 123), ("eget", 123), ("urni", 123), ("onbe", 123), ("demo", 123), ("recu", 123),
+    ("nked", 123), ("onsb", 123), ("neac", 123), ("oval", 123), ("away", 123), ("ateb", 123),
+    ("ccom", 123), ("rstr", 123), ("giss", 123), ("tsow", 123), ("lbei", 123), ("dtom", 123),
+    ("best", 123), ("sser", 123), ("rusi", 123), ("agef", 123), ("meso", 123), ("cred", 123),
+    ("hewa", 123), ("redu", 123), ("utli", 123), ("imep", 123), ("htha", 123), ("utea", 123),
+    ("uard", 123), ("atde", 122), ("bese", 122), ("haso", 122), ("ofea", 122), ("hend", 122),
+    ("sewe", 122), ("ntdi", 122), ("indt", 122), ("semi", 122), ("orev", 122), ("ndde", 122),
+    ("ager", 122), ("view", 122), ("curs", 122), ("wedt", 122), ("nsho", 122), ("ngwe", 122),
+    ("ntwi", 122), ("bean", 122), ("tota", 122), ("thst", 122), ("ceed", 122), ("otco", 122),
+    ("nope", 122), ("rtso", 122), ("tice", 122), ("aryw", 122), ("omic", 122), ("pper", 122),
+    ("rerr", 122), ("rlib", 122), ("thus", 122), ("ypic", 122), ("ndor", 122), ("nuni", 122),
+    ("acem", 122), ("nows", 121), ("nbut", 121), ("eini", 121), ("ehea", 121), ("bstr", 121),
+    ("ypen", 121), ("ideo", 121), ("itim", 121), ("ispo", 121), ("ndsa", 121), ("ntsh", 121),
+    ("ownt", 121), ("shad", 121), ("doin", 121), ("ewar", 121), ("ilef", 121), ("dexa", 121),
+    ("uite", 121), ("uwil", 121), ("getc", 121), ("edoe", 121), ("ourd", 121), ("hoth", 121),
+    ("dthr", 121), ("ilst", 121), ("basi", 121), ("ofpa", 120), ("canp", 120), ("ably", 120),
+    ("medt", 120), ("sade", 120), ("croi", 120), ("nitt", 120), ("tref", 120), ("tove", 120),
+    ("ctwi", 120), ("spos", 120), ("inue", 120), ("stwo", 120), ("herm", 120), ("orep", 120),
+    ("hatn", 120), ("ntsc", 120), ("butn", 120), ("notm", 120), ("sbei", 120), ("ybec", 120),
+    ("odem", 120), ("ithu", 120), ("allf", 120), ("eign", 120), ("ngte", 120), ("sref", 120),
+    ("etit", 120), ("rdep", 120), ("ranc", 120), ("tsuc", 120), ("roll", 120), ("alva", 119),
+    ("ndho", 119), ("tloo", 119), ("rnty", 119), ("iori", 119), ("majo", 119), ("uchs", 119),
+    ("icet", 119), ("ivei", 119), ("rast", 119), ("usag", 119), ("wtha", 119), ("nyot", 119),
+    ("edfi", 119), ("mwit", 119), ("edpr", 119), ("asad", 119), ("hree", 119), ("tman", 119),
+    ("atti", 119), ("ppin", 119), ("ncer", 119), ("ngad", 119), ("lead", 119), ("yrus", 119),
+    ("divi", 119), ("lres", 119), ("dbui", 119), ("aclo", 119), ("ateg", 119), ("stde", 118),
+    ("sinf", 118), ("ndot", 118), ("inei", 118), ("gges", 118), ("ajor", 118), ("tatt", 118),
+    ("eaco", 118), ("gwhi", 118), ("nbea", 118), ("peat", 118), ("tedd", 118), ("meri", 118),
+    ("sbet", 118), ("stfo", 118), ("deca", 118), ("amut", 118), ("lidi", 118), ("eori", 118),
+    ("ngfu", 118), ("oadi", 118), ("obal", 118), ("tson", 118), ("nlyc", 118), ("rycr", 118),
+    ("ulda", 118), ("gont", 118), ("tcra", 118), ("unte", 118), ("ishi", 118), ("nbes", 117),
+    ("nhas", 117), ("eced", 117), ("nddo", 117), ("sdon", 117), ("isdi", 117), ("atho", 117),
+    ("nlyo", 117), ("isim", 117), ("ntca", 117), ("mfor", 117), ("ordo", 117), ("eown", 117),
+    ("eisu", 117), ("orer", 117), ("estw", 117), ("offi", 117), ("gert", 117), ("onsp", 117),
+    ("ecat", 117), ("ayco", 117), ("tres", 117), ("rupt", 117), ("uces", 117), ("ifno", 117),
+    ("ldan", 117), ("laye", 117), ("ocar", 117), ("gits", 116), ("hell", 116), ("enis", 116),
+    ("etri", 116), ("sugg", 116), ("ctan", 116), ("mits", 116), ("mint", 116), ("nenu", 116),
+    ("ldst", 116), ("fany", 116), ("illh", 116), ("etre", 116), ("isne", 116), ("hens", 116),
+    ("ican", 116), ("nblo", 116), ("oned", 116), ("orpa", 116), ("tass", 116), ("iton", 116),
+    ("meca", 116), ("dexi", 116), ("ntsf", 116), ("stve", 116), ("tewh", 116), ("tbou", 116),
+    ("npat", 116), ("mite", 116), ("ndtr", 116), ("enca", 116), ("sful", 116), ("nmat", 116),
+    ("nenv", 116), ("bpat", 116), ("cket", 115), ("uldh", 115), ("cret", 115), ("cisi", 115),
+    ("rowi", 115), ("ithn", 115), ("heba", 115), ("ldso", 115), ("eapa", 115), ("lbec", 115),
+    ("radd", 115), ("lict", 115), ("nlyi", 115), ("llat", 115), ("nove", 115), ("kely", 115),
+    ("eisc", 115), ("aris", 115), ("ader", 115), ("intw", 115), ("ital", 115), ("ultb", 115),
+    ("egat", 115), ("live", 115), ("alib", 115), ("lets", 115), ("itss", 115), ("runa", 115),
+    ("pica", 115), ("hine", 115), ("utet", 115), ("alse", 115), ("ugin", 115), ("ngou", 114),
+    ("ngea", 114), ("isfi", 114), ("stex", 114), ("dass", 114), ("ofun", 114), ("ceas", 114),
+    ("blin", 114), ("canh", 114), ("nser", 114), ("meto", 114), ("sisn", 114), ("ntss", 114),
+    ("llha", 114), ("ersm", 114), ("ndwe", 114), ("ecol", 114), ("htbe", 114), ("sewi", 114),
+    ("tety", 114), ("ormu", 114), ("rbit", 114), ("afor", 114), ("agec", 114), ("gpro", 114),
+    ("rcio", 114), ("cion", 114), ("bebu", 114), ("ohan", 114), ("aryo", 114), ("dpat", 114),
+    ("eora", 114), ("ewas", 114), ("leus", 114), ("retr", 114), ("gres", 114), ("lsco", 114),
+    ("toml", 114), ("sinp", 114), ("hado", 114), ("xprm", 114), ("rcas", 113), ("chpa", 113),
+    ("ksli", 113), ("eens", 113), ("dofa", 113), ("abst", 113), ("rinc", 113), ("tisp", 113),
+    ("enat", 113), ("asie", 113), ("ofde", 113), ("sesw", 113), ("atdo", 113), ("orys", 113),
+    ("ertr", 113), ("tyto", 113), ("pena", 113), ("utsi", 113), ("tadd", 113), ("toup", 113),
+    ("ugha", 113), ("akei", 113), ("etoe", 113), ("loba", 113), ("tosu", 113), ("olst", 113),
+    ("itbo", 113), ("lved", 113), ("erer", 113), ("einp", 113), ("smod", 113), ("awar", 113),
+    ("ldep", 113), ("agto", 113), ("trip", 113), ("geis", 112), ("rnam", 112), ("crop", 112),
+    ("atst", 112), ("aveb", 112), ("hasn", 112), ("heno", 112), ("tlik", 112), ("ngla", 112),
+    ("elik", 112), ("edup", 112), ("isru", 112), ("efou", 112), ("oron", 112), ("henb", 112),
+    ("lyim", 112), ("ofca", 112), ("utat", 112), ("erme", 112), ("ropt", 112), ("nant", 112),
+    ("eide", 112), ("adow", 112), ("cato", 112), ("otom", 112), ("ryfo", 112), ("rgoh", 112),
+    ("etfo", 112), ("nlit", 112), ("pecl", 112), ("oush", 111), ("outw", 111), ("uess", 111),
+    ("fixt", 111), ("nceb", 111), ("cebe", 111), ("abli", 111), ("earn", 111), ("anis", 111),
+    ("dany", 111), ("sese", 111), ("oinc", 111), ("vant", 111), ("veas", 111), ("dewh", 111),
+    ("ncor", 111), ("sonw", 111), ("supe", 111), ("uper", 111), ("itch", 111), ("exts", 111),
+    ("lepr", 111), ("fory", 111), ("fers", 111), ("aitb", 111), ("itya", 111), ("odep", 111),
+    ("octe", 111), ("oole", 111), ("rgol", 111), ("otus", 111), ("tibi", 111), ("lych", 111),
+    ("lmac", 111), ("ldha", 110), ("ieda", 110), ("oder", 110), ("yava", 110), ("ptin", 110),
+    ("lyas", 110), ("lysi", 110), ("atta", 110), ("tlea", 110), ("hodo", 110), ("edal", 110),
+    ("afew", 110), ("emea", 110), ("ileb", 110), ("sues", 110), ("lyha", 110), ("asaf", 110),
+    ("emut", 110), ("ddoc", 110), ("lain", 110), ("rche", 110), ("atle", 110), ("inap", 110),
+    ("eted", 110), ("roto", 110), ("tyth", 110), ("twar", 110), ("ilec", 110), ("rega", 110),
+    ("lesu", 110), ("inkt", 110), ("vebe", 110), ("llyr", 110), ("zero", 110), ("rdoc", 110),
+    ("unus", 110), ("rgoi", 110), ("dsom", 109), ("usho", 109), ("edar", 109), ("asli", 109),
+    ("aste", 109), ("osts", 109), ("unto", 109), ("chof", 109), ("asma", 109), ("isfu", 109),
+    ("tpos", 109), ("seli", 109), ("lwit", 109), ("gers", 109), ("leng", 109), ("idth", 109),
+    ("plai", 109), ("icod", 109), ("ytot", 109), ("offe", 109), ("rale", 109), ("heha", 109),
+    ("llon", 109), ("etex", 109), ("nthr", 109), ("dese", 109), ("msas", 109), ("nsyn", 109),
+    ("dfea", 109), ("yrun", 108), ("eant", 108), ("tsit", 108), ("lves", 108), ("gean", 108),
+    ("tisr", 108), ("oftw", 108), ("excl", 108), ("blee", 108), ("blyc", 108), ("keda", 108),
+    ("mize", 108), ("chre", 108), ("oose", 108), ("dbef", 108), ("tift", 108), ("mmed", 108),
+    ("icst", 108), ("tsyo", 108), ("onei", 108), ("oits", 108), ("opas", 108), ("unsi", 108),
+    ("tree", 108), ("htot", 108), ("uthe", 108), ("ffix", 108), ("eexi", 108), ("rpat", 108),
+    ("spas", 107), ("plep", 107), ("uefo", 107), ("uean", 107), ("narr", 107), ("stno", 107),
+    ("pesw", 107), ("ngab", 107), ("okno", 107), ("aseo", 107), ("sede", 107), ("reim", 107),
+    ("deas", 107), ("tlys", 107), ("xclu", 107), ("rimp", 107), ("ormi", 107), ("topt", 107),
+    ("onar", 107), ("vena", 107), ("ommu", 107), ("iort", 107), ("rlin", 107), ("dtes", 107),
+    ("rmay", 107), ("leex", 107), ("amod", 107), ("nasy", 107), ("onfu", 107), ("owse", 107),
+    ("tpri", 107), ("rrup", 107), ("bran", 107), ("llca", 107), ("furt", 106), ("rste", 106),
+    ("lera", 106), ("leri", 106), ("seru", 106), ("tdis", 106), ("ntou", 106), ("toun", 106),
+    ("erla", 106), ("dtoo", 106), ("esem", 106), ("aand", 106), ("osea", 106), ("sinr", 106),
+    ("itwo", 106), ("gare", 106), ("thit", 106), ("sons", 106), ("dtoe", 106), ("grea", 106),
+    ("pote", 106), ("ersf", 106), ("llyu", 106), ("ksth", 106), ("sowe", 106), ("ohas", 106),
+    ("ells", 106), ("grap", 106), ("toff", 106), ("ocke", 106), ("rywi", 106), ("emes", 106),
+    ("leca", 106), ("dema", 106), ("vice", 106), ("agst", 106), ("seen", 105), ("ginf", 105),
+    ("nreq", 105), ("henp", 105), ("ofst", 105), ("meex", 105), ("ntse", 105), ("acto", 105),
+    ("ttop", 105), ("ofva", 105),
+];
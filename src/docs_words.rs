@@ -0,0 +1,1330 @@
+use std::sync::OnceLock;
+
+/// The 10,000 most common words in the prose of the Rust documentation (the
+/// book, the reference, the standard library docs, the release notes and the
+/// other guides shipped with the toolchain), most common first. Code samples
+/// are excluded.
+///
+/// This is not a general English word list: it is heavy with technical
+/// terms such as "rustc" and "clippy" and includes contributors' names. The
+/// rarest words occur only once in the documentation, so the tail of the
+/// list also has a few misspellings.
+#[rustfmt::skip]
+pub(crate) static DOCS_WORDS: [&str; 10000] = [
+    "the", "to", "a", "is", "of", "and", "in", "this", "for", "that", "be", "if", "with", "not",
+    "are", "documentation", "an", "or", "on", "elements", "from", "as", "it", "by", "can",
+    "examples", "value", "will", "type", "when", "using", "packed", "you", "returns", "which",
+    "function", "use", "store", "set", "dst", "code", "element", "see", "all", "bit", "rust", "may",
+    "slice", "one", "at", "we", "example", "results", "corresponding", "integers", "mask", "only",
+    "have", "used", "result", "vector", "method", "any", "into", "exceptions", "same", "has", "but",
+    "error", "more", "round", "integer", "must", "note", "no", "suppress", "out", "memory",
+    "pointer", "types", "number", "also", "than", "two", "does", "values", "trait", "then", "new",
+    "target", "operation", "other", "each", "cargo", "now", "panics", "specified", "reference",
+    "so", "return", "instead", "its", "bits", "rounding", "string", "version", "default",
+    "iterator", "should", "data", "up", "like", "returned", "src", "these", "parameter", "compiler",
+    "unsigned", "such", "where", "safety", "some", "overflow", "writemask", "items", "lower",
+    "multiple", "copied", "signed", "file", "behavior", "your", "add", "zeroed", "crate", "convert",
+    "first", "panic", "zeromask", "current", "feature", "support", "equal", "need",
+    "implementation", "without", "because", "order", "do", "there", "would", "call", "bytes", "was",
+    "they", "zero", "intrinsic", "upper", "unsafe", "name", "expression", "build", "index", "lint",
+    "syntax", "information", "following", "been", "always", "given", "macro", "pattern", "time",
+    "argument", "library", "implement", "want", "flag", "multiply", "even", "mutable", "functions",
+    "possible", "after", "copy", "available", "down", "done", "before", "output", "according",
+    "about", "case", "run", "load", "range", "over", "part", "how", "different", "converts",
+    "ordering", "closure", "block", "attributes", "issue", "expr", "nearest", "calling", "while",
+    "compare", "path", "end", "length", "intermediate", "their", "size", "operations", "change",
+    "makes", "struct", "via", "shift", "listing", "equivalent", "make", "truncate", "within",
+    "being", "valid", "takes", "whether", "byte", "cannot", "way", "lifetime", "complex", "match",
+    "package", "attribute", "most", "between", "atomic", "targets", "returning", "variable",
+    "longer", "computes", "pointers", "details", "however", "features", "test", "might", "binary",
+    "safe", "what", "those", "references", "standard", "methods", "called", "otherwise", "means",
+    "sign", "point", "usage", "future", "instruction", "array", "creates", "check", "i", "module",
+    "them", "patterns", "tracking", "either", "both", "crates", "edition", "directory",
+    "associated", "address", "book", "raw", "right", "tests", "field", "bitwise", "scope",
+    "defined", "arguments", "undefined", "uses", "registers", "another", "dependencies", "empty",
+    "allows", "added", "here", "key", "thread", "program", "slices", "traits", "allocation", "just",
+    "access", "execution", "environment", "provided", "still", "required", "single", "could",
+    "item", "our", "create", "checks", "neon", "platforms", "created", "get", "second", "source",
+    "generic", "section", "work", "bounds", "left", "option", "intro", "itself", "through", "many",
+    "basic", "link", "contains", "since", "list", "saturating", "numbers", "instance", "currently",
+    "endian", "names", "errors", "enabled", "literal", "enum", "control", "changes", "write",
+    "subtract", "implemented", "four", "particular", "last", "windows", "compile", "less", "files",
+    "long", "maximum", "structures", "dependency", "input", "never", "cast", "fix", "rules",
+    "contents", "passed", "starting", "tuple", "options", "line", "language", "representation",
+    "least", "minimum", "expressions", "allowed", "take", "passing", "rs", "read", "variables",
+    "wrapping", "platform", "useful", "ensure", "allow", "contain", "search", "fields", "vectors",
+    "implements", "three", "layout", "parameters", "system", "chapter", "underlying", "absolute",
+    "original", "next", "containing", "cases", "rather", "distributed", "previous", "capacity",
+    "written", "every", "runtime", "inside", "const", "produce", "alignment", "similar", "macros",
+    "resulting", "calls", "removed", "exactly", "character", "main", "tier", "token", "requires",
+    "threads", "comparison", "characters", "once", "implementations", "format", "matches",
+    "release", "sets", "object", "describes", "running", "common", "greater", "provides", "already",
+    "aligned", "lanes", "find", "times", "lints", "provide", "requirements", "supported", "process",
+    "except", "root", "negative", "compute", "versions", "paths", "well", "message", "building",
+    "command", "stable", "cause", "shared", "based", "variant", "generated", "state", "place",
+    "consider", "including", "were", "automatically", "specify", "libraries", "contained", "rustc",
+    "operator", "loop", "avoid", "bound", "fixed", "configuration", "performs", "project",
+    "structure", "filename", "around", "precision", "require", "back", "internal", "erroneous",
+    "slots", "instructions", "ownership", "directly", "composed", "start", "above", "unstable",
+    "found", "specific", "allocator", "indices", "flags", "below", "lock", "include", "guaranteed",
+    "tools", "calculates", "move", "pass", "represent", "significant", "remainder", "dropped",
+    "stabilized", "expected", "location", "unspecified", "across", "needs", "occur", "zeros",
+    "boundary", "stored", "position", "mode", "therefore", "named", "config", "definition",
+    "previously", "fail", "register", "known", "amount", "additional", "handle", "writing", "inner",
+    "host", "look", "followed", "stores", "form", "strings", "big", "count", "producing", "unlike",
+    "user", "workspace", "adding", "own", "table", "buffer", "addition", "attempts", "reinterpret",
+    "little", "positive", "shifting", "keyword", "supports", "packages", "std", "built", "messages",
+    "compilation", "extern", "static", "division", "please", "allocated", "due", "update",
+    "general", "intended", "remove", "sorted", "async", "enable", "implementing", "borrow",
+    "guarantees", "base", "invalid", "matching", "users", "offset", "scalar", "blocks", "thus",
+    "registry", "total", "larger", "extra", "optional", "impl", "lifetimes", "until", "checked",
+    "borrowed", "defines", "local", "special", "performance", "adjacent", "often", "low", "caller",
+    "considered", "prefix", "during", "web", "against", "explicitly", "custom", "likely", "define",
+    "constant", "toolchain", "checking", "inputs", "strict", "manifest", "know", "working",
+    "binaries", "failure", "able", "wrapped", "generate", "includes", "high", "lane", "allocate",
+    "arithmetic", "adds", "trailing", "something", "connection", "initialized", "heap", "structs",
+    "depending", "us", "generally", "doing", "conversion", "debug", "warning", "native", "very",
+    "testing", "literals", "predicate", "try", "perform", "print", "unix", "stack", "show", "docs",
+    "entire", "though", "external", "actually", "yet", "metadata", "display", "changed", "operand",
+    "works", "boolean", "rustdoc", "creating", "public", "half", "space", "typically", "exception",
+    "leading", "represented", "gets", "indicates", "give", "linker", "why", "programs", "subslice",
+    "existing", "script", "compatibility", "saturation", "provenance", "refer", "variants",
+    "panicking", "accumulate", "occurred", "together", "explicit", "explanation", "clippy",
+    "endianness", "context", "indicating", "cursor", "level", "reads", "arm", "unicode", "sort",
+    "better", "primitive", "true", "correct", "active", "floating", "socket", "suppressed",
+    "beginning", "specifying", "invocation", "none", "made", "io", "selected", "guarantee",
+    "smaller", "asm", "nightly", "full", "depends", "builds", "lex", "too", "fails", "setting",
+    "functionality", "usually", "compiled", "channel", "final", "apply", "points", "came",
+    "uninitialized", "truncation", "digits", "help", "closures", "drop", "separate", "linux",
+    "self", "included", "sequence", "internet", "iterators", "along", "hardware", "under",
+    "numeric", "unless", "map", "stabilize", "body", "constructs", "sae", "having", "pointing",
+    "properly", "moved", "hash", "success", "statement", "kind", "meaning", "regardless",
+    "requirement", "difference", "mutability", "arrays", "again", "exists", "represents",
+    "condition", "suffix", "addresses", "interval", "problem", "simple", "exact", "needed",
+    "warnings", "fully", "insert", "present", "corresponds", "multiplication", "old", "union",
+    "separated", "resolution", "compares", "recommended", "modes", "notes", "reason", "looks",
+    "objects", "handling", "later", "modules", "things", "wrap", "applied", "detects", "formatting",
+    "interior", "emitted", "put", "intrinsics", "issues", "extract", "architecture", "artifacts",
+    "shuffle", "programming", "outside", "relative", "else", "alias", "alternative", "owned",
+    "collection", "yields", "shows", "whose", "specifies", "temporary", "exist", "log", "text",
+    "systems", "arbitrary", "writes", "whitespace", "git", "style", "hold", "matched", "power",
+    "pairs", "page", "correctly", "keys", "conditions", "cfg", "various", "copies", "alternatively",
+    "enables", "executable", "profile", "codegen", "immutable", "immediately", "network", "capture",
+    "much", "respective", "semantics", "removes", "smallest", "certain", "rounded", "null",
+    "manually", "differ", "overlap", "normal", "remaining", "declared", "tool", "parent", "chunks",
+    "etc", "definitions", "prevent", "guard", "words", "signature", "ones", "entry", "appropriate",
+    "identifier", "nul", "generics", "determined", "mutex", "unit", "sure", "appear", "nothing",
+    "representing", "making", "initial", "applies", "subtraction", "follow", "assembly",
+    "necessary", "losslessly", "updated", "logic", "extended", "copying", "immediate", "enums",
+    "become", "cache", "shown", "square", "accesses", "shifts", "break", "loads", "large",
+    "embedded", "normally", "group", "simply", "successful", "https", "anything", "private",
+    "disabled", "statements", "subslices", "linking", "several", "prelude", "dynamic", "false",
+    "runs", "global", "task", "destructors", "accepted", "ambiguity", "scale", "let", "related",
+    "swapped", "reading", "nor", "reverse", "weak", "happens", "situations", "document", "content",
+    "affected", "fn", "infinity", "important", "occurs", "produces", "deprecated", "gives", "sized",
+    "ways", "invariants", "equality", "few", "actual", "operators", "lib", "reduce", "extension",
+    "reserved", "instances", "wide", "purpose", "unique", "marked", "contexts", "narrow", "lines",
+    "core", "attempt", "starts", "ignored", "stream", "determine", "assuming", "parts", "rustup",
+    "team", "destructor", "binding", "determines", "inline", "languages", "effect", "restrictions",
+    "component", "scripts", "follows", "request", "interface", "doc", "optimizations", "compatible",
+    "ever", "glob", "side", "keep", "operating", "comments", "rule", "unaligned", "pair", "safely",
+    "faster", "splits", "sum", "wrapper", "top", "depend", "scopes", "max", "declaration",
+    "sometimes", "overrides", "extensions", "negated", "hard", "affect", "logical", "model",
+    "acquire", "partial", "guide", "go", "iteration", "extend", "satisfy", "subject", "import",
+    "min", "incorrect", "clone", "installed", "tree", "converted", "rest", "derive", "emit",
+    "further", "causes", "float", "whole", "specifically", "tokens", "compiling", "kinds", "trying",
+    "additionally", "usual", "allowing", "unsized", "divide", "derived", "maintainers", "gap",
+    "replace", "changing", "ensures", "constants", "development", "namespace", "foreign",
+    "integration", "members", "accessing", "moves", "differences", "becomes", "ignore", "warn",
+    "diagnostics", "forms", "inserted", "chunk", "performed", "happen", "replaced", "remain",
+    "fast", "failed", "succeeds", "operands", "care", "interpreted", "send", "open", "described",
+    "defaults", "commands", "duplicate", "repository", "missing", "resolver", "allocations",
+    "sense", "child", "improvements", "coercion", "prefer", "editions", "settings", "split",
+    "exclusive", "algorithm", "excluding", "units", "implicit", "varies", "contiguous", "prints",
+    "places", "consumes", "unchecked", "smart", "assume", "moving", "unwinding", "unchanged",
+    "ends", "concrete", "ranges", "specification", "machine", "describe", "unwind", "essentially",
+    "doubling", "implicitly", "branch", "decl", "word", "sufficient", "accumulator", "box",
+    "easier", "flow", "accessed", "generates", "optimization", "unused", "evaluated", "equals",
+    "visibility", "overflowing", "regular", "install", "exit", "groups", "railroad", "symbol",
+    "projects", "dropping", "optimized", "relaxed", "free", "almost", "defining", "goes", "linked",
+    "potentially", "summary", "keywords", "small", "largest", "although", "good", "invariant",
+    "bug", "concatenate", "consistent", "respect", "override", "evaluation", "declarations",
+    "short", "produced", "components", "breaking", "imports", "exponent", "renamed", "continue",
+    "problems", "respectively", "indicate", "limited", "middle", "distinct", "impls", "idx",
+    "letters", "accept", "fused", "construct", "had", "whenever", "modified", "directories",
+    "captured", "neither", "complete", "truncated", "improve", "did", "subset", "decimal",
+    "reduced", "com", "deque", "minimal", "reciprocal", "multiplies", "clear", "borrows",
+    "receiver", "reorder", "dangling", "convention", "concurrency", "front", "detect",
+    "identifiers", "server", "inferred", "strong", "virtual", "turn", "links", "modify", "indexing",
+    "preserving", "proc", "possibly", "removing", "holds", "providing", "coerce", "compound",
+    "detail", "finally", "validity", "going", "toml", "poisoned", "duplicates", "portability",
+    "nested", "dereference", "twice", "parsing", "resolve", "lead", "bindings", "logarithm", "say",
+    "report", "euclidean", "enabling", "really", "finds", "futures", "encoding", "cell", "precise",
+    "selection", "casting", "terminal", "fit", "floats", "inherent", "modular", "reversed",
+    "diagnostic", "towards", "major", "cloning", "requests", "interfaces", "compared", "toward",
+    "underscores", "execute", "enough", "disable", "expansion", "consequence", "taking", "among",
+    "computation", "primitives", "tries", "choose", "expect", "sent", "upon", "maps", "symbols",
+    "share", "resources", "converting", "uncovered", "assignment", "handles", "accepts", "controls",
+    "bool", "similarly", "everything", "tell", "borrowing", "deref", "earlier", "interleave",
+    "seen", "terms", "optimize", "outer", "referenced", "debugging", "linear", "consecutive",
+    "replicate", "declare", "bugs", "verify", "lets", "statically", "executed", "average",
+    "reverses", "careful", "status", "newly", "publish", "aliasing", "broadcast", "off", "uphold",
+    "encoded", "discriminant", "abi", "select", "info", "fraction", "terminator", "escape", "step",
+    "refers", "developers", "discussion", "desired", "published", "implies", "situation", "limit",
+    "rely", "extracts", "applying", "procedural", "placed", "span", "allocates", "notice", "wrong",
+    "negation", "inclusive", "supplied", "yielded", "libc", "exponentiation", "lowest", "ascending",
+    "mark", "forward", "executing", "unpack", "best", "modifying", "chosen", "github", "sync",
+    "duration", "substring", "triple", "comes", "lot", "listed", "description", "sorting", "sorts",
+    "abort", "pinning", "partially", "synchronization", "yield", "carry", "divides", "third",
+    "looking", "aliases", "modifications", "architectures", "automatic", "located", "behind",
+    "stop", "computed", "parses", "repr", "mapped", "understand", "assign", "fact", "away", "taken",
+    "pinned", "obtained", "shifted", "property", "comment", "efficient", "tuples", "higher",
+    "mutate", "searches", "filesystem", "identical", "migration", "infinite", "introduce", "track",
+    "verbose", "consuming", "allocating", "direct", "casts", "def", "printed", "remains", "mostly",
+    "resolved", "prior", "checker", "relevant", "strictly", "padding", "portable", "raises",
+    "attempting", "easy", "beyond", "assumes", "consists", "benchmark", "newer", "orderings",
+    "minor", "double", "necessarily", "internally", "conditional", "initialization", "license",
+    "signaling", "semantically", "kernel", "generation", "discussed", "broken", "constraint",
+    "applications", "parallel", "passes", "overflows", "preserve", "come", "think", "filled",
+    "spec", "color", "affects", "improved", "older", "reports", "act", "tells", "locations",
+    "coverage", "mapping", "documented", "boxed", "behaviour", "extraction", "people", "storing",
+    "advanced", "sections", "width", "generating", "inference", "invoked", "integral",
+    "constructed", "approximate", "quiet", "brackets", "series", "evaluates", "prevents",
+    "application", "collections", "mutably", "repeated", "formatted", "exits", "hint", "tail",
+    "conjugate", "discuss", "treated", "entirely", "dynamically", "practice", "signal", "fallback",
+    "conversions", "releases", "lukas", "reporting", "design", "commonly", "helps", "tasks",
+    "considerations", "reasons", "separator", "constraints", "loops", "software", "successfully",
+    "wraps", "obtain", "critical", "preferred", "destination", "entries", "descriptor", "bergdoll",
+    "scaled", "constructor", "printing", "label", "query", "tested", "completion", "colors",
+    "exposed", "processor", "experimental", "credential", "mean", "concept", "dereferencing",
+    "especially", "attempted", "annotation", "others", "calculation", "meant", "fixes", "displayed",
+    "precedence", "correspond", "fence", "ident", "benchmarks", "vindex", "far", "storage",
+    "ability", "browser", "concerns", "download", "latest", "omitted", "replaces", "region", "tag",
+    "eight", "handled", "char", "response", "parse", "ordered", "solution", "imported", "modifiers",
+    "int", "updating", "introduced", "ready", "violated", "past", "soon", "lookup", "leaving",
+    "restricted", "soundness", "product", "fragment", "mut", "invocations", "volatile",
+    "registries", "early", "race", "iterating", "completely", "wish", "divided", "toolchains",
+    "opaque", "bare", "rotate", "interv", "cover", "plus", "getting", "referred", "accidentally",
+    "captures", "configure", "per", "publishing", "parsed", "graph", "transitive", "handler",
+    "backwards", "latency", "misc", "states", "spaces", "environments", "profiles", "behaves",
+    "approximation", "configured", "subtracts", "payload", "preludes", "stdout", "fuchsia", "orson",
+    "peters", "figure", "individual", "scenarios", "container", "sources", "pin", "acquired",
+    "unsupported", "switch", "steps", "real", "potential", "designed", "org", "wildcard",
+    "combines", "internals", "observed", "cross", "learn", "lists", "probably", "mentioned",
+    "spawned", "requested", "await", "comparing", "outputs", "processors", "inverse", "boundaries",
+    "timeout", "variance", "thing", "behave", "progress", "locally", "selector", "portion",
+    "restrict", "helper", "unknown", "tiered", "positions", "choice", "overlapping", "particularly",
+    "possibility", "ignoring", "site", "locked", "ambiguous", "offsets", "debugger", "conjugates",
+    "conflict", "performing", "hand", "causing", "port", "commit", "template", "worst",
+    "qualifiers", "counterpart", "retrieved", "tmp", "analysis", "difficult", "account",
+    "stability", "loaded", "permitted", "debuginfo", "coroutine", "violate", "combination", "said",
+    "restriction", "waiting", "suitable", "clang", "detailed", "strategy", "symbolic", "leak",
+    "bounded", "replacement", "statics", "zip", "knows", "recursive", "whatever", "dereferenced",
+    "pointed", "blocking", "who", "greatest", "conventions", "formats", "pull", "preconditions",
+    "policy", "sc", "signatures", "contrast", "unexpected", "convenient", "live", "shell", "mod",
+    "chaining", "sequences", "extending", "processes", "masks", "migrate", "detection", "annotated",
+    "remember", "wait", "opening", "repeatedly", "apple", "artifact", "fine", "expanded",
+    "uniquely", "escapes", "highest", "beware", "comparator", "pairwise", "themselves", "purposes",
+    "holding", "impossible", "keeps", "qualified", "efficiently", "convenience", "meaningless",
+    "trigger", "authentication", "doctests", "transpose", "parentheses", "begins", "world",
+    "compiles", "approach", "ensuring", "fetches", "capturing", "inserts", "primary", "comma",
+    "unnecessary", "terminated", "normalized", "unzip", "lowercase", "splitting", "properties",
+    "mechanism", "failing", "concurrently", "basis", "responsible", "turning", "member", "ties",
+    "auto", "grammar", "escaped", "marker", "primarily", "effectively", "assertions",
+    "monomorphization", "dyn", "coercions", "console", "reader", "drawbacks", "annotations", "talk",
+    "assigned", "appendix", "combine", "coerced", "expand", "shadow", "inherited", "magic",
+    "modifier", "abstract", "whereas", "naming", "sending", "arms", "receive", "appears", "clause",
+    "manual", "visible", "ui", "protection", "writer", "covered", "bind", "mind", "overall",
+    "tables", "outlive", "clarify", "meaningful", "installation", "invoking", "chains", "combined",
+    "queue", "pool", "favor", "hook", "super", "encountered", "quotes", "backend", "provider",
+    "exceeds", "achieving", "glibc", "wake", "broadcasts", "canonical", "considers", "iterate",
+    "knowledge", "anywhere", "expose", "save", "transition", "conditionally", "calculate",
+    "subsequent", "finite", "supporting", "atomically", "sound", "outlives", "disables", "unsorted",
+    "device", "programmer", "searching", "repetition", "involved", "received", "interested",
+    "random", "post", "underscore", "irrefutable", "backtrace", "covariant", "rounds", "quicksort",
+    "helpful", "advantage", "unsafety", "creation", "family", "lengths", "processing", "html",
+    "independent", "assumed", "hence", "specifier", "emits", "succeed", "suggestions", "clones",
+    "raise", "normalize", "hexagon", "deallocated", "upheld", "question", "protocol", "enter",
+    "clean", "anonymous", "maintained", "assumptions", "class", "invalidate", "tooling", "vtable",
+    "reachable", "overridden", "contiguously", "infer", "worry", "responsibility", "suite", "cycle",
+    "originally", "permission", "legal", "yes", "digit", "avoids", "intel", "bring", "complexity",
+    "placeholder", "speed", "repeat", "destructuring", "decide", "interrupt", "join", "fill",
+    "swaps", "ipnsort", "easily", "caused", "hidden", "levels", "pretty", "giving", "separately",
+    "applicable", "counts", "thin", "ignores", "markdown", "meantime", "maintaining", "accounted",
+    "attached", "temporaries", "view", "peripheral", "optionally", "semicolon", "concepts",
+    "slightly", "official", "updates", "unlikely", "fetch", "finished", "cost", "wants", "ship",
+    "incremental", "executables", "reordered", "hexadecimal", "xor", "encouraged", "distance",
+    "unlocked", "res", "hello", "technically", "enforce", "warns", "interpretation", "bad",
+    "shorter", "belongs", "indicated", "finish", "notation", "translation", "asynchronous", "owner",
+    "security", "effects", "precisely", "propagated", "pointee", "merged", "mantissa", "initialize",
+    "interact", "declaring", "races", "treat", "manage", "introducing", "proper", "spawning",
+    "incompatible", "destructure", "displays", "unbounded", "uppercase", "eventually", "conflicts",
+    "interfere", "relied", "scrutinee", "exceed", "elementwise", "requiring", "review", "express",
+    "cleanup", "matter", "elided", "downloaded", "managed", "swap", "refutable", "shrink", "vec",
+    "ref", "meta", "prefixes", "reduction", "remote", "clamps", "curly", "action", "variety", "opt",
+    "community", "released", "avoided", "constructing", "suggest", "rejected", "subtyping",
+    "lockfile", "musl", "ending", "catch", "consume", "despite", "confusing", "quite", "hosts",
+    "counter", "job", "happening", "course", "locks", "reserve", "node", "succeeded", "reflexive",
+    "permissions", "android", "fills", "increase", "familiar", "contract", "asserts", "fourth",
+    "concurrent", "leaves", "closed", "overview", "sample", "reuse", "leave", "home", "mutated",
+    "covers", "deallocate", "deterministic", "targeting", "entity", "standalone", "bar", "promote",
+    "evaluate", "incorrectly", "operate", "labels", "trivially", "rewritten", "usable", "locking",
+    "correctness", "lazily", "satisfied", "modulo", "hints", "migrating", "pack", "searched", "fig",
+    "tile", "overhead", "deny", "preferable", "unsound", "redundant", "owners", "got", "replacing",
+    "started", "chapters", "reached", "shorthand", "clauses", "namespaces", "operates", "merge",
+    "meet", "elision", "poisoning", "lang", "preserved", "prefixed", "matcher", "activate", "repo",
+    "fat", "quotient", "squaring", "mantissas", "mistake", "abstraction", "idiomatic", "exposes",
+    "cloned", "describing", "happened", "deal", "header", "skip", "force", "satisfies", "leaks",
+    "nonzero", "stderr", "caveats", "placing", "definitely", "proceed", "explain", "term", "idea",
+    "diverging", "dot", "resource", "consumed", "inherit", "manager", "blocked", "circumstances",
+    "parser", "proposal", "notably", "transmuting", "backends", "floor", "nand", "thought",
+    "intent", "subtle", "globally", "disallowed", "supertraits", "introduces", "reported",
+    "authors", "suggested", "furthermore", "vis", "peripherals", "picked", "rotation",
+    "partitioned", "substrings", "explore", "preceding", "capabilities", "recall", "continues",
+    "wanted", "differently", "game", "pick", "lazy", "roughly", "manner", "indeed", "lie",
+    "extracted", "runner", "negate", "inherits", "distribution", "infrastructure", "management",
+    "presence", "reordering", "variadic", "numerous", "lexicographically", "expectations",
+    "significand", "waker", "programmers", "coming", "combining", "mutation", "mutating", "close",
+    "keeping", "opposed", "invoke", "great", "executes", "yourself", "categories", "workspaces",
+    "opposite", "closing", "upgrade", "subcommand", "simplify", "breakage", "buffers", "indirect",
+    "sysroot", "attr", "delimiters", "expanding", "appended", "truncating", "radians", "terminate",
+    "tried", "initializer", "relying", "worth", "adapter", "channels", "alternate", "executor",
+    "rare", "offline", "leads", "blanket", "wider", "developer", "jobs", "harness", "bugfixes",
+    "invokes", "basically", "braces", "codes", "textual", "failures", "ci", "flatten", "ternary",
+    "representable", "differs", "drops", "implementors", "relation", "shadowing", "distinguish",
+    "transformation", "limits", "seconds", "accurate", "callers", "readers", "disk", "paired",
+    "regions", "representations", "scoped", "discriminants", "silently", "rustfmt", "timing",
+    "colored", "nesting", "recursively", "chain", "abstractions", "met", "saw", "benefits",
+    "skipped", "downstream", "halves", "configurations", "begin", "fashion", "duplicated", "hasher",
+    "lives", "guess", "introduction", "caching", "perhaps", "typical", "violation", "validation",
+    "dedicated", "gate", "board", "relies", "aborts", "instantiated", "frame", "recovery",
+    "horizontal", "vendor", "radix", "brace", "leaked", "negates", "magnitude", "heapsort",
+    "widening", "halving", "held", "assertion", "accessible", "unions", "miri", "conjunction",
+    "substituted", "completed", "preventing", "clearly", "factor", "slow", "pages", "setup",
+    "simultaneously", "aborting", "parallelism", "atomics", "transmutes", "delimiter",
+    "limitations", "cryptography", "excess", "seek", "emulator", "clock", "frequent", "reversing",
+    "guest", "elsewhere", "suggests", "life", "online", "throughout", "connections", "foo",
+    "needing", "counting", "rebuild", "worked", "inheritance", "dispatch", "minus", "guards",
+    "constructors", "dereferences", "operated", "propagating", "disjoint", "resolving", "naked",
+    "backtracking", "removal", "entropy", "doctest", "builder", "reallocate", "discovering",
+    "demangling", "masked", "turns", "annotate", "alter", "append", "record", "quickly", "newline",
+    "latter", "ecosystem", "receives", "waits", "violating", "significantly", "expands",
+    "credentials", "freely", "cycles", "aware", "disallow", "afterwards", "directives", "clobbers",
+    "inlined", "unary", "eagerly", "priority", "flattens", "speculatively", "exhaustion", "folding",
+    "polynomial", "volume", "horizontally", "functional", "suggestion", "exclude", "readable",
+    "referring", "pieces", "propagation", "maintain", "streams", "independently", "guidelines",
+    "fewer", "nicer", "median", "sizes", "supertrait", "onto", "subcommands", "initializing",
+    "controlled", "exported", "hex", "extends", "edge", "forces", "glue", "turned", "predicates",
+    "highly", "arises", "driver", "divisor", "subnormal", "complicated", "marking", "impact",
+    "unable", "technique", "strategies", "installing", "completes", "teams", "solve", "branches",
+    "rename", "backing", "reasonable", "nodes", "blog", "advance", "superset", "fall", "lexer",
+    "mixed", "dispose", "dependent", "caveat", "column", "convertible", "args", "haystack",
+    "materialize", "topic", "encode", "interacting", "dealing", "export", "assert", "serve",
+    "transferred", "quote", "unfortunately", "retrieve", "showing", "compilers", "formatter",
+    "destroyed", "notable", "str", "scheme", "odd", "handlers", "conflicting", "matrix",
+    "reallocated", "descriptors", "auxiliary", "deterministically", "somewhere", "bodies", "boxes",
+    "scenario", "rustonomicon", "interpret", "largely", "offers", "production", "feel", "refactor",
+    "achieve", "receiving", "ask", "flexible", "labeled", "fits", "semantic", "behaviors",
+    "coherence", "confusion", "comparisons", "rendering", "resolves", "automated", "temporarily",
+    "syntactically", "existence", "subtype", "triggered", "enclosing", "suffixes", "entities",
+    "newlines", "theme", "discovery", "detected", "medium", "instrumentation", "zeroes", "sixteen",
+    "says", "inserting", "enumeration", "learned", "referencing", "interesting", "collecting",
+    "involves", "newtype", "plain", "former", "organization", "hashing", "capability", "visual",
+    "jump", "implied", "events", "yielding", "sleep", "bump", "surprising", "concern", "fragments",
+    "alloc", "wasm", "spuriously", "activated", "emulation", "disambiguator", "mutates",
+    "overflowed", "rotates", "saturated", "indirection", "reject", "human", "sends", "choosing",
+    "author", "vs", "experience", "omit", "slower", "patch", "devices", "additions", "mathematical",
+    "specialized", "inspect", "sockets", "indexed", "closer", "children", "approval", "pending",
+    "consist", "denotes", "established", "arc", "protected", "reserves", "category", "misaligned",
+    "registered", "stdin", "var", "symlink", "baseline", "instrumented", "reallocation",
+    "dereferenceable", "buffered", "disconnected", "piece", "mangling", "appends", "answer",
+    "alternatives", "aka", "flexibility", "clearer", "improvement", "connected", "protocols",
+    "finishes", "focus", "ended", "documenting", "powerful", "forever", "trivial", "logically",
+    "calculated", "area", "dividing", "welcome", "importing", "exhaustive", "lack", "counted",
+    "extracting", "consistency", "effort", "selects", "optimizer", "candidates", "qualifier",
+    "builtin", "cmp", "delimited", "lossless", "plugin", "jobserver", "pipe", "pipelines",
+    "hardfloat", "emscripten", "neutrino", "reinterpreted", "reflexivity", "reorders",
+    "quadruplets", "examine", "risk", "acceptable", "explaining", "closest", "equivalence",
+    "protect", "reducing", "headers", "unconditionally", "selecting", "attacks", "wikipedia",
+    "delete", "aspects", "natural", "expensive", "saved", "opened", "backtraces", "licensed",
+    "init", "fractional", "noted", "purely", "machines", "green", "configuring", "sparse",
+    "arbitrarily", "event", "emitting", "fundamental", "strip", "notification", "rlib", "bounding",
+    "untouched", "punctuation", "symmetric", "apache", "facing", "exhausted", "spurious", "elapsed",
+    "nth", "reallocating", "quadruplet", "assigning", "perfectly", "demonstrate", "putting",
+    "involving", "computing", "assumption", "likewise", "depth", "ultimately", "caution", "caught",
+    "modification", "simpler", "sharing", "privacy", "brings", "reset", "rendered", "whichever",
+    "initially", "immutably", "loading", "overloading", "regarding", "freed", "encounter",
+    "reliably", "background", "technical", "simplest", "insertion", "window", "interrupts",
+    "discarded", "desugar", "uninhabited", "len", "desugaring", "overwrites", "repeats",
+    "syntactic", "hygiene", "segment", "params", "forwards", "spans", "env", "url", "owing", "kth",
+    "introselect", "medians", "ninther", "pivot", "directionality", "arabic", "hebrew", "shuffles",
+    "expects", "expressed", "majority", "understanding", "denote", "concise", "actions", "thanks",
+    "hierarchy", "deliberately", "hide", "containers", "relationship", "runtimes",
+    "microcontroller", "rarely", "guessing", "evaluating", "specifications", "numerically",
+    "explains", "anyway", "overriding", "overwrite", "waited", "offer", "fixing", "ensured",
+    "thumb", "fulfill", "beta", "propagate", "dylib", "cheap", "desirable", "alive", "carefully",
+    "tags", "direction", "verbatim", "chooses", "discarding", "completeness", "contrib",
+    "positives", "ghost", "blend", "stays", "sees", "brought", "enforced", "falls", "seem", "grow",
+    "parenthesized", "panicked", "indexes", "imagine", "communicate", "upstream", "owns", "prove",
+    "treating", "folder", "quick", "row", "consistently", "pipes", "overwritten", "analogous",
+    "fairly", "website", "bracket", "exiting", "button", "interaction", "legacy", "sender",
+    "controlling", "yank", "decided", "picks", "consumers", "scheduling", "scheduled", "assist",
+    "deadlock", "absence", "dev", "infallible", "triggers", "weird", "owning", "align", "mandatory",
+    "raised", "manipulation", "projections", "segments", "algebraic", "deprecation", "discards",
+    "freestanding", "sanitizer", "midpoint", "partition", "shrinks", "retained", "occupied",
+    "cursors", "transfer", "signals", "moment", "extremely", "goal", "crash", "complement",
+    "minimize", "accordance", "popular", "client", "networking", "feed", "render", "rewrite",
+    "somewhat", "someone", "characteristics", "identify", "reaches", "date", "determining",
+    "randomly", "appropriately", "encounters", "supposed", "mixing", "shadowed", "retain",
+    "synchronous", "fetching", "expecting", "disabling", "simplified", "illegal", "lifted", "weeks",
+    "recent", "proposed", "easiest", "libstd", "bypassing", "recommend", "transparent", "transmute",
+    "unreachable", "layer", "obligations", "natively", "prefetch", "filters", "inlining",
+    "communication", "libcore", "testsuite", "suffixed", "simulator", "estimate", "woken", "clears",
+    "wavefront", "resultant", "straightforward", "five", "leaking", "demonstrates", "kept",
+    "originated", "appending", "screen", "bottom", "confused", "stay", "submodules", "secret",
+    "repeating", "historically", "matters", "monomorphized", "mechanisms", "unset", "avoiding",
+    "alongside", "questions", "surrounding", "filling", "deriving", "serves", "octal", "draft",
+    "mainly", "declarative", "acts", "indentation", "benchmarking", "besides", "mitigation",
+    "documents", "forbidden", "permit", "poor", "permits", "reflect", "unsizing", "termination",
+    "repetitions", "pub", "shebang", "trees", "identity", "restore", "issued", "debian",
+    "repositories", "changelog", "symlinks", "cfgs", "monotonic", "copyright", "coroutines",
+    "bitwidth", "bignum", "subtracting", "multiplications", "driftsort", "mergesort", "hyperbolic",
+    "multicast", "nanoseconds", "funky", "recursion", "saying", "forget", "promise", "collisions",
+    "growable", "domain", "collect", "solutions", "refactoring", "wrote", "looked", "bin",
+    "closely", "accepting", "wiki", "orphan", "increasing", "generator", "eliminate", "forced",
+    "transform", "consumption", "gather", "apart", "bunch", "poll", "developed", "session",
+    "overloaded", "specifiers", "discard", "exited", "construction", "backward", "historical",
+    "proposals", "incomplete", "tracked", "linkage", "interoperability", "assembler", "expansions",
+    "improper", "ing", "stabilization", "dashes", "bootstrap", "syscall", "xcode", "pointees",
+    "functionally", "insertions", "inclusively", "hart", "cleaned", "years", "contracts",
+    "verified", "detecting", "typing", "person", "assignments", "finding", "filtering",
+    "experiment", "successive", "meets", "divisible", "retry", "reusing", "marks", "regression",
+    "indent", "wherever", "threading", "robust", "duplication", "ergonomics", "straight", "archive",
+    "mention", "inspecting", "malformed", "period", "combinations", "editing", "pertaining",
+    "greatly", "maybe", "synchronize", "erroneously", "semihosting", "ideally", "exclusively", "ok",
+    "transmutation", "proxy", "assignee", "negating", "unification", "miscellaneous", "forbid",
+    "galois", "supervisor", "upgraded", "schema", "completions", "semver", "providers", "bases",
+    "respects", "libtest", "ie", "nonblocking", "stdio", "unpacks", "commas", "indirectly", "six",
+    "colon", "everywhere", "reach", "anyone", "tricky", "stands", "ran", "managing", "benefit",
+    "organized", "extensive", "everyone", "algorithms", "explained", "declares", "binds",
+    "concerned", "tied", "intend", "sugar", "interest", "revision", "utilities", "involve", "edit",
+    "yanked", "renaming", "visiting", "frequently", "acquires", "aliased", "pure", "transmuted",
+    "achieved", "indeterminate", "trick", "unwinds", "unnamed", "paren", "separators", "image",
+    "outlined", "joined", "unified", "phase", "profiling", "filter", "indented", "binder", "chip",
+    "lost", "physical", "kernels", "caches", "redox", "solaris", "degrees", "concatenates", "sine",
+    "cosine", "reallocations", "unordered", "entering", "standards", "bundle", "becoming", "trust",
+    "limitation", "obvious", "push", "transitively", "multithreaded", "inconsistent", "preference",
+    "learning", "carriage", "customize", "today", "spawn", "manages", "aspect", "affecting", "vice",
+    "versa", "seems", "iterates", "structured", "cleaning", "blob", "corruption", "alone",
+    "workaround", "reliable", "modern", "secure", "quality", "contributors", "interacts",
+    "accurately", "dual", "switching", "breaks", "workflow", "spare", "feedback", "displaying",
+    "upgrading", "schedule", "skips", "planned", "totally", "contributing", "agree", "somehow",
+    "misuse", "motivation", "escaping", "verifying", "diverge", "structural", "normalization",
+    "externally", "arise", "filenames", "barrier", "expectation", "accidental", "reduces",
+    "highlights", "samples", "emulated", "lacks", "bash", "packaging", "deprecate", "attack",
+    "bitcode", "endpoint", "wishing", "traverse", "llvm", "decoding", "equivalently", "hung",
+    "evenly", "scatter", "cons", "comprehensive", "conservative", "computer", "rustaceans",
+    "exposing", "walk", "improving", "adapters", "english", "plan", "models", "interrupted",
+    "widely", "expresses", "angle", "cached", "relate", "shallow", "lots", "terminates",
+    "unrecoverable", "ergonomic", "emulate", "pi", "clients", "conversely", "room", "advances",
+    "gone", "styles", "activity", "referent", "dead", "respected", "stronger", "unsoundness",
+    "tagged", "aggressive", "callback", "identified", "concatenated", "adjust", "nominal",
+    "parenthesis", "projection", "interactions", "publicly", "concatenation", "wrappers",
+    "checksum", "recommendation", "rebuilds", "sums", "writers", "exponential", "nomicon", "mtime",
+    "fingerprint", "preliminary", "fmt", "pentium", "shall", "backref", "flagged", "sanitizers",
+    "unexpectedly", "instant", "splat", "distinction", "grouping", "talked", "exchange", "slicing",
+    "overload", "illustrate", "principles", "connect", "mix", "formal", "corner", "accomplish",
+    "producer", "play", "incoming", "orders", "hood", "promotion", "en", "disambiguate",
+    "integrated", "downloads", "garbage", "invalidates", "derivable", "placeholders", "numerical",
+    "nice", "classes", "framework", "saving", "topics", "relatively", "shipped", "specifics",
+    "inheriting", "rebuilt", "submodule", "increments", "non", "speaking", "mathematically",
+    "delayed", "denoted", "strongly", "promoted", "catching", "compressed", "useless", "mangled",
+    "intersection", "sides", "consts", "discouraged", "json", "cold", "descriptions", "instructs",
+    "encryption", "playground", "threshold", "eg", "preserves", "payloads", "stricter", "gathered",
+    "distributions", "enhanced", "continuous", "locale", "uppercased", "mitigations", "jemalloc",
+    "misspell", "precautions", "partitioning", "loopback", "listener", "lanewise", "affine", "puts",
+    "transferring", "amounts", "nature", "simplicity", "techniques", "heavily", "letter",
+    "glossary", "turbofish", "stops", "perfect", "anymore", "typed", "respond", "encourage",
+    "awaiting", "cores", "couple", "overwriting", "boilerplate", "chance", "studio", "invalidated",
+    "scoping", "developing", "consequently", "unrelated", "shut", "recover", "tie", "transforms",
+    "serial", "helpers", "presented", "bigger", "sequentially", "logging", "consequences", "pins",
+    "redirect", "officially", "vary", "staying", "incredibly", "hack", "specialization", "adjusted",
+    "inverted", "allocators", "conservatively", "proof", "composite", "soundly", "exploit",
+    "suppose", "imply", "sigil", "enclosed", "surrounded", "linted", "derives", "satisfying",
+    "collapse", "undesirable", "eval", "gdb", "candidate", "initializes", "iterative", "excluded",
+    "debuggers", "manifests", "whatsoever", "defaulted", "linkers", "timestamp", "incompatibility",
+    "frontmatter", "stale", "plugins", "queries", "login", "supplying", "unnecessarily",
+    "hypervisor", "wakeup", "lesser", "vacant", "decision", "conceptually", "incur", "grouped",
+    "goals", "fault", "understands", "catches", "conveniently", "perspective", "constrained",
+    "metavariable", "email", "dig", "unsafely", "opportunities", "nonexistent", "individually",
+    "delays", "restaurant", "supply", "importantly", "basics", "comparable", "separating",
+    "outcome", "knowing", "continuously", "increased", "th", "preparation", "txt", "discusses",
+    "occurrence", "prevented", "deciding", "retains", "exhaustiveness", "constrain", "pulled",
+    "indefinitely", "relationships", "gain", "picking", "equally", "consideration",
+    "responsibilities", "mutexes", "guarding", "measured", "snippet", "contravariant", "layouts",
+    "violates", "instruct", "rc", "views", "availability", "directive", "unsize", "reexports",
+    "subtrait", "natvis", "cipher", "addressed", "verification", "excessive", "reused", "carries",
+    "vendoring", "timestamps", "interop", "refactored", "mac", "illumos", "softfloat", "symmetry",
+    "codepoints", "planning", "funnel", "conserved", "draining", "wakeups", "amortized",
+    "resistant", "accumulating", "consisting", "bringing", "readability", "discovered", "observe",
+    "tracks", "sake", "truly", "consumer", "role", "organize", "spot", "attention", "advancing",
+    "discover", "annotating", "pushing", "improves", "editors", "ahead", "iterated", "develop",
+    "prepare", "continuing", "versioning", "restricts", "implications", "utility", "milliseconds",
+    "maintains", "sensitive", "visit", "mismatch", "trouble", "deleted", "reaching", "frameworks",
+    "surface", "swapping", "shape", "designated", "regard", "polled", "approved", "certainly",
+    "precedes", "unusual", "instantiate", "regressions", "adder", "stage", "scheduler", "fences",
+    "subsequently", "insufficient", "deleting", "growing", "embed", "restored", "delegates",
+    "subpatterns", "engine", "caret", "hashes", "inhabited", "detached", "visited", "observable",
+    "chars", "microcontrollers", "flash", "loss", "rows", "sealed", "deps", "uplift", "man",
+    "respecting", "bench", "deletes", "plans", "explanations", "substantially", "xtensa",
+    "deployment", "granularity", "lowercased", "lossily", "decode", "gained", "visits",
+    "semicolons", "attach", "designing", "absolutely", "vulnerabilities", "pervasive",
+    "eliminating", "mistakes", "story", "enforcing", "relax", "closes", "pause", "practices", "tab",
+    "uniform", "approximately", "focused", "versus", "notion", "pushed", "idioms", "ourselves",
+    "contribute", "actively", "assigns", "prompt", "adapt", "naturally", "hardcoded", "universal",
+    "freeing", "deep", "sidebar", "harder", "mitigate", "height", "redirecting", "coordinate",
+    "material", "reasonably", "growth", "duplicating", "opens", "irrelevant", "alert", "validated",
+    "visually", "typo", "capable", "reviewed", "cares", "miss", "terminating", "mutual", "rework",
+    "burden", "transcription", "transitivity", "balance", "denied", "inv", "fieldless", "callbacks",
+    "globals", "nullable", "deinitializing", "alignments", "drain", "shares", "relocations",
+    "lowered", "unify", "logs", "syntaxes", "exports", "stdlib", "nevertheless", "cut", "endlessly",
+    "stages", "configures", "fulfills", "near", "commits", "master", "remembers", "gated",
+    "collision", "superseded", "snapshot", "patches", "tarballs", "timings", "cygwin",
+    "alphabetical", "retried", "standardized", "backport", "unimplemented", "rpath", "portions",
+    "seeking", "lossy", "syscalls", "roadmap", "reconstructed", "unfused", "crossing", "russian",
+    "wakers", "wakes", "condvar", "infinitely", "illustrates", "encodes", "okay", "inherently",
+    "fallible", "problematic", "factors", "namely", "grapheme", "rejects", "mock", "told",
+    "switched", "validating", "transmitter", "ancestor", "blank", "shortcut", "resume", "entirety",
+    "sites", "associative", "occurrences", "downside", "treats", "exercise", "terminals",
+    "collects", "late", "letting", "downloading", "click", "rectangle", "video", "timer", "trusted",
+    "reflection", "consult", "upload", "pop", "guarded", "inequality", "markers", "enforces",
+    "saturate", "truncates", "obviously", "restrictive", "gamma", "traditional", "posts",
+    "formally", "literally", "comfortable", "history", "backported", "maintenance", "exclusion",
+    "stated", "considering", "database", "touched", "implying", "coerces", "buggy", "destroy",
+    "compression", "flavors", "flavor", "obtaining", "suitably", "optimizing", "mutually",
+    "propagates", "matchers", "cr", "disambiguation", "randomized", "id", "locality", "vars",
+    "norm", "microsoft", "fulfilled", "emission", "embedding", "launch", "composable", "utilize",
+    "clap", "metal", "images", "rustflags", "breakpoint", "party", "subjective", "perf", "infra",
+    "remap", "circular", "mappings", "patched", "downcast", "approving", "niche", "relocation",
+    "nvidia", "establish", "ints", "unwraps", "downcasts", "temporal", "atop", "resize", "trap",
+    "successor", "workgroup", "ancillary", "tiles", "theoretically", "forth", "fundamentally",
+    "thinking", "warned", "stuck", "overloadable", "disambiguating", "practical", "inefficient",
+    "evolve", "backslash", "lightweight", "theory", "retrieving", "talking", "asking", "chose",
+    "shortcuts", "nest", "stopped", "limiting", "ideas", "noting", "keyboard", "innermost", "throw",
+    "telling", "recoverable", "username", "unambiguous", "incrementally", "compose", "joining",
+    "safer", "identifies", "inform", "serving", "grows", "influence", "complementary", "informs",
+    "encapsulated", "slot", "unlock", "inspection", "cdylib", "preemption", "poison",
+    "synchronized", "outdated", "differentiate", "interoperate", "staticlib", "memcpy", "laid",
+    "fed", "addressing", "noreturn", "acting", "service", "formed", "ffi", "ty", "lexical", "eq",
+    "initializers", "es", "surrogate", "br", "ops", "viewed", "zulip", "continuation", "arity",
+    "divergence", "inert", "alphanumeric", "translates", "qualify", "hosted", "decryption",
+    "enhancements", "migrated", "resets", "firmware", "routine", "probes", "suffices", "dir",
+    "tarball", "specs", "notifications", "profiler", "friends", "bundled", "heuristics", "triples",
+    "checkout", "unavailable", "hermit", "darwin", "demoted", "accuracy", "instrument",
+    "unfinished", "unintentionally", "ships", "precompiled", "trusty", "compilations", "leaf",
+    "esoteric", "widens", "subtractions", "nonnegative", "partitions", "tangent", "formatters",
+    "discontiguous", "unfilled", "consisted", "imm", "overhauled", "tend", "decides", "advantages",
+    "upholding", "asked", "processed", "increases", "looping", "briefly", "entered", "hiding",
+    "responses", "requesting", "days", "arrive", "blue", "cleaner", "increment", "implementor",
+    "impose", "saves", "ubuntu", "editor", "decisions", "luckily", "enters", "reproducible",
+    "assembled", "thorough", "cleared", "losing", "deadlocks", "advice", "shadows", "integrate",
+    "challenges", "dirty", "supplies", "unwrap", "enumerations", "uploaded", "http", "fork",
+    "solved", "occasionally", "transformations", "protects", "streaming", "intentionally",
+    "interprets", "procedure", "hyphens", "workflows", "rebuilding", "coherent", "exhaustively",
+    "separation", "dangle", "liveness", "naive", "permanently", "killed", "gcc", "frames",
+    "decrement", "clobber", "ed", "preceded", "aforementioned", "ambiguities", "probe",
+    "highlighted", "ongoing", "intentional", "unsuffixed", "stdcall", "crt", "bulk", "startup",
+    "collide", "decomposes", "occurring", "compact", "equivalents", "population", "halfword",
+    "coercing", "accordingly", "encodings", "year", "subdirectory", "revert", "dummy", "codebase",
+    "breakpoints", "iterations", "fatal", "leverage", "excludes", "filesystems", "accommodate",
+    "merging", "vendored", "validate", "dep", "revisions", "malicious", "tweaked", "centralized",
+    "corrupting", "incompat", "positioned", "compliant", "customized", "recovered", "nintendo",
+    "communicated", "vtables", "lowering", "costly", "solver", "calculations", "quadratic",
+    "encompassing", "datagram", "peer", "anomalies", "seeks", "subfield", "halfwords", "stabilised",
+    "math", "namespaced", "ease", "led", "journey", "extensible", "recommendations", "took",
+    "concatenating", "percent", "violations", "penalty", "prefers", "fortunately", "duck",
+    "annoying", "servers", "connects", "garden", "polling", "tedious", "press", "demonstrated",
+    "scratch", "uninstall", "regularly", "indicator", "criteria", "interleaved", "guaranteeing",
+    "conveys", "prototyping", "ideal", "coding", "merely", "broader", "timers", "nanosecond",
+    "friendly", "delay", "analyze", "idiom", "thereby", "vulnerability", "covering", "licenses",
+    "clicking", "resizes", "day", "obscure", "approaches", "hit", "head", "oriented", "age",
+    "conform", "lose", "enumerated", "substantial", "facilities", "roll", "indication", "wishes",
+    "reasoning", "dropck", "overly", "ariant", "sufficiently", "regards", "spawns", "thrown",
+    "memmove", "vulnerable", "deletion", "synchronizes", "readonly", "rightmost", "doubleword",
+    "probing", "translated", "adt", "param", "areas", "reexport", "instantiations", "snippets",
+    "unescaped", "combinators", "usize", "epsilon", "inspected", "distinguished", "singleton",
+    "toggle", "silence", "recognizes", "dividend", "cpp", "seed", "months", "binders", "origin",
+    "typos", "imaginary", "metric", "bootstrapping", "leverages", "contributor", "rustfix",
+    "usability", "curl", "cyclic", "pipeline", "zlib", "epoch", "policies", "unifying",
+    "misspelled", "rfcs", "trace", "corollary", "qemu", "unikernel", "performant", "detector",
+    "bumped", "centered", "anticipated", "precondition", "unicast", "flushed", "advisory",
+    "untyped", "disguise", "contentious", "bitmask", "pidfd", "carryout", "ps", "absent",
+    "possibilities", "my", "opting", "capital", "clusters", "ranked", "beforehand", "stand",
+    "collected", "happily", "classic", "encapsulation", "communicating", "traditionally",
+    "translations", "brief", "connecting", "reminder", "postfix", "shirt", "red", "principle",
+    "eliminates", "specially", "exe", "popping", "immutability", "cleans", "recovering",
+    "recompile", "forcing", "cells", "calculating", "talks", "lies", "thereafter", "receivers",
+    "efforts", "served", "granular", "measure", "outermost", "sequential", "completing", "explored",
+    "belong", "worse", "guidance", "optimal", "tabs", "shutdown", "disconnect", "inconvenient",
+    "rate", "booleans", "collectively", "relates", "modifies", "graphs", "approve", "coupled",
+    "extent", "ordinary", "holes", "rights", "tracker", "phantom", "noisy", "kill", "clobbered",
+    "pedantic", "destruction", "outstanding", "erased", "elide", "stripped", "positional",
+    "patching", "subpattern", "pat", "moreover", "principal", "obligation", "nonterminals",
+    "typestate", "braced", "attributed", "diverges", "transfers", "quadword", "simd",
+    "generalizing", "unsuccessful", "corrupt", "migrations", "tips", "gates", "aims", "shortens",
+    "merges", "inclusion", "drivers", "stepping", "miscompilation", "fresh", "boot", "delaying",
+    "ports", "controller", "granted", "offload", "clashing", "zsh", "linting", "tracing", "ord",
+    "schemes", "libcurl", "unittests", "privileges", "clarified", "guides", "deduplicated",
+    "retrieves", "inactive", "catalyst", "unikraft", "percentage", "remapped", "upcasting",
+    "locals", "transmutations", "measurement", "counterparts", "interoperation", "pkgsrc", "chroot",
+    "spanning", "stdarch", "differentiation", "generically", "interpolated", "terminators",
+    "signedness", "residual", "sqrt", "parents", "recomputed", "exceeded", "packs", "prepends",
+    "cuda", "accelerator", "transaction", "uppper", "pd", "arrow", "confident", "upholds",
+    "dangerous", "asserting", "deeper", "surprise", "choices", "substituting", "constraining",
+    "solely", "realistic", "drive", "poem", "pressing", "tiny", "heading", "selectively",
+    "navigate", "resumed", "renames", "awaited", "incrementing", "resistance", "obey",
+    "identically", "adhere", "seeing", "subdirectories", "stopping", "installs", "foundation",
+    "inexpensive", "deeply", "handy", "coordination", "periods", "dimension", "proving", "happy",
+    "engines", "evolution", "movement", "nearly", "challenge", "sleeping", "arrives", "intervals",
+    "recognize", "manipulate", "crucial", "halt", "instantiating", "filtered", "transparently",
+    "dive", "disconnects", "face", "columns", "multiplying", "light", "adjusting", "polymorphism",
+    "python", "reside", "tightly", "landed", "believe", "hosting", "effective", "accomplished",
+    "uncommon", "witness", "entail", "agreement", "downgrade", "suited", "cheaper", "companion",
+    "synced", "disagree", "tricks", "constitute", "asymmetric", "expired", "survive", "elaborate",
+    "eligible", "occupy", "emphasize", "ping", "arcs", "sentinel", "bother", "pitfall",
+    "computations", "exprs", "tt", "transcriber", "unambiguously", "metavariables", "optimisations",
+    "lexed", "mixture", "mismatches", "undecided", "subsystem", "maximize", "hygienic", "driven",
+    "desugared", "presently", "preferring", "skipping", "technology", "transactional", "collapsed",
+    "presents", "unoptimized", "differing", "favors", "reserving", "integrity", "opted", "motor",
+    "traces", "essential", "bandwidth", "shrunk", "preprocessor", "january", "drift", "records",
+    "bins", "dont", "mtimes", "gc", "gating", "sanitized", "obsolete", "upcoming", "reimplemented",
+    "consolidated", "fetched", "pipelining", "feat", "appeared", "spacing", "badges", "metabuild",
+    "maintainer", "oldest", "bugfix", "cryptographic", "appearing", "recorded", "suffice",
+    "recompiling", "certificate", "unaltered", "preview", "sony", "demotion", "unconditional",
+    "susceptible", "punycode", "madsmtm", "packets", "mechanical", "implementable", "conceptual",
+    "logo", "superscalar", "situational", "inf", "reacting", "conformant", "graphemes", "charts",
+    "trimming", "infallibility", "octets", "circularly", "decrements", "awaken", "customizes",
+    "awoken", "cgroup", "recreate", "spatial", "zips", "chopping", "abs", "searcher", "accumulated",
+    "blends", "rustpkg", "conventional", "deallocation", "pseudocode", "tempted", "study",
+    "crashes", "interfacing", "computers", "interpreting", "news", "research", "recap", "hang",
+    "pay", "newtypes", "river", "runnable", "transmission", "vast", "listening", "icon",
+    "responding", "browsers", "newest", "buffering", "gracefully", "substitute", "responds",
+    "house", "roots", "fair", "title", "richer", "invisible", "win", "favorite", "vertical",
+    "inferring", "additive", "highlighting", "exercises", "imposes", "debate", "eliminated",
+    "troubleshooting", "experienced", "deallocating", "discussions", "conditionals", "axis",
+    "destructured", "suspend", "varying", "hands", "batch", "triggering", "simulate", "meanings",
+    "shuts", "persist", "sensible", "exporting", "difficulty", "logged", "sentence", "permanent",
+    "undo", "configurable", "wildcards", "fearless", "promises", "costs", "associate", "advisable",
+    "getter", "forgets", "fire", "unpublished", "scattered", "prepared", "denoting", "noticed",
+    "existed", "aim", "glance", "customizing", "forgetting", "surely", "ancestors", "shorten",
+    "chained", "opportunity", "spin", "teach", "strange", "translate", "loose", "witnessed", "dark",
+    "stuff", "curious", "contrary", "vectorization", "claims", "borrowck", "surprisingly",
+    "referents", "chips", "snappy", "registration", "registering", "misused", "inaccessible",
+    "autoref", "mem", "rcs", "multiplied", "counters", "deallocates", "interoperable", "unaffected",
+    "tokenization", "numbered", "unqualified", "aggregate", "tilde", "reborrowed", "zeroth",
+    "intervening", "books", "clicked", "erase", "substitutions", "font", "ast", "unintended",
+    "cdecl", "layers", "employed", "distributable", "codebases", "lasts", "consequent", "printable",
+    "expires", "replacements", "queried", "injected", "throwing", "tagging", "shim", "unfulfilled",
+    "encountering", "intact", "backticks", "inject", "reentrancy", "backslashes", "invert",
+    "optimisation", "packaged", "app", "frequency", "silicon", "os", "installer", "relocatable",
+    "highlight", "central", "alleviate", "gradually", "forwarded", "footnote", "hyperlinks",
+    "chore", "de", "globs", "triage", "lldb", "downgrading", "logout", "querying", "locate", "lld",
+    "rejecting", "laying", "sys", "parties", "authorization", "repos", "claimed", "md", "stem",
+    "membership", "judgment", "runners", "unittest", "flat", "makefile", "tiers", "vita", "hurd",
+    "supplementary", "onerous", "meeting", "widespread", "demangle", "smashing", "randomization",
+    "assistance", "stacks", "crichton", "pthreads", "remapping", "products", "subtracted",
+    "programmatically", "historic", "rolled", "seeds", "gelbpunkt", "alexcrichton", "tomatoware",
+    "onwards", "menu", "disappear", "identifying", "specialize", "manipulated", "believed",
+    "themes", "usecase", "hacking", "lintcheck", "busy", "flattening", "carryless",
+    "affectionately", "partly", "interconversion", "practically", "crypto", "randomness",
+    "polluting", "derivative", "stride", "flushing", "flushes", "splicing", "mul", "replicated",
+    "saturates", "employs", "encrypt", "decrypt", "percentile", "demote", "dollar", "superpowers",
+    "me", "degree", "asterisk", "asks", "separates", "summarize", "govern", "quota", "enhancement",
+    "focuses", "excellent", "discussing", "hides", "aggregates", "customers", "designate", "listen",
+    "simultaneous", "ctrl", "concentrate", "experimentation", "transformed", "tradeoffs",
+    "mentions", "wins", "customization", "company", "distribute", "klabnik", "nichols",
+    "contributions", "visualizations", "yellow", "homogeneous", "nicely", "variations", "fear",
+    "haskell", "isolation", "touch", "mess", "adapted", "solid", "player", "tutorial", "draw",
+    "walks", "incurs", "disallows", "summaries", "concisely", "descriptive", "unclear",
+    "introductory", "focusing", "asynchronously", "interleaving", "polls", "wondering", "attacker",
+    "refuse", "mentioning", "slashes", "checklist", "revoke", "yanking", "secrets", "complain",
+    "leveraging", "graceful", "prototype", "minutes", "hours", "influenced", "families",
+    "alphabetic", "desktop", "mobile", "procedures", "commented", "decreases", "metaprogramming",
+    "roles", "predictable", "pretend", "suggesting", "coins", "forgotten", "deadline", "installers",
+    "huge", "convey", "abbreviation", "microphone", "ten", "gave", "gains", "extraneous", "edges",
+    "decrease", "fancy", "diagnose", "cumbersome", "permissive", "maximal", "alright",
+    "unsynchronized", "nonsensical", "influences", "placement", "deemed", "cov", "hazard",
+    "unfortunate", "occupies", "tends", "desugars", "prose", "footprint", "stateful", "targeted",
+    "instructing", "rlibs", "facility", "unresolved", "predicted", "offsetting", "aligns", "obeys",
+    "nomem", "nostack", "sym", "lateout", "inout", "inlateout", "restoring", "scrutinized",
+    "nonempty", "ascription", "browse", "productions", "linefeed", "punct", "descendants",
+    "formerly", "functionalities", "varargs", "arch", "priorities", "oversight", "directed",
+    "unconstrained", "structurally", "narrowing", "recognized", "ordinal", "prologue", "unboxed",
+    "heterogeneous", "illustrated", "contributes", "phased", "callee", "decorated", "scanning",
+    "reliability", "permute", "camel", "pitfalls", "testcase", "sub", "writable", "manipulating",
+    "frozen", "incompatibilities", "aid", "tip", "facilitate", "integrates", "arranged", "hyphen",
+    "backups", "canonicalized", "mdbook", "precede", "meetings", "cleanups", "enhance", "uplifted",
+    "authenticated", "corrected", "dylibs", "contact", "licensing", "recompiled", "submit", "serde",
+    "grown", "sanitization", "claim", "sanitize", "workarounds", "brevity", "undocumented",
+    "clearing", "subtree", "mainline", "brain", "cpu", "cloud", "userspace", "hostname",
+    "personality", "ld", "tty", "remappings", "predict", "implementers", "copyable", "confusable",
+    "dlltool", "remarks", "predecessor", "walkthrough", "relating", "strategically", "nullary",
+    "sentences", "rough", "suspension", "spelled", "quantification", "strikethrough", "unreliable",
+    "triaging", "centred", "electronics", "dependant", "codomain", "ceil", "transposes",
+    "decompose", "exploited", "inequal", "corollaries", "relations", "analogue", "reallocates",
+    "peeked", "vein", "encapsulates", "waking", "fs", "prescribed", "culture", "forcibly", "inode",
+    "standardised", "protecting", "consoles", "toplevel", "waiters", "rendezvous", "deinterleave",
+    "flush", "swizzling", "logarithmic", "sendable", "sb", "bs", "val", "generalize", "futex",
+    "optimizes", "clocks", "cdylibs", "modernized", "reorganization", "libuv", "ruby", "explores",
+    "presentation", "billion", "listings", "trickier", "exploration", "latin", "sooner",
+    "substitutes", "guessed", "rubber", "abbreviations", "gotten", "summarizes", "pulling", "sheet",
+    "clarity", "infers", "installations", "interactive", "fastest", "strive", "hypothetical",
+    "outline", "delegate", "throws", "forgot", "password", "shoes", "minimizing", "solves",
+    "mechanics", "whew", "week", "figuring", "generators", "outcomes", "guesses", "quit", "tweak",
+    "interchangeable", "diagram", "constrains", "distinguishing", "suit", "prefixing", "freedom",
+    "rewriting", "spend", "love", "organizing", "multitasking", "foreword", "insensitive",
+    "integrating", "enumerating", "measures", "acquiring", "releasing", "thank", "tour", "behalf",
+    "resulted", "notifying", "handwritten", "consensus", "reusable", "reviews", "bearing",
+    "refutability", "strengths", "transitions", "maintainability", "familiarity", "continued",
+    "overlaps", "longest", "trades", "coin", "stagnation", "gitignore", "periodically", "resides",
+    "wonder", "route", "competing", "watch", "disregard", "eventual", "multithreading", "hole",
+    "lucky", "intention", "traversal", "bail", "harmless", "causality", "weaker", "establishes",
+    "interoperating", "reinterprets", "trusting", "accounts", "intrusive", "hopefully", "rank",
+    "universe", "informally", "exotic", "improperly", "discretion", "tweaking", "callable",
+    "archived", "ptr", "outlines", "stance", "seemingly", "noop", "constitutes", "jumps", "black",
+    "subexpressions", "definite", "resumes", "stmt", "ruled", "exhibit", "facets", "screens",
+    "settled", "rationale", "ascii", "analyzed", "seamlessly", "aapcs", "fastcall", "riscv",
+    "inductive", "kit", "alternation", "soft", "modeled", "epilogue", "polymorphic", "uniqueness",
+    "wild", "card", "inspects", "akin", "varieties", "cacheable", "awareness", "suites",
+    "retaining", "eager", "chainable", "heavy", "simplifies", "drawback", "versatile", "committed",
+    "mental", "honored", "risky", "unwanted", "switches", "became", "everybody", "routines",
+    "porting", "expense", "populated", "bus", "instruments", "occasions", "barriers",
+    "instantiation", "automate", "translating", "binutils", "raspberry", "picture", "decreased",
+    "dots", "parsers", "alt", "mounts", "mdman", "styling", "metrics", "cumulative", "redirects",
+    "renovate", "docker", "documentations", "checkouts", "gitoxide", "hot", "dash", "configs",
+    "recompiles", "wrongly", "scrape", "scraped", "fingerprints", "benches", "fossil",
+    "publication", "uploading", "locating", "spent", "isolated", "conforms", "grace", "calendar",
+    "altogether", "versioned", "participate", "denying", "sane", "pubtime", "misleading",
+    "untested", "downcasting", "maximally", "offending", "lto", "hover", "offered", "hierarchical",
+    "diffs", "grant", "conventionally", "loosen", "commitment", "subprocesses", "reinstall",
+    "pijul", "serializing", "haiku", "powerpc", "fortran", "proposing", "timely", "sandbox",
+    "exempt", "qualifying", "sole", "injecting", "demangler", "enforcement", "colorize", "endings",
+    "demanglers", "denies", "stylistic", "heuristic", "fires", "adopted", "spelling", "noops",
+    "proactively", "gotchas", "hooks", "fitting", "triplets", "starter", "wasmtime", "thomcc",
+    "pthread", "confirmed", "prebuilt", "zxdb", "ratified", "gaisler", "compliance", "dispatched",
+    "parens", "traversed", "dialog", "coinductive", "artificial", "savings", "heart", "reflected",
+    "lifespan", "navigation", "duties", "remotes", "cube", "plane", "arctangent", "unpaired",
+    "derivatives", "consulted", "interpolation", "exceeding", "backed", "evict", "faults",
+    "aborted", "quotas", "shrinking", "cancel", "unwrapping", "displacement", "shareable",
+    "reintroduce", "mir", "unmasked", "ring", "abandoned", "supersedes", "disconnection", "senders",
+    "cloneable", "anchor", "instants", "masking", "accelerates", "indetermination", "bag",
+    "rountrips", "dist", "snake", "interchangeably", "lisp", "enumerate", "embeds", "isolate",
+    "enclose", "fulfilling", "nameable", "confidence", "objective", "intuitive", "interestingly",
+    "aside", "synonyms", "synonym", "arriving", "chat", "producers", "pauses", "nondeterministic",
+    "managers", "siblings", "fruit", "stock", "mentally", "onward", "awesome", "vegetables",
+    "executors", "mailing", "contrived", "score", "disregarding", "alphabetically",
+    "expressiveness", "terminology", "noticeable", "clutter", "branching", "mismatched", "twelve",
+    "www", "repetitive", "pile", "acquisition", "fun", "seeded", "refine", "crashing",
+    "congratulations", "timeline", "reproduce", "whereby", "prematurely", "acknowledge",
+    "extensively", "ambiguously", "getters", "toolbox", "prone", "powers", "services", "timeouts",
+    "millisecond", "retries", "crafted", "robustly", "showed", "edited", "sensitivity", "finer",
+    "recommends", "speak", "parameterize", "walking", "bonus", "modeling", "paste", "throughput",
+    "universally", "mindful", "nobody", "flaws", "statistic", "informative", "erlang", "shutting",
+    "programmed", "yours", "conclude", "understood", "examined", "frustrating", "subsets",
+    "chinese", "colloquially", "demand", "variation", "visualize", "demonstration", "realized",
+    "unwrapped", "demands", "py", "hope", "annotates", "predefined", "quarters", "train", "night",
+    "continually", "dictates", "unlocking", "modularity", "consolidate", "designs", "tweaks",
+    "sparingly", "bypass", "messing", "java", "observes", "coincide", "bridge", "convince", "awful",
+    "boils", "intuitively", "executions", "considerable", "refresher", "punning", "angry", "defend",
+    "iter", "excessively", "mutations", "elimination", "meanwhile", "audit", "willing", "naively",
+    "descendant", "coarse", "extreme", "spinning", "reflects", "participates", "monomorphizations",
+    "wastes", "regarded", "validates", "correspondingly", "argue", "decreasing", "defer",
+    "inbounds", "intensive", "jumping", "quad", "unmodified", "deduplicate", "architectural",
+    "altered", "omitting", "associativity", "precedent", "uncaught", "lookahead", "forwarding",
+    "kleene", "slash", "exhibits", "paragraph", "italics", "flux", "hesitate", "num", "delim",
+    "thiscall", "fulfillment", "isize", "satisfaction", "ineffective", "prohibited", "forbidding",
+    "clash", "stringified", "greek", "permissible", "linkable", "backtrack", "interpreter",
+    "lookups", "suffer", "shortened", "permitting", "rebinding", "unnameable", "incoherent",
+    "appearance", "mirrors", "usefulness", "neural", "identification", "privileged", "scalable",
+    "reservation", "forbids", "broad", "evolving", "drastically", "signs", "unpacking", "boxing",
+    "templates", "manufacturer", "zone", "adjustments", "expressive", "awkward", "transitioning",
+    "coordinated", "unifies", "preferably", "surprises", "restructured", "prerequisites", "licence",
+    "physically", "debuggability", "confirm", "hitting", "synchronisation", "activates", "bindgen",
+    "prerequisite", "distros", "center", "upfront", "legible", "summing", "bloat", "fuzzy",
+    "dependents", "sessions", "pkg", "mergeable", "helping", "lockfiles", "deserialization",
+    "unpacked", "persists", "certificates", "canonicalize", "gzip", "undeclared", "normalizing",
+    "snapbox", "charter", "verifies", "rerun", "libs", "stabilizing", "unencrypted", "mips",
+    "interning", "archives", "unintentional", "uncommitted", "reverted", "abnormal", "serially",
+    "differed", "overlapped", "transitioned", "spreading", "inadvertently", "stat", "sr", "ht",
+    "multiplexing", "usernames", "searchable", "mirroring", "backporting", "scan", "subprocess",
+    "trimmed", "desire", "submitted", "invite", "doubt", "beneficial", "grain", "erroring",
+    "verbosity", "pipelined", "mistakenly", "disruptive", "hazards", "guided", "bourne",
+    "authenticating", "authority", "severity", "mitigating", "deem", "generalization", "backports",
+    "cautious", "stripping", "speeds", "frontend", "flattened", "sierra", "fortanix", "managarm",
+    "motorola", "proprietary", "agreements", "statistics", "figs", "mozilla", "hacks", "hardening",
+    "anderson", "malloc", "stalled", "blobs", "disambiguators", "decoded", "loader", "probable",
+    "unlabeled", "na", "vely", "originate", "revealed", "refinements", "publically", "unrecognized",
+    "treatment", "ar", "impacts", "stub", "stabilizes", "interlinking", "opensource", "apps",
+    "buildroot", "mmc", "localhost", "competitive", "differentiated", "wasi", "multi", "memories",
+    "attaching", "ad", "albeit", "miscompilations", "ordinarily", "resumption", "resuming",
+    "idempotent", "opts", "enzyme", "profilers", "movable", "redefined", "buttons", "landing",
+    "fenced", "noteworthy", "opinionated", "nursery", "deals", "onboarding", "mentor", "forge",
+    "traffic", "recently", "submatches", "unread", "nearby", "insignificant", "quieting",
+    "hypotenuse", "triangle", "arcsine", "arccosine", "signalingness", "recombines", "recombined",
+    "constness", "amenable", "reconstitute", "memset", "emoji", "overloads", "stitch", "duality",
+    "fallibility", "residuals", "lifts", "circuiting", "subtraits", "transmitted", "feeds",
+    "referee", "sourced", "prefetches", "optimistic", "smarter", "coalesced", "awake", "zombie",
+    "soul", "notified", "estimation", "bugzilla", "unquoted", "activities", "scalars", "unsets",
+    "pinboard", "witnessing", "uniformly", "safeguard", "workload", "resort", "justifies",
+    "decomposed", "timespan", "provenances", "gibberish", "answering", "inconsequential",
+    "repeater", "preallocate", "peak", "zipped", "concretely", "incomparable", "reentrantly",
+    "possess", "cheaply", "dataflow", "lowers", "derefs", "ors", "jumped", "pid", "api", "junction",
+    "notwithstanding", "cstr", "nuls", "reconstructing", "retakes", "recalculated", "provisions",
+    "retake", "reconstituted", "mitigates", "unpoisoned", "timed", "infrequent", "oneshot", "bools",
+    "neg", "adjoin", "microseconds", "monotonicity", "roundinging", "wavefronts",
+    "parameterisations", "qwords", "vertically", "averages", "zmm", "deviation", "operational",
+    "certs", "pasted", "undeprecate", "immediates", "weaken", "stringify", "compiletest",
+    "workloads", "publishes", "widened", "parametricity", "corrupts", "redesigned", "multidispatch",
+    "begun", "hexfloat", "fourcc", "facade", "segmented", "reorganized", "casual", "deliberate",
+    "distinctions", "synonymous", "contradicts", "devoted", "pose", "similarity", "expressing",
+    "his", "pain", "nulls", "confidently", "investigate", "harm", "threaded", "invisibly",
+    "promising", "credit", "unwieldy", "complexities", "substitution", "pretending", "analyzing",
+    "catastrophic", "doubles", "reproduced", "abstracts", "assured", "emily", "dickinson", "mini",
+    "increasingly", "nowhere", "colons", "metaphor", "office", "favicon", "restart", "phrase",
+    "simulating", "cheat", "remind", "pulls", "scenes", "handed", "rectangles", "carol", "ebook",
+    "starch", "plays", "hashers", "equipped", "department", "designer", "unrolling", "inspired",
+    "baby", "originates", "belonging", "prompted", "silly", "glossed", "incremented", "reminds",
+    "gathers", "plates", "abbreviated", "popped", "waste", "contradict", "rapidly", "helpfully",
+    "figured", "suboptimal", "altering", "signifies", "rustacean", "schedules", "alternating",
+    "evident", "labeling", "productive", "hundreds", "biggest", "decades", "productivity",
+    "broadly", "philosophy", "digs", "exploring", "ferris", "needless", "hits", "structuring",
+    "cooperative", "secondarily", "lays", "inevitable", "wonderful", "valuable", "collaboration",
+    "thousands", "fairness", "linearly", "examining", "analyzer", "autocompletion", "coordinates",
+    "accomplishes", "insight", "annoyed", "art", "uploads", "idle", "havoc", "unlimited",
+    "overwhelm", "cleanly", "functioning", "diff", "recurse", "grasp", "unidiomatic", "folks",
+    "subpath", "insecure", "validations", "went", "reassign", "paper", "unsure", "japanese",
+    "month", "unpin", "challenging", "arguably", "gaining", "subclasses", "uncomment",
+    "encapsulate", "transforming", "featured", "missed", "stick", "templating", "wow", "unfamiliar",
+    "apostrophe", "warranted", "guiding", "lands", "career", "neatly", "proven", "upward",
+    "panelist", "teaching", "circumstance", "friendlier", "programmatic", "watching", "knew",
+    "demonstrating", "inconsistencies", "synchronously", "confirms", "slide", "exhaust", "hat",
+    "suddenly", "abstractly", "abstracted", "blows", "bullet", "poisons", "blindly", "chaos",
+    "hierarchies", "establishing", "aggressively", "spinlock", "weakest", "arts", "normative", "un",
+    "touches", "reinitialize", "uninit", "superfluous", "wherein", "thereof", "contra", "barring",
+    "subtypes", "println", "variances", "lived", "uncontroversial", "spending", "subtleties",
+    "reinterpreting", "suppresses", "falling", "imposed", "reality", "incorporate", "advertise",
+    "lay", "directions", "remark", "announce", "horribly", "foremost", "afoul", "magically",
+    "constituent", "untagged", "net", "deduces", "massive", "freeze", "mold", "composition",
+    "surprised", "theoretical", "amplification", "hatch", "deploy", "frees", "motivating", "limbo",
+    "quirks", "clever", "rise", "augmented", "sticky", "thunks", "concerning", "architecturally",
+    "dwarf", "bitflags", "appendices", "converse", "expander", "transcribed", "transcribers", "shl",
+    "shr", "disambiguated", "undef", "gaps", "synthetic", "informal", "discharge", "illustrative",
+    "carried", "transient", "undergo", "feeding", "observing", "eye", "lexically", "pounds",
+    "combinator", "tying", "omission", "activation", "loongarch", "msvc", "convergence",
+    "speculation", "syntactical", "segregated", "hangs", "slated", "formalism", "determination",
+    "justification", "unlinked", "typeclasses", "annex", "qualification", "bang", "classified",
+    "incorporating", "resizable", "infix", "confuse", "motivations", "defaulting", "exposition",
+    "speculative", "visitation", "parameterized", "bracketed", "contextual", "compaction",
+    "virtualization", "topmost", "callsite", "caps", "printers", "velocity", "designators", "drink",
+    "lemonade", "flatmap", "succinctly", "arrange", "keyed", "scientific", "governed",
+    "unknowingly", "parallelize", "fly", "freezing", "intrepid", "smooth", "reformatting",
+    "simplifying", "deferred", "utilizes", "spontaneously", "forming", "unprefixed", "ago",
+    "silenced", "undue", "forum", "categorized", "submitting", "postgres", "bookshelf", "generous",
+    "commons", "pleasant", "thresholds", "serviced", "grab", "disassemble", "laptop", "peek",
+    "overwhelming", "accessor", "sibling", "initialised", "preemptive", "datatypes", "traversing",
+    "wire", "multitude", "cortex", "udev", "capacities", "ratio", "predictability", "qualifies",
+    "reexported", "zipping", "nonstandard", "accident", "backfill", "rmeta", "plumbing", "flaky",
+    "arg", "libsecret", "singular", "overlay", "resilient", "triagebot", "aligning",
+    "reimplementing", "networks", "trim", "homepage", "pkgid", "redirection", "margin", "shortest",
+    "adopting", "inheritable", "umask", "misspellings", "xtask", "refined", "rotated", "revoked",
+    "reuses", "bumping", "corrupted", "argfile", "paves", "terse", "exclusions", "tar",
+    "unreadable", "incorporated", "staged", "downloadable", "behaving", "loadable", "sccache",
+    "serialization", "viewing", "regex", "endpoints", "bakes", "organizations", "angled", "comply",
+    "posting", "worktree", "upgrades", "quoting", "outright", "impression", "approximates",
+    "misbehavior", "acyclic", "nvptx", "nalgebra", "unblocked", "succinct", "personal",
+    "revocation", "representative", "looser", "generalized", "employ", "benchmarked", "criterion",
+    "commercial", "cranelift", "balanced", "repeatable", "sur", "horizon", "xous", "reviewing",
+    "propose", "stubbed", "knowingly", "demoting", "viability", "jointly", "raising", "wraparound",
+    "protector", "micay", "matsakis", "usefully", "demangled", "shims", "bruijn", "cap", "builtins",
+    "unexported", "behavioral", "secondary", "defense", "preexisting", "inhibits", "specializes",
+    "uweigand", "portal", "workbench", "contacts", "eld", "presumably", "baremetal", "arttet",
+    "famfo", "neuschaefer", "forked", "emulators", "chrisnc", "affiliated", "oracle", "revised",
+    "whilst", "viable", "mvolfik", "mipsel", "ic", "par", "wine", "launching", "betrusted",
+    "evolves", "op", "hoc", "energy", "comprehend", "sigils", "supersede", "instrumenting",
+    "suspended", "appreciated", "nop", "dramatically", "discourage", "fake", "noticeably", "hijack",
+    "restores", "stateless", "amendment", "circumvent", "reclaim", "prohibit", "omits",
+    "uninferred", "clashes", "renderer", "picker", "tildes", "footnotes", "friction", "ex",
+    "applicability", "rid", "tooltips", "utils", "uninstalled", "dates", "essence", "syncing",
+    "revisited", "dependence", "submatch", "hir", "syn", "inconsistency", "visitors", "travis",
+    "radices", "bias", "graphic", "blanks", "casually", "forged", "scientist", "lexicographic",
+    "routable", "packet", "hashable", "hashed", "prefetched", "predictor", "pessimistic",
+    "promotes", "park", "overcount", "affinity", "recombine", "cancelled", "linebreaks",
+    "parseability", "internationalization", "justifiably", "unpinned", "manners", "notify",
+    "efficiency", "accessors", "bypasses", "touching", "roundtrip", "warrants", "nominally",
+    "hybrid", "writeable", "exceptionally", "incurring", "pathname", "inappropriate", "fds",
+    "rendezvoused", "tainted", "downgrades", "swizzle", "leap", "monotonically", "measuring",
+    "inadvisable", "er", "unwinded", "foolishness", "pushes", "resizing", "lexicographical",
+    "rearranges", "accumulation", "workitem", "rd", "granule", "multiprocessor", "underflow",
+    "polarity", "polynomials", "resut", "specifieid", "unbalanced", "quartile", "border",
+    "overhaul", "cc", "estimates", "err", "levenshtein", "reverts", "announced", "unintuitive",
+    "hoedown", "timsort", "defects", "nold", "rt", "shortly", "reveals", "rectify", "anytime",
+    "distraction", "tackle", "wanting", "intense", "facilitates", "mangles", "protections", "coded",
+    "clues", "hindi", "devanagari", "sixth", "apparent", "famous", "neat", "trading",
+    "reproduction", "laziness", "afraid", "fearlessly", "cool", "lengthy", "manageable", "thunk",
+    "golden", "slogan", "water", "destructures", "pausing", "portugu", "espa", "ol", "navigating",
+    "organizational", "rearranging", "chef", "customer", "reorganize", "meal", "uncommenting",
+    "listens", "retrying", "encompasses", "payment", "kitchen", "rooted", "scraper", "delivered",
+    "phew", "kick", "legitimately", "baked", "steve", "chris", "krycho", "paperback", "featuring",
+    "quizzes", "cs", "brown", "edu", "engineering", "texts", "foundations", "dogs", "puppies",
+    "asterisks", "thinks", "exercising", "pinpoint", "definitively", "quitting", "ceases",
+    "conciseness", "countdown", "christmas", "reimplement", "cutting", "seated", "contemporary",
+    "farther", "namespacing", "collector", "profound", "sounds", "fundamentals", "cd", "stating",
+    "thoroughly", "wise", "shines", "beneath", "reevaluate", "chances", "nongeneric", "brittle",
+    "oh", "deploying", "filed", "media", "aggregator", "downsides", "conveyed", "business",
+    "dimensions", "odds", "refusing", "painless", "students", "welcoming", "companies", "firefox",
+    "hopes", "hear", "elapses", "enthusiasts", "adoption", "explosion", "vibrant", "offering",
+    "definitive", "skills", "diverse", "designers", "tackled", "dynamics", "casing", "reformats",
+    "illustration", "investigation", "distributes", "rearrange", "simulated", "million", "pooling",
+    "significance", "experimenting", "totals", "widths", "nicknamed", "elegant", "skill",
+    "congrats", "enhancing", "distinguishes", "contradictory", "dire", "encourages", "conveying",
+    "spoiler", "mastering", "quotation", "intuition", "strength", "stealing", "utilized", "rates",
+    "dotted", "readiness", "foundational", "encapsulating", "pro", "richard", "gang", "subclass",
+    "reorganizing", "rightfully", "complains", "governing", "issuing", "onscreen", "orange",
+    "interacted", "helloworld", "pdb", "manipulates", "humans", "experiments", "funny", "quarter",
+    "choo", "trains", "december", "pressure", "intricate", "serious", "panel", "wordy", "overkill",
+    "worthwhile", "associates", "slight", "mistaken", "colleague", "friend", "dice", "tandem",
+    "diving", "dubious", "witnesses", "bomb", "uselessly", "phases", "untrusted", "toss", "sadly",
+    "terrible", "ascribe", "proceeding", "minimally", "nonsense", "hopeless", "critically",
+    "revolves", "guts", "ground", "rustnomicon", "unacceptable", "justified", "subfields",
+    "toggled", "branched", "sophisticated", "vanilla", "moral", "nope", "generously", "classical",
+    "contravariance", "concludes", "invariance", "remembered", "covariance", "fiddle", "toes",
+    "dang", "earth", "reinitialized", "dip", "cbindgen", "bridging", "overwhelmingly", "instantly",
+    "untenable", "die", "burn", "persistent", "pollute", "decompression", "stdc", "uncompressed",
+    "reaction", "footgun", "proceeds", "dest", "blow", "wildly", "sanity", "pad", "indirections",
+    "air", "unbound", "endeavor", "tons", "sit", "hardly", "backshift", "subranges", "refcount",
+    "degenerate", "joins", "subtlety", "obeying", "untracked", "inappropriately", "ascribed",
+    "analyses", "decrementing", "ped", "loosely", "streamline", "boost", "indistinguishable",
+    "intimately", "reliance", "unneeded", "subregister", "comprise", "typographical", "manuals",
+    "deinitialization", "et", "undelimited", "traverses", "transcribes", "nonterminal", "lt",
+    "pound", "commitments", "adverse", "achieves", "subexpr", "recurses", "iteratively",
+    "discharged", "arising", "assure", "crlf", "fallthrough", "relaxation", "relocated",
+    "omissions", "answers", "magnifying", "glass", "facet", "hovering", "tapping", "unpredictable",
+    "exp", "abide", "fish", "bodyless", "mono", "efiapi", "decay", "div", "defs", "endless",
+    "glued", "predetermined", "composes", "reconcile", "outwards", "suppressing", "backtracks",
+    "al", "unmangled", "alters", "reversible", "minimized", "lending", "signify", "halfway",
+    "lambda", "dispatching", "parenthetical", "autoderef", "breakable", "suspending", "startend",
+    "unexpanded", "till", "charge", "dispatchable", "intermixed", "redirections", "associable",
+    "redefine", "unconstraining", "associating", "classifies", "persistence", "zaamo", "zalrsc",
+    "zicsr", "emissions", "unsatisfied", "visualizer", "visualized", "preprocessing", "sacrifices",
+    "dictate", "subsections", "induce", "rescue", "sampling", "editable", "clarification",
+    "borrower", "proactive", "forums", "counterintuitive", "living", "inadvertent", "impasse",
+    "prioritizes", "oddity", "instantaneous", "illustrations", "proofs", "narrowly", "lift", "gen",
+    "tokenize", "depended", "announcement", "cookbook", "caters", "debugged", "ada",
+    "embedonomicon", "nitty", "gritty", "volunteers", "revisiting", "vendors", "purchasing",
+    "pictures", "diagrams", "creative", "firmwares", "layering", "sensors", "magnetometer",
+    "accelerometer", "boil", "arcane", "unconfigured", "walked", "tradeoff", "feels", "afford",
+    "circuit", "multicore", "fired", "disassembly", "flashing", "re", "halted", "texas", "baud",
+    "boards", "agnostic", "compass", "ticks", "preempt", "microprocessor", "atomicity",
+    "singletons", "graphics", "hundred", "nordic", "datasheet", "pdf", "reload", "prominent",
+    "setups", "watchpoints", "seamless", "favored", "alike", "ranging", "classifications",
+    "homebrew", "tick", "mood", "fedora", "privilege", "optimising", "evaluable", "interoperates",
+    "npm", "rubygems", "paying", "prioritize", "understandable", "determinism", "symlinked",
+    "silent", "association", "schemas", "centralize", "unblocks", "flock", "snapshots", "originals",
+    "cow", "alpine", "gix", "informational", "unmatched", "benchsuite", "prerelease", "macos",
+    "deserializing", "strips", "compress", "broke", "considerably", "lean", "scraping", "dogfood",
+    "harnesses", "authenticate", "monitor", "rearranged", "temp", "relabel", "overlooked",
+    "relaxes", "cyclical", "refused", "fingerprinting", "complaining", "wording", "msys", "honor",
+    "subdivided", "farm", "securely", "keychain", "prepublishing", "greedy", "accompanied",
+    "sticking", "fallbacks", "unyank", "linters", "surfaced", "subscribe", "sbom", "crater",
+    "precursor", "keypair", "reconstruct", "infostring", "prepended", "occasional",
+    "misapplication", "noise", "directs", "invalidation", "wary", "hostnames", "invited",
+    "exaggeration", "negatively", "tightening", "loosening", "deprecations", "difficulties",
+    "spirit", "prioritizing", "serialized", "routed", "risks", "redownloading", "exceptional",
+    "unusable", "disruption", "probability", "visualization", "mm", "assemble", "benchname", "sha",
+    "vcs", "hg", "penryn", "flang", "cl", "obligated", "disruptions", "estoppel", "commenting",
+    "reviewer", "employment", "revocable", "derail", "backlinks", "experts", "slowest",
+    "importance", "copyleft", "substantively", "signing", "fee", "discrepancies", "quoted",
+    "engineers", "effectiveness", "exploitation", "grsecurity", "regressed", "probabilistic",
+    "destinations", "cfi", "hosfelt", "cwe", "mitre", "relro", "dlmalloc", "doug", "lea",
+    "outputted", "aggregated", "stabilisation", "undisambiguated", "substitutable", "capped",
+    "capping", "optimiser", "suspends", "prolong", "numbering", "meaningfully", "katakana",
+    "apparently", "eliding", "abc", "instructed", "realtime", "mkroening", "eighth", "heiher",
+    "cuviper", "sun", "openbsd", "interworking", "march", "loganek", "daltenty", "mk", "routers",
+    "utilizing", "co", "earliest", "cdimage", "receipt", "jonathanpallant", "sourcing", "inodes",
+    "robotics", "recognizable", "helium", "boy", "empower", "launched", "amdgpu", "generations",
+    "psumbera", "unofficial", "rosetta", "intends", "lynx", "sh", "audience", "badboy", "inception",
+    "devsite", "repackage", "qualcomm", "dpaoliello", "amanieu", "sandboxing", "upstreamed",
+    "artefacts", "vpk", "adopts", "resembles", "emulating", "xen", "liberally", "void",
+    "systematic", "imperative", "rightward", "accessibility", "developments", "gateway", "advised",
+    "allowable", "implementer", "delegating", "resumable", "pointless", "coinduction",
+    "interposable", "sleds", "bitmap", "geometric", "autodiff", "argfiles", "nops", "fuzzing",
+    "aggregating", "parity", "flamegraph", "dump", "discrepancy", "quantified", "reassigned",
+    "unmarked", "overruled", "spell", "typedefs", "tractable", "quantifying", "badly", "utput",
+    "intra", "toggles", "clipboard", "anchors", "arrows", "exposure", "testable", "gear", "ayu",
+    "tolerate", "suspicious", "groupings", "multiline", "elevated", "negatives", "sprinkle",
+    "capitalization", "prepending", "classification", "interpreters", "lexing", "dramatic",
+    "foresee", "echos", "alumni", "vote", "grammatically", "dumped", "classifying", "classify",
+    "alternations", "backreferences", "neighbors", "unwritten", "spanish", "openable", "infectious",
+    "deviated", "lastly", "hop", "surrogates", "multilingual", "tailoring", "conformance",
+    "decodes", "zfh", "bottleneck", "unbuffered", "measurable", "prepares", "canonicalizes",
+    "proxied", "unhygienically", "stretches", "monkeys", "abilities", "abiding", "proves",
+    "stopgap", "interleaves", "nullness", "microarchitectures", "informing", "irreflexivity",
+    "denormal", "numerics", "misplaced", "drained", "committing", "modifiable", "quintessential",
+    "fold", "distinguishable", "recreatable", "rational", "standpoint", "traitless", "unsurprising",
+    "resemblance", "drill", "traversals", "bitstring", "octet", "errata", "corrects", "prescribe",
+    "ln", "disassociated", "disassociating", "unprivileged", "encloses", "prototypical",
+    "populates", "invariably", "inversion", "invented", "reaped", "orphaning", "wiggle", "suffers",
+    "reflexibility", "detach", "parked", "timeslice", "threadsafe", "undercount", "mountpoints",
+    "amongst", "austin", "mingw", "hardlinks", "unquoting", "unregisters", "padded", "subsumes",
+    "niches", "metaphorical", "securing", "stress", "cancels", "practicalities", "truth",
+    "moveable", "dubbed", "synchronizing", "intermediately", "whirlwind", "bumps", "onus", "dis",
+    "transmutability", "transmutable", "proved", "inhibit", "linger", "conjure", "conjuring",
+    "deconstruct", "axioms", "addressable", "rationalize", "mildly", "subslicing", "lineage",
+    "traced", "descends", "nonnull", "successively", "successors", "adapts", "fallibly", "folds",
+    "determinable", "showcased", "distortions", "gradual", "underlies", "bake", "volatiles",
+    "initiate", "workgroups", "tupled", "discriminating", "recycling", "reap", "obtains",
+    "impersonation", "sentry", "granularly", "whence", "underway", "dissociated", "dissociating",
+    "acquisitions", "queued", "indicative", "instigating", "dies", "parallelized", "handful",
+    "compromised", "sympathy", "imposing", "infeasible", "finalizers", "unparsed", "niform",
+    "aming", "onvention", "trims", "adjoined", "particulars", "windowed", "loaned", "accounting",
+    "measurements", "coalesce", "reborrows", "cousin", "unrestricted", "prime", "immovable",
+    "conduit", "tight", "clogging", "offloaded", "abnormally", "outperform", "pops", "undetected",
+    "monomorphizes", "trapping", "eo", "splats", "rotating", "roundings", "intger", "accelerating",
+    "indicies", "signaled", "doublewords", "preparing", "denormalized", "xabort", "permutes",
+    "picturing", "crosses", "permutevar", "xsave", "prefetching", "thirty", "inverts", "asseration",
+    "dword", "accumulations", "denormals", "pallette", "assoc", "outliers", "estimator",
+    "quartiles", "calc", "ccc", "fff", "rustlings", "teaches", "destabilized", "safeties",
+    "strengthen", "scrutinees", "shebangs", "shortening", "promised", "linewrap", "intermixture",
+    "openssl", "negations", "discriminator", "affirm", "hamburger", "dumps", "killing",
+    "validators", "variadics", "spectre", "fallibles", "sigma", "refining", "gep", "regenerate",
+    "rwlock", "reverting", "reveal", "unsafeck", "bevy", "headings", "injection", "weirdness",
+    "exponents", "benign", "broaden", "misalignment", "codify", "backoff", "tsan", "soften",
+    "unboxing", "adjustment", "movbe", "splice", "lev", "deducing", "unreserve", "twisted",
+    "zeroext", "profraw", "reenable", "sunset", "strengthens", "compiletimes", "lub", "visibly",
+    "relay", "parker", "woff", "subdirs", "tidy", "abbreviating", "dominators", "dlopen",
+    "hashmaps", "backsliding", "contention", "sink", "adjusts", "surfaces", "unhygenic", "fns",
+    "responsibly", "disclosing", "memchr", "inaccurate", "uninhabitable", "reparsed", "numerals",
+    "ban", "trie", "bootstrapped", "republished", "commandline", "multirust", "exponentially",
+    "modest", "augment", "unaware", "overlong", "bitshifts", "rumprun", "loans", "avoidable",
+    "trips", "binops", "dives", "timekeeping", "undetermined", "suspected", "speedups", "pads",
+    "speedup", "fanfare", "unnamable", "obviating", "polish", "tuned", "headway", "destroying",
+    "gorgeous", "unsightly", "alpha", "attractive", "footguns", "flawed", "pared", "rudimentary",
+    "incurred", "crucially", "redesign", "clarifications", "uuid", "aesthetic", "bitv", "servo",
+    "modulus", "speedier", "comm", "graphviz", "pod", "libdebug", "plural", "registrar", "powered",
+    "sundown", "hyperlinked", "nuggets", "genericity", "consolidating", "indications", "booted",
+    "unmapped", "serialize", "bot", "procs", "libstdc", "factored", "libnative", "libgreen",
+    "rustuv", "bitrotted", "bitrot", "blessed", "standardizing", "splices", "impacting", "mmap",
+    "builders", "inconveniences", "incl", "terminfo", "borrowcheck", "stylesheet", "unaliasable",
+    "typechecking", "serializable", "ala", "mature", "thawing", "intertask", "visitor",
+    "conveniences", "iface", "repurposed", "typechecker", "col", "schedulers", "helgrind",
+    "revived", "arena", "regularization", "multithread", "ds", "dropckrfc", "sk", "pre",
+    "prevalent", "conversation", "lonely", "dialects", "nil", "shapes", "tony", "hoare", "inventor",
+    "resist", "temptation", "innumerable", "dollars", "damage", "forty", "pervasiveness", "ego",
+    "thankful", "audited", "segmentation", "dangers", "mangle", "frowned", "propensity",
+    "greetings", "cyrillic", "ze", "correlate", "misunderstandings", "diacritics", "cluster",
+    "nonequality", "bend", "halting", "complies", "inconvenienced", "stunt", "filmmaking", "actor",
+    "scene", "messenger", "deployed", "outwardly", "clarifying", "tougher", "fiddling",
+    "commonplace", "frog", "tiresome", "lingo", "actors", "directional", "travel", "waterway",
+    "flowing", "transmitting", "vital", "acronym", "por", "fran", "ais", "polski", "cebuano",
+    "tagalog", "esperanto", "svenska", "farsi", "persian", "deutsch", "danske", "ti", "ng", "vi",
+    "italiano", "rustier", "someday", "personally", "bread", "accompanies", "season", "designating",
+    "hypertext", "initiates", "telephone", "ico", "browsing", "locator", "typewriter", "handcoded",
+    "backyard", "industry", "seat", "bartenders", "drinks", "chefs", "cooks", "dishwashers",
+    "administrative", "nests", "apt", "juggling", "tokio", "cookies", "racing", "inventory",
+    "winner", "giveaway", "shirts", "rigidly", "agrees", "explicitness", "convoluted", "dictionary",
+    "goodies", "scores", "pig", "consonant", "ay", "vowel", "hay", "employee", "sally", "amir",
+    "sales", "adventures", "sherlock", "holmes", "sir", "arthur", "conan", "doyle", "bjarne",
+    "stroustrup", "keynote", "millimeters", "meters", "clarifies", "animal", "shelter", "animals",
+    "framed", "discipline", "ideology", "compel", "imitate", "continual", "pressed", "won",
+    "streamlining", "sizable", "temperatures", "fahrenheit", "celsius", "fibonacci", "lyrics",
+    "song", "cmd", "nickname", "uninstalling", "eats", "illustrating", "shoe", "plate",
+    "comparatively", "bookkeeping", "analogy", "heard", "wrinkle", "ceremony", "beginner",
+    "congratulatory", "reopen", "crab", "pincers", "grabbed", "figures", "presses", "insatiable",
+    "monster", "ruins", "shy", "graphical", "drawn", "refrain", "clicks", "quacks", "multibyte",
+    "emptied", "symptoms", "repost", "reply", "headline", "fuller", "cluttered", "pixels", "drat",
+    "prettiest", "complication", "virtue", "quicker", "penultimate", "lasting", "abbreviate",
+    "balancing", "hassle", "collaborating", "gatekeeper", "elusive", "chasing", "audio",
+    "transcoding", "cryptocurrencies", "bioinformatics", "crave", "striving", "endeavors",
+    "stakeholders", "ambition", "backgrounds", "delve", "revisit", "meticulous", "learner",
+    "smorgasbord", "appendixes", "throttle", "coincidence", "starving", "starvation", "carrying",
+    "handoff", "handing", "bottlenecks", "imagined", "incubation", "nascent", "loved", "heads",
+    "foothold", "innovation", "permeated", "communities", "catalyzed", "industries", "wisdom",
+    "passionate", "empowering", "empowerment", "advocates", "invests", "initiatives", "sustainable",
+    "reflecting", "invitation", "thoughtful", "seasoned", "enjoyable", "bec", "rumbul", "executive",
+    "director", "arrived", "interspersed", "rush", "awaits", "skeleton", "ampersand", "cmdlet",
+    "linter", "collaborative", "grained", "restructuring", "fifth", "overread", "investing",
+    "artistic", "science", "decouples", "deletions", "republish", "deprecating", "slowly",
+    "recognition", "slept", "wreak", "grinding", "contrasting", "shine", "feeling", "ambitious",
+    "worker", "workers", "transmits", "died", "greets", "agreed", "greeting", "imprecise",
+    "unknowns", "panoply", "dogmatic", "ungraceful", "robustness", "wondered", "emerged", "harmful",
+    "prices", "shopping", "cart", "spreadsheet", "nudges", "clearest", "minute", "hour", "earn",
+    "overshadows", "inputting", "spares", "inspiration", "devote", "accented", "korean", "emojis",
+    "mainstream", "weakness", "parallelizable", "countless", "absorb", "recalling", "worrying",
+    "toolkit", "comprising", "interrelated", "paradigms", "erich", "helm", "ralph", "johnson",
+    "john", "vlissides", "catalog", "stayed", "parametric", "restricting", "uncollected",
+    "visualizing", "piqued", "crux", "unapproved", "invalidating", "unpopulated", "blurring",
+    "disadvantage", "rethink", "lingering", "rethinking", "behaved", "yup", "recapped", "belt",
+    "lobally", "egular", "xpression", "rint", "andrew", "gallant", "realizing", "tuesday", "purple",
+    "anatomy", "puzzle", "rb", "js", "exclamation", "encompass", "daniel", "wirth",
+    "dissimilarities", "advancements", "rallying", "enumerates", "analyzes", "inferences",
+    "approves", "hypotheses", "emerge", "customizable", "announcing", "consecutively", "interfered",
+    "constantly", "riding", "cisco", "snuck", "hooray", "station", "clockwork", "sneak",
+    "unpolished", "bleeding", "comprised", "subteams", "rides", "lengthens", "reputation",
+    "conference", "enthusiastic", "hinted", "impressive", "tendency", "hyperoptimize", "helped",
+    "newfound", "essay", "humble", "edsger", "dijkstra", "hopelessly", "inadequate", "shoulders",
+    "simula", "alan", "he", "coined", "uproar", "watchers", "celebration", "billions", "dedicate",
+    "detour", "bored", "multitask", "realize", "intersect", "unblock", "coworker", "cycling",
+    "returnable", "variously", "cents", "penny", "united", "minted", "loses", "develops", "lessons",
+    "pools", "ampersands", "depicts", "explanatory", "struggle", "tasked", "bibliography",
+    "relegated", "misusing", "dreaded", "unallocated", "impractical", "categorically",
+    "transiently", "bubbling", "characteristic", "elem", "cheating", "bite", "intermix", "reals",
+    "deadlocked", "revolve", "devs", "smuggling", "pervasively", "digging", "disrepair", "facts",
+    "blatantly", "pragmatic", "concession", "radically", "unobservable", "seriously", "anguish",
+    "overwrote", "inconsistently", "mechanically", "weakening", "rigorously", "sloppy",
+    "erratically", "weighed", "derivations", "provably", "minimizes", "deinitialize", "stashed",
+    "reassigning", "brand", "bottoms", "slew", "varied", "memcopied", "troubles", "simulates",
+    "herein", "droppable", "ings", "broadest", "liberal", "instinct", "blame", "flip", "plausible",
+    "disjointness", "admit", "singly", "adopt", "magnified", "implore", "endure", "frob", "opinion",
+    "paradigm", "perils", "optimally", "fructose", "corn", "syrup", "tall", "shoot", "dumb",
+    "penalized", "lightly", "neighboring", "minds", "grew", "toy", "questionable", "badness",
+    "pollutes", "unregistering", "deregistration", "messy", "flying", "preemptively", "june",
+    "rigid", "disaster", "exploits", "sadness", "uncountable", "shenanigans", "stumble",
+    "guardrails", "dental", "floss", "boggling", "usages", "unhappy", "inhabit", "densely",
+    "autorefs", "unsizes", "breakout", "feelings", "boon", "plausibly", "typecheck", "intents",
+    "offenders", "safest", "uncontrolled", "strictest", "unpreventable", "precious", "reclaimed",
+    "rarer", "claiming", "rock", "enormous", "audacity", "gosh", "ridiculous", "scrapped", "eyes",
+    "death", "harbor", "avenues", "inviting", "fragile", "unimplement", "spite", "carton", "appeal",
+    "exotically", "aether", "necessitate", "marginal", "unreachability", "modelling", "habit",
+    "portrays", "decremented", "quintillion", "eh", "seeming", "insanely", "wrench", "slipped",
+    "drafts", "hey", "ludicrous", "quantities", "fortunate", "dealt", "ultimate", "payoff",
+    "artificially", "yep", "concluded", "decent", "bells", "whistles", "sweet", "fight", "specter",
+    "thankfully", "mysteriously", "busted", "abusing", "forehead", "slap", "pedantry", "massively",
+    "weeds", "merit", "gonna", "vsx", "altivec", "vreg", "vrsave", "hoisting", "recomputing",
+    "checkers", "adherence", "succession", "maximums", "legally", "positioning", "lvalues",
+    "rvalues", "parenthesisation", "deinitialized", "subexpression", "cetera", "inwards",
+    "desirability", "prudent", "nuance", "ill", "distributive", "law", "halts", "implication",
+    "rethrow", "transcribing", "behaviours", "textually", "misparse", "star", "ne", "gt", "ge",
+    "le", "semi", "debated", "conditioned", "supertype", "organised", "requisite", "recognizing",
+    "tokenizer", "exemplifies", "angular", "bastion", "manufactured", "unhygienic", "invocable",
+    "subportion", "uu", "unseparated", "unenforced", "parseable", "deserves", "varepsilon",
+    "subtrees", "stackable", "stems", "hinting", "strives", "subsystems", "tangible", "cyclone",
+    "newsqueak", "alef", "swift", "hermes", "notations", "xxxx", "xxxxxx", "partway", "mizushima",
+    "grammars", "literature", "disjunction", "centers", "owens", "flatt", "mesa", "medial",
+    "joiner", "metadatum", "notices", "nontrivial", "mere", "amended", "lifecycle", "reborrowing",
+    "phrasing", "penalties", "opaqueness", "inhabits", "acknowledgment", "standing", "promoting",
+    "writings", "characterised", "continuations", "destroys", "purposefully", "resolutions",
+    "traveling", "layered", "promoteds", "dominant", "osdev", "undecorated", "decorations",
+    "qualities", "importable", "unmentioned", "intrinsically", "loosened", "initiative",
+    "alternately", "unforced", "monomorphic", "inlines", "monomorphizing", "coercee", "locker",
+    "digest", "supplemental", "quadwords", "keylocker", "monitors", "serviceability", "statistical",
+    "zabha", "zacas", "zawrs", "zba", "zbb", "zbc", "zbkb", "zbkc", "zbkx", "crossbar",
+    "permutations", "zbs", "zca", "zcb", "zcmop", "zicbom", "zicbop", "zicboz", "ziccamoa",
+    "ziccif", "zicclsm", "ziccrse", "zicntr", "zicond", "zifencei", "zihintntl", "zihintpause",
+    "zihpm", "zimop", "zk", "zkn", "zknd", "zkne", "zknh", "zkr", "zks", "zksed", "zksh", "zkt",
+    "ztso", "multivalue", "nnp", "observation", "unattributed", "observers", "unreported",
+    "purposely", "supplement", "stacked", "printer", "hierarchically", "noticing", "subchapters",
+    "rigorous", "impatient", "hexadecimals", "formula", "elegance", "advantageous", "presume",
+    "factoring", "calculator", "designator", "sugary", "unsuccessfully", "unpleasant", "alas",
+    "inducing", "king", "hardcoding", "unprintable", "surpassed", "actuality", "protip", "hashmap",
+    "logon", "untidy", "boring", "reloaded", "voila", "organizationally", "elaborates", "playable",
+    "codefence", "reddit", "stipulate", "reimplements", "shields", "showcase", "lender", "banned",
+    "subtly", "unidirectional", "fluent", "headaches", "parcelling", "inputted", "chunked",
+    "delimitation", "flexibly", "preferentially", "anonymity", "freezes", "infamous", "equated",
+    "tolerable", "colorful", "unchangeable", "axiom", "pivotal", "consequential", "skin",
+    "reformat", "coincided", "hardest", "mysterious", "coexist", "veterans", "commenced",
+    "conceived", "daily", "rescoping", "neccessitate", "churn", "obviated", "qualifications",
+    "overcapturing", "undesired", "baroque", "reservations", "disallowing", "compelled",
+    "alterations", "fictional", "squirreling", "auditing", "narrowed", "rewrites", "overcome",
+    "upset", "elect", "unhelpful", "shame", "examination", "defeating", "equalize", "installable",
+    "nutshell", "countdowns", "analog", "digital", "reinvent", "wheel", "flavours", "multiplexer",
+    "temperature", "actuators", "motors", "transmitters", "adaptation", "initialisation", "buses",
+    "renders", "typestates", "exchanging", "dozens", "unroll", "halve", "squareroot", "algebra",
+    "subroutines", "semaphores", "unhandled", "wires", "plenty", "barebone", "pun", "divergent",
+    "flashed", "nix", "booting", "glitch", "debugonomicon", "blinking", "studying", "blink",
+    "bluetooth", "crudely", "middling", "frequencies", "misconvert", "programmable", "diversion",
+    "microprocessors", "synchronising", "luxury", "rising", "incremements", "needlessly", "jitter",
+    "exclusivity", "satisfactory", "summarise", "unlocks", "disastrous", "deadlocking",
+    "initialise", "firing", "periodic", "surroundings", "controllers", "yesterday", "cards",
+    "vulkan", "kilobytes", "megabits", "kilobits", "calibration", "rusty", "diligent", "spread",
+    "frightening", "suspiciously", "cmake", "datatype", "piped", "drives", "transport",
+    "semiconductors", "versatility", "affordability", "hobbyists", "educators", "professional",
+    "visualstudio", "sku", "rel", "gyroscope", "voltage", "volts", "ratings", "bootloaders",
+    "bootloader", "cable", "connectors", "sits", "openocd", "stretch", "plugged", "unplug", "plug",
+    "battle", "fragmentation", "virtually", "mimic", "erasure", "reconfigure", "customise", "xargo",
+    "npmjs", "bundler", "ecosystems", "minority", "discoverability", "bogs", "leveraged", "fronts",
+    "wrangle", "canvas", "replay", "libcargo", "publishable", "deserialize", "spellcheck", "tmpdir",
+    "ghostty", "ellipses", "phrasings", "sanitiztion", "forks", "prev", "cachelock", "interprocess",
+    "reqwest", "rej", "orig", "plumb", "perma", "completer", "prep", "styled", "schemars", "gh",
+    "dirtiness", "xcompile", "libatomic", "capitalized", "fro", "humantime", "jiff", "pathspec",
+    "unpublishable", "cmds", "refspec", "cargos", "bindep", "bors", "snapshotting", "trusts",
+    "longhand", "pathspecs", "gmake", "unreferenced", "overlays", "activations", "populate",
+    "freshness", "msrv", "recategorize", "decouple", "surce", "misses", "statuses", "annoyance",
+    "diffing", "worktrees", "dashboard", "wincon", "auth", "buildfile", "clickable", "controllable",
+    "manpage", "cli", "mismatching", "util", "bails", "overlooks", "monthly", "rewrote",
+    "negotiations", "purges", "redacted", "arranges", "manipulations", "punctuations", "em",
+    "autolabelled", "subcrate", "headless", "coexists", "angles", "discoverable", "urls",
+    "interrupting", "jul", "nov", "cgroups", "sporadically", "differentiates", "intermittent",
+    "leaner", "misnamed", "unstaged", "mishandling", "unversioned", "colliding", "googlesource",
+    "aux", "scrub", "decoupling", "honors", "bitbucket", "dll", "iowait", "correlating", "seg",
+    "topological", "munging", "cirrus", "blowup", "reproduces", "stomped", "november", "groundwork",
+    "redisplay", "symbolically", "restarted", "regenerated", "azure", "flickering", "confirmation",
+    "downgraded", "animated", "developmental", "landmark", "automates", "passwords", "osxkeychain",
+    "pageant", "sshd", "administrator", "theirs", "blush", "farming", "nuanced", "lifting",
+    "endorsement", "patchable", "trade", "polyfill", "drifts", "frustration", "unmet",
+    "justifiable", "disempower", "hurt", "weighing", "principled", "resorting", "earned",
+    "alleviates", "centralizing", "overridable", "semaphore", "authenticates", "keyring", "wallet",
+    "enterprising", "gears", "participation", "fileapi", "handleapi", "winnt", "coordinating",
+    "cooperate", "architect", "readily", "blurb", "lieu", "upwards", "prereleases", "unresolvable",
+    "outweigh", "packagers", "checksums", "predictably", "depedant", "bill", "ingested",
+    "mandating", "authorized", "footer", "unshallowing", "distinguishment", "cluttering",
+    "sanitizes", "reoganization", "inaccuracies", "elvish", "powershell", "depinfo",
+    "recompilations", "bars", "replayed", "resolvers", "multitarget", "speeding", "restructure",
+    "opportunistically", "converged", "duplications", "politely", "troublesome", "unwilling",
+    "influencing", "matrices", "complicating", "pros", "nginx", "homograph", "refreshing", "mouse",
+    "lavender", "bottlenecked", "communicates", "probed", "mercurial", "underneath",
+    "intelligently", "assets", "curated", "memberships", "enterprise", "grade", "blacklist",
+    "whitelisted", "authorize", "exercised", "tighten", "rapid", "discontinues", "discontinue",
+    "residing", "debuginfos", "diminishing", "dominate", "activating", "passively", "dependabot",
+    "thoroughness", "turnaround", "pronounced", "pairing", "tomorrow", "preliminaries",
+    "normalizes", "environmental", "recompressing", "reuploading", "reextracting", "breakages",
+    "irrespective", "susceptibility", "rand", "prune", "charset", "testname", "pristine",
+    "recompute", "td", "nowrap", "haswell", "deferring", "interprocedural", "compability", "vcpkg",
+    "inclined", "commensurate", "governance", "foreseen", "motivate", "reviewers", "suitability",
+    "filing", "preclude", "promotions", "gauge", "acceptance", "beliefs", "employer", "liability",
+    "adversely", "livelihood", "prospects", "recuse", "cited", "threats", "educate", "gratuitously",
+    "feasibly", "justify", "approvals", "deficiencies", "prominently", "smoke", "redistributing",
+    "land", "likelihood", "sandboxed", "sandboxes", "intervention", "renewal", "smoothly",
+    "feasible", "obligate", "smoother", "enhances", "bundles", "mangler", "tailor", "overlaid",
+    "countable", "survey", "benefiting", "unrolled", "hijacked", "comprehensiveness",
+    "commercially", "authoritative", "spills", "contradicting", "setarch", "davidoff", "shnatsel",
+    "kmcallister", "lehel", "turon", "wilson", "myths", "legends", "huon", "huonw", "brson",
+    "froyd", "esp", "ndola", "seipp", "librustrt", "clark", "bsd", "zoxc", "guelton", "ledru",
+    "stone", "thberg", "smallcultfollowing", "babysteps", "fackler", "jemallocator", "bbjornse",
+    "smash", "valle", "controlflowintegrity", "lozano", "shadowcallstack", "wiser", "safestack",
+    "ptmalloc", "wolfram", "gloger", "bundling", "pathless", "coloring", "ansi", "decodable",
+    "identifiable", "computationally", "unscoped", "referrer", "segregate", "differentiating",
+    "alphanumerics", "accompanying", "backrefs", "latitude", "ascertains", "unambiguity",
+    "rustfilt", "refinement", "nudge", "trumps", "penalizes", "surfacing", "actionable",
+    "impediment", "remedy", "informed", "introductions", "hoist", "wasted", "silencing", "bloating",
+    "redefinition", "prudence", "reconsider", "faulty", "alerting", "positionally", "reintroducing",
+    "amiss", "arrival", "codepoint", "fmease", "explainer", "functionlike", "fallen", "ibm",
+    "inflict", "inflicts", "uncertain", "whos", "aliasable", "overlooking", "cba",
+    "interpretations", "amendments", "segfaults", "collapsing", "combinatorial", "defers",
+    "interpositioning", "rpaths", "emulates", "ineffectual", "incomprehensible", "introspection",
+    "obfuscation", "disassemblers", "decompilers", "tuning", "filepath", "libdir", "synthesizes",
+    "thesummer", "flight", "zedboard", "stlankes", "unikernels", "chainloaded", "uhyve", "branded",
+    "shipping", "biabbas", "vsb", "windriver", "estdc", "fujitsu", "incompatibles", "semarie",
+    "thejpster", "quic", "insofar", "embeddings", "rebased", "standardization", "micro", "worlds",
+    "rich", "phone", "petrochenkov", "unavailability", "bootstraps", "culled", "protracted",
+    "ivmarkov", "wzssyqa", "netgear", "asus", "lancethepants", "router", "unsuitable", "deb",
+    "subproject", "wget", "hoodmane", "juntyr", "nvm", "kyoto", "microcomputer", "ltd", "kawadakk",
+    "loongson", "corporation", "glaubitz", "commodore", "amiga", "atari", "skrap", "bootlin",
+    "distro", "psx", "ayrtonm", "accelerators", "kjetilkjeka", "maximizes", "haraldh", "mikeleany",
+    "zones", "flaub", "jbruestle", "seal", "nto", "elektrobit", "automotive", "japaric", "triplet",
+    "leohowell", "subgroup", "transitionary", "jco", "educational", "vexide", "lewisfm", "softfp",
+    "uploadable", "chriswailes", "jfgoog", "maurer", "nine", "enthusiasm", "perceive", "terribly",
+    "nokia", "misnomer", "bespoke", "rylev", "octocat", "flakebi", "sea", "islands", "entrypoints",
+    "xnack", "kulikjak", "uk", "vmactions", "cfarm", "relibc", "redoxer", "incompletely",
+    "sthibaul", "virtualized", "lasiotus", "ahomescu", "bdbai", "lokathor", "corwinkuiper",
+    "berrysoft", "july", "abrown", "ab", "repurpose", "wamr", "meziu", "citra", "macbook", "macs",
+    "libui", "mat", "volf", "lacking", "turris", "freescale", "dennisbonke", "technologies",
+    "stubs", "graydon", "anyways", "gitlab", "dirreke", "catalina", "knickish", "gnu", "fewest",
+    "blocker", "catchpads", "atmel", "transmit", "withdrawn", "microkernel", "xobs", "updatable",
+    "erickt", "freshly", "markup", "reruns", "fdio", "mipsisa", "cyanoxygen", "fearyncess",
+    "finalize", "asomers", "lambdageek", "sivadeilra", "wesleywiser", "cceerczw", "ohos",
+    "targetability", "arming", "incorporatable", "thereotically", "compilable", "enclaves",
+    "enclave", "jethrogb", "raoulstrackx", "aditijannu", "converter", "adhering", "flows",
+    "michaelmaitland", "topperc", "embecosm", "jclulow", "pfmooney", "sucessfully", "kraj", "hegza",
+    "nikarh", "pheki", "zetanumbers", "elf", "emphasis", "addons", "dvdhrm", "nicholasbishop",
+    "tianocore", "transposed", "integrations", "efi", "rustified", "rustification", "cmath",
+    "ported", "nodejs", "exciting", "pretends", "kraftkit", "roblabla", "combinable",
+    "normalisation", "normalisations", "relieved", "formulate", "corpus", "purport", "widest",
+    "grep", "aesthetics", "minimising", "cratename", "footing", "rtm", "mandate", "bpf",
+    "definitional", "ignorable", "veneer", "concat", "idents", "lahfsahf", "ermsb", "wfness",
+    "confidential", "inefficiently", "inlineable", "inliner", "avr", "matchable", "subsumed",
+    "prfchw", "hops", "customised", "aiming", "deshadowing", "csky", "viral", "prediction",
+    "evidence", "gathering", "alludes", "progresses", "ambient", "pluggable", "reify", "reified",
+    "carves", "feaures", "exportable", "mgca", "machinery", "sparc", "presuming", "wind",
+    "workable", "calibrator", "pinpointing", "gotcha", "chromium", "disrupt", "constituents",
+    "recourse", "bisecting", "bisection", "contrasted", "profiled", "libgcc", "libunwind",
+    "investigating", "codegened", "monomorph", "cmdline", "interposed", "earier", "whitepaper",
+    "predictive", "peers", "summarizing", "amplified", "striping", "harden", "overheads",
+    "parallelization", "gradients", "sdata", "sbss", "lua", "diversifier", "hotpatching", "impede",
+    "generalised", "origins", "interception", "userland", "santizer", "inevitably", "symbolizing",
+    "symbolized", "stacktraces", "pseudorandomly", "permutation", "freedoms", "observably",
+    "stolen", "codegeneration", "stikes", "hyperlink", "autodetect", "ergo", "jmp", "analogically",
+    "finishing", "dictated", "prepackaged", "lessen", "unknowable", "resemble", "alarm", "millions",
+    "facto", "elides", "deduced", "specializations", "inadmissible", "recreating", "irreconcilable",
+    "enumerator", "subtler", "outlived", "misuses", "disappeared", "prohibits", "reconciled",
+    "uninstantiated", "signified", "sensibly", "unaliased", "unimportable", "termed", "orderable",
+    "insufficiently", "unterminated", "appearances", "expandable", "quantifications",
+    "reinitializing", "wand", "holy", "grail", "enjoy", "styleguide", "circled", "backs",
+    "methodology", "typedef", "overidden", "unclosed", "parsable", "removable", "subheadings",
+    "hovered", "valgrind", "opposing", "expertise", "novice", "glazes", "unanswered", "tenets",
+    "ample", "polished", "rome", "rustdocs", "overviews", "hyper", "abruptly", "clutters",
+    "preprocess", "distract", "testability", "judicious", "sysinfo", "css", "aide", "loudly",
+    "kicks", "progressively", "delimits", "deactivate", "annoy", "emphatically", "uninlined",
+    "dotfiles", "cognitive", "supress", "legitimate", "collapsible", "singleline", "unavoidable",
+    "sprinkling", "sus", "offends", "justifying", "abundance", "suits", "supervision",
+    "interpolate", "chaotic", "realm", "illogical", "empowers", "outputting", "peeking", "coder",
+    "automation", "grave", "accents", "quickest", "allowance", "cherry", "subfolder", "fellow",
+    "readings", "beginners", "dear", "peruse", "robert", "crafting", "bugfixing", "intellij",
+    "proxies", "repair", "orderly", "unforeseeable", "unfair", "heavier", "lifeblood", "ics",
+    "rustbot", "healthy", "inactivity", "abandon", "appreciates", "privately", "recharge",
+    "responsive", "unanimously", "voil", "beloved", "authoring", "hackers", "flooding", "urgent",
+    "cadence", "backup", "accelerate", "thursday", "judgement", "exchanged", "mentorship",
+    "nowadays", "recruit", "scaling", "starters", "syncs", "influx", "dashboards", "planing",
+    "prioritization", "ifs", "declaratively", "proposes", "optionals", "suspect", "novices",
+    "independence", "adaptions", "publicity", "collapses", "quadrant", "piecewise", "plethora",
+    "planes", "mirror", "ideographic", "ligature", "unrepresentable", "stringifies",
+    "stringification", "superimposed", "perused", "distracting", "skimmable", "curtains",
+    "enlightening", "bedrock", "mighty", "familiarizing", "contribution", "abstracting", "exempted",
+    "hookable", "oom", "symbolicated", "detailing", "addr", "reexporting", "labelled", "leftmost",
+    "mandated", "idable", "reborrowable", "tipliable", "stevens", "connectionless", "looped",
+    "outgoing", "uefi", "nagle", "conforming", "veorq", "spooky", "city", "circle", "preface",
+    "generalizes", "reporter", "quantity", "reactor", "nonzeroness", "disposition", "zombies",
+    "informantion", "struck", "ubiquitous", "savvy", "parking", "twofold", "condvars",
+    "cooperatively", "nonetheless", "expiration", "setter", "destructed", "factory",
+    "orchestrators", "burst", "latencies", "cpuset", "overcommitted", "asleep", "dragonfly",
+    "vxworks", "monitoring", "detaches", "danger", "wit", "negligible", "passwd", "setuid",
+    "setgid", "cease", "remained", "lenient", "flushable", "buf", "mount", "escalation",
+    "unwritable", "nuances", "mounted", "reinstating", "terser", "execs", "absurd", "disposes",
+    "processable", "vardiadic", "feb", "bridges", "fptoui", "fptosi", "misbehave", "postcondition",
+    "slowdown", "causal", "acknowledgement", "awakened", "necessity", "expressible", "rogue",
+    "reaping", "cooperation", "finalizes", "winuser", "msvcrt", "configurability", "midnight",
+    "tricking", "pairings", "msg", "recv", "exec", "foreground", "posix", "utilises", "vigilant",
+    "fifo", "uid", "gid", "sgid", "chown", "underpinning", "unixes", "interference", "augments",
+    "leader", "novel", "enqueued", "monomorphize", "miscompiled", "born",
+];
+
+/// Returns [`DOCS_WORDS`] in alphabetical order, for binary search. The list
+/// is sorted on first use.
+pub(crate) fn docs_words_sorted() -> &'static [&'static str] {
+    static SORTED: OnceLock<Vec<&str>> = OnceLock::new();
+    SORTED.get_or_init(|| {
+        let mut words = DOCS_WORDS.to_vec();
+        words.sort_unstable();
+        words
+    })
+}
+
+/// Returns the length of the longest word in [`DOCS_WORDS`].
+pub(crate) fn longest_docs_word_len() -> usize {
+    static LONGEST: OnceLock<usize> = OnceLock::new();
+    *LONGEST.get_or_init(|| DOCS_WORDS.iter().map(|word| word.len()).max().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorted() {
+        let sorted = docs_words_sorted();
+        assert_eq!(sorted.len(), DOCS_WORDS.len());
+        assert!(sorted.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(sorted
+            .iter()
+            .all(|word| word.len() <= longest_docs_word_len()));
+    }
+}
@@ -0,0 +1,154 @@
+use crate::columnar::{self, Permutation};
+use crate::lowercase_string::LowercaseString;
+use crate::ngram::NgramModel;
+use crate::utils::{Rng, SolverConfig};
+
+const SEED: u64 = 0x5eed;
+
+/// The annealing schedule of [`solve`].
+const DEFAULT_CONFIG: SolverConfig = SolverConfig {
+    initial_temperature: 40.0,
+    cooling_rate: 0.999_7,
+    steps: 20_000,
+    model: None,
+};
+
+/// The longest key tried by [`solve`] when a key length is 0.
+const MAX_KEY_LEN: usize = 6;
+
+/// Decrypts a double transposition: the second transposition is undone
+/// first.
+fn decrypt(text: &LowercaseString, key1: &Permutation, key2: &Permutation) -> LowercaseString {
+    columnar::decrypt_with_permutation(&columnar::decrypt_with_permutation(text, key2), key1)
+}
+
+/// Anneals a pair of keys of the given lengths, returning the best pair
+/// found and its score.
+fn anneal(
+    text: &LowercaseString,
+    key_len1: usize,
+    key_len2: usize,
+    config: &SolverConfig,
+    rng: &mut Rng,
+) -> (f64, Permutation, Permutation) {
+    let (n, table) = config.scoring_table();
+    let score = |key1: &Permutation, key2: &Permutation| {
+        let decrypted = decrypt(text, key1, key2);
+        NgramModel::table_score(&table, n, decrypted.to_indices().iter().copied())
+    };
+
+    let identity = |len: usize| Permutation::from_keyword(&"a".repeat(len)).unwrap();
+    let mut current = (identity(key_len1), identity(key_len2));
+    let mut current_score = score(&current.0, &current.1);
+    let mut best = (current_score, current.0.clone(), current.1.clone());
+    let mut temperature = config.initial_temperature;
+
+    for _ in 0..config.steps {
+        let mut candidate = current.clone();
+        let key = if rng.below(2) == 0 {
+            &mut candidate.0
+        } else {
+            &mut candidate.1
+        };
+        if key.len() > 1 {
+            let i = rng.below(key.len() - 1);
+            key.swap(i, i + 1);
+        }
+        let candidate_score = score(&candidate.0, &candidate.1);
+
+        let accept = candidate_score < current_score
+            || rng.next_f64() < ((current_score - candidate_score) / temperature).exp();
+        if accept {
+            current = candidate;
+            current_score = candidate_score;
+            if current_score < best.0 {
+                best = (current_score, current.0.clone(), current.1.clone());
+            }
+        }
+        temperature *= config.cooling_rate;
+    }
+    best
+}
+
+/// Solves a double columnar transposition, where the text was encrypted
+/// with [`columnar::encrypt`] under one key and then under another, with a
+/// default annealing schedule. See [`solve_with_config`].
+///
+/// # Example
+/// ```
+/// use cipher_solver::{columnar, double_transposition};
+///
+/// let text = "Shall I compare thee to a summer's day? Thou art more lovely and \
+///     more temperate: Rough winds do shake the darling buds of May";
+/// let once = columnar::encrypt(text, "cab").unwrap();
+/// let twice = columnar::encrypt(&once, "bad").unwrap();
+/// let (plaintext, key1, key2) = double_transposition::solve(&twice, 3, 3);
+/// assert_eq!(key1, "cab");
+/// assert_eq!(key2, "bac");
+/// assert!(plaintext.starts_with("shallicomparethee"));
+/// ```
+#[must_use]
+pub fn solve(text: &str, key_len1: usize, key_len2: usize) -> (String, String, String) {
+    solve_with_config(text, key_len1, key_len2, &DEFAULT_CONFIG)
+}
+
+/// Solves a double columnar transposition using simulated annealing over
+/// both column orders, scoring candidates with the model of the config (by
+/// default [`NgramModel::english_quadgrams`]). Letter frequencies are left
+/// unchanged by transposition, so only models of two or more letters help.
+///
+/// A key length of 0 tries every length from 1 to 6, each with the full
+/// schedule. Keys are returned as [`Permutation::to_keyword`], so any
+/// keyword with the same letter order is equivalent.
+/// Returns the plaintext and both keys.
+#[must_use]
+pub fn solve_with_config(
+    text: &str,
+    key_len1: usize,
+    key_len2: usize,
+    config: &SolverConfig,
+) -> (String, String, String) {
+    let text = LowercaseString::normalize(text);
+    let lengths = |key_len: usize| match key_len {
+        0 => 1..=MAX_KEY_LEN,
+        n => n..=n,
+    };
+    let mut rng = Rng::new(SEED);
+
+    let (_, key1, key2) = lengths(key_len1)
+        .flat_map(|len1| lengths(key_len2).map(move |len2| (len1, len2)))
+        .map(|(len1, len2)| anneal(&text, len1, len2, config, &mut rng))
+        .min_by(|(score1, ..), (score2, ..)| score1.total_cmp(score2))
+        .unwrap();
+    (
+        decrypt(&text, &key1, &key2).to_string(),
+        key1.to_keyword(),
+        key2.to_keyword(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::OZYMANDIAS;
+
+    #[test]
+    fn test_solve() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        let plaintext = &normalized[..200];
+        let once = columnar::encrypt(plaintext, "sand").unwrap();
+        let twice = columnar::encrypt(&once, "stone").unwrap();
+        assert_eq!(twice.len(), 200);
+
+        let (solved, key1, key2) = solve(&twice, 4, 5);
+        assert_eq!(solved, plaintext);
+        assert_eq!(
+            Permutation::from_keyword(&key1),
+            Permutation::from_keyword("sand")
+        );
+        assert_eq!(
+            Permutation::from_keyword(&key2),
+            Permutation::from_keyword("stone")
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use std::fmt::Write;
+
+use crate::lowercase_string::LowercaseString;
+use crate::utils::ENGLISH_FREQUENCIES;
+
+/// Letter frequencies of a text, for comparing against English.
+///
+/// # Example
+/// ```
+/// use cipher_solver::frequency_analysis::FrequencyAnalysis;
+///
+/// let analysis = FrequencyAnalysis::new("aab");
+/// let histogram = analysis.display_histogram(10);
+/// assert!(histogram.starts_with("a: ██████████ 66.7%"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrequencyAnalysis {
+    frequencies: [f64; 26],
+}
+
+impl FrequencyAnalysis {
+    #[must_use]
+    pub fn new(text: &str) -> Self {
+        Self {
+            frequencies: LowercaseString::normalize(text).letter_frequencies(),
+        }
+    }
+
+    #[must_use]
+    pub fn frequencies(&self) -> [f64; 26] {
+        self.frequencies
+    }
+
+    /// Renders an ASCII bar chart with one line per letter.
+    ///
+    /// The observed frequency is drawn as a filled bar (`█`). Where English
+    /// would expect a higher frequency, the difference is drawn lighter (`░`).
+    /// The largest frequency is scaled to `width` columns.
+    #[must_use]
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    pub fn display_histogram(&self, width: usize) -> String {
+        let max = self
+            .frequencies
+            .iter()
+            .chain(&ENGLISH_FREQUENCIES)
+            .copied()
+            .fold(0.0, f64::max);
+        let columns = |frequency: f64| (frequency / max * width as f64).round() as usize;
+
+        let mut output = String::new();
+        for (letter, (&observed, &expected)) in
+            ('a'..='z').zip(self.frequencies.iter().zip(&ENGLISH_FREQUENCIES))
+        {
+            let filled = columns(observed);
+            let lighter = columns(expected).saturating_sub(filled);
+            let padding = width - filled - lighter;
+            writeln!(
+                output,
+                "{letter}: {}{}{} {:.1}%",
+                "█".repeat(filled),
+                "░".repeat(lighter),
+                " ".repeat(padding),
+                observed * 100.0
+            )
+            .unwrap();
+        }
+        output
+    }
+
+    /// Lists the observed and expected frequency of each letter, along with
+    /// the residual (observed minus expected).
+    #[must_use]
+    pub fn compare_to_english(&self) -> String {
+        let mut output = String::new();
+        for (letter, (&observed, &expected)) in
+            ('a'..='z').zip(self.frequencies.iter().zip(&ENGLISH_FREQUENCIES))
+        {
+            writeln!(
+                output,
+                "{letter}: observed {:5.1}% expected {:5.1}% residual {:+5.1}%",
+                observed * 100.0,
+                expected * 100.0,
+                (observed - expected) * 100.0
+            )
+            .unwrap();
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_histogram() {
+        let histogram = FrequencyAnalysis::new("eeeet").display_histogram(10);
+        let lines: Vec<_> = histogram.lines().collect();
+        assert_eq!(lines.len(), 26);
+        assert_eq!(lines[4], "e: ██████████ 80.0%");
+        assert_eq!(lines[19], "t: ███        20.0%");
+        assert_eq!(lines[0], "a: ░          0.0%");
+        assert_eq!(lines[25], "z:            0.0%");
+    }
+
+    #[test]
+    fn test_compare_to_english() {
+        let comparison = FrequencyAnalysis::new("e").compare_to_english();
+        let lines: Vec<_> = comparison.lines().collect();
+        assert_eq!(lines.len(), 26);
+        assert_eq!(
+            lines[4],
+            "e: observed 100.0% expected  12.7% residual +87.3%"
+        );
+        assert_eq!(
+            lines[0],
+            "a: observed   0.0% expected   8.2% residual  -8.2%"
+        );
+    }
+}
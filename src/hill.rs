@@ -0,0 +1,330 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::lowercase_string::LowercaseString;
+use crate::utils;
+
+/// The letter used to pad a text to a whole number of blocks ('x').
+const PADDING: u8 = 23;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HillError {
+    /// The key's determinant (mod 26), which shares a factor with 26.
+    NonInvertibleKey(u8),
+}
+
+impl Display for HillError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonInvertibleKey(determinant) => {
+                write!(f, "key determinant {determinant} has no inverse modulo 26")
+            }
+        }
+    }
+}
+
+impl Error for HillError {}
+
+/// Inverts a 2×2 matrix modulo 26, if its determinant is invertible.
+fn invert(matrix: [[u8; 2]; 2]) -> Option<[[u8; 2]; 2]> {
+    let [[a, b], [c, d]] = matrix.map(|row| row.map(|x| u32::from(x % 26)));
+    let determinant = u8::try_from((a * d + 26 * 26 - b * c) % 26).unwrap();
+    let inverse = u32::from(utils::modular_inverse(determinant, 26)?);
+    #[allow(clippy::cast_possible_truncation)]
+    let entry = |x: u32| (x * inverse % 26) as u8;
+    Some([
+        [entry(d), entry((26 - b) % 26)],
+        [entry((26 - c) % 26), entry(a)],
+    ])
+}
+
+/// Inverts a 3×3 matrix modulo 26, or returns its determinant if that has
+/// no inverse.
+fn invert_3x3(matrix: [[u8; 3]; 3]) -> Result<[[u8; 3]; 3], HillError> {
+    utils::matrix_inverse_mod26_3x3(matrix)
+        .ok_or_else(|| HillError::NonInvertibleKey(utils::det_mod26(matrix)))
+}
+
+fn multiply<const N: usize, const M: usize>(a: [[u8; N]; N], b: [[u8; M]; N]) -> [[u8; M]; N] {
+    std::array::from_fn(|i| {
+        std::array::from_fn(|j| {
+            let sum: u32 = (0..N)
+                .map(|k| u32::from(a[i][k]) * u32::from(b[k][j]))
+                .sum();
+            #[allow(clippy::cast_possible_truncation)]
+            let entry = (sum % 26) as u8;
+            entry
+        })
+    })
+}
+
+/// Multiplies each block of `N` letters of the text, as a column vector, by
+/// the matrix. The last block is padded.
+fn apply<const N: usize>(text: &LowercaseString, matrix: [[u8; N]; N]) -> LowercaseString {
+    let mut indices = text.to_indices().to_vec();
+    while !indices.len().is_multiple_of(N) {
+        indices.push(PADDING);
+    }
+    LowercaseString::from_indices(
+        indices
+            .chunks(N)
+            .flat_map(|block| {
+                let column: [[u8; 1]; N] = std::array::from_fn(|i| [block[i]]);
+                multiply(matrix, column).map(|[x]| x)
+            })
+            .collect(),
+    )
+}
+
+/// Encrypts a message using a 2×2 Hill cipher, multiplying each pair of
+/// letters, as a column vector, by the key matrix (mod 26). An odd-length
+/// text is padded with 'x'. Returns `None` if the key has no inverse
+/// modulo 26.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::hill;
+///
+/// let encrypted = hill::encrypt("help", [[3, 3], [2, 5]]).unwrap();
+/// assert_eq!(encrypted, "hiat");
+/// assert_eq!(hill::encrypt("help", [[2, 4], [1, 2]]), None);
+/// ```
+#[must_use]
+pub fn encrypt(text: &str, key: [[u8; 2]; 2]) -> Option<String> {
+    invert(key)?;
+    let key = key.map(|row| row.map(|x| x % 26));
+    Some(apply(&LowercaseString::normalize(text), key).to_string())
+}
+
+/// Decrypts a message using a 2×2 Hill cipher with the given key matrix.
+/// Padding is not removed. Returns `None` if the key has no inverse modulo
+/// 26.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::hill;
+///
+/// let decrypted = hill::decrypt("hiat", [[3, 3], [2, 5]]).unwrap();
+/// assert_eq!(decrypted, "help");
+/// ```
+#[must_use]
+pub fn decrypt(text: &str, key: [[u8; 2]; 2]) -> Option<String> {
+    let inverse = invert(key)?;
+    Some(apply(&LowercaseString::normalize(text), inverse).to_string())
+}
+
+/// Encrypts a message using a 3×3 Hill cipher, multiplying each trigraph,
+/// as a column vector, by the key matrix (mod 26). The text is padded with
+/// 'x' to a whole number of trigraphs.
+/// Punctuation and whitespace are removed.
+///
+/// # Errors
+/// Returns [`HillError::NonInvertibleKey`] if the key has no inverse modulo
+/// 26.
+///
+/// # Example
+/// ```
+/// use cipher_solver::hill;
+///
+/// let key = [[6, 24, 1], [13, 16, 10], [20, 17, 15]];
+/// assert_eq!(hill::encrypt_3x3("act", key), Ok("poh".to_string()));
+/// ```
+pub fn encrypt_3x3(text: &str, key: [[u8; 3]; 3]) -> Result<String, HillError> {
+    invert_3x3(key)?;
+    let key = key.map(|row| row.map(|x| x % 26));
+    Ok(apply(&LowercaseString::normalize(text), key).to_string())
+}
+
+/// Decrypts a message using a 3×3 Hill cipher with the given key matrix.
+/// Padding is not removed.
+/// Punctuation and whitespace are removed.
+///
+/// # Errors
+/// Returns [`HillError::NonInvertibleKey`] if the key has no inverse modulo
+/// 26.
+///
+/// # Example
+/// ```
+/// use cipher_solver::hill;
+///
+/// let key = [[6, 24, 1], [13, 16, 10], [20, 17, 15]];
+/// assert_eq!(hill::decrypt_3x3("poh", key), Ok("act".to_string()));
+/// ```
+pub fn decrypt_3x3(text: &str, key: [[u8; 3]; 3]) -> Result<String, HillError> {
+    let inverse = invert_3x3(key)?;
+    Ok(apply(&LowercaseString::normalize(text), inverse).to_string())
+}
+
+/// Recovers the key of a 2×2 Hill cipher from a ciphertext and its known
+/// plaintext. Two digraph pairs determine the key, so pairs are tried in
+/// order until their plaintext matrix is invertible, and the key is checked
+/// against the whole text. Returns `None` if no pairs give a consistent
+/// key.
+///
+/// # Example
+/// ```
+/// use cipher_solver::hill;
+///
+/// let key = [[3, 3], [2, 5]];
+/// let plaintext = "short but known";
+/// let encrypted = hill::encrypt(plaintext, key).unwrap();
+/// assert_eq!(hill::solve_known_plaintext(&encrypted, plaintext), Some(key));
+/// ```
+#[must_use]
+pub fn solve_known_plaintext(ciphertext: &str, plaintext: &str) -> Option<[[u8; 2]; 2]> {
+    let ciphertext = LowercaseString::normalize(ciphertext);
+    let plaintext = LowercaseString::normalize(plaintext);
+    let len = ciphertext
+        .to_indices()
+        .len()
+        .min(plaintext.to_indices().len())
+        / 2
+        * 2;
+    let c = &ciphertext.to_indices()[..len];
+    let p = &plaintext.to_indices()[..len];
+    let digraphs = len / 2;
+    let known = LowercaseString::from_indices(p.to_vec());
+
+    (0..digraphs)
+        .flat_map(|i| (i + 1..digraphs).map(move |j| (2 * i, 2 * j)))
+        .find_map(|(i, j)| {
+            let p_inverse = invert([[p[i], p[j]], [p[i + 1], p[j + 1]]])?;
+            let key = multiply([[c[i], c[j]], [c[i + 1], c[j + 1]]], p_inverse);
+            (invert(key).is_some() && apply(&known, key).to_indices() == c).then_some(key)
+        })
+}
+
+/// Recovers the key of a 3×3 Hill cipher from a ciphertext and its known
+/// plaintext. Three trigraph pairs determine the key, so triples are tried
+/// in order until their plaintext matrix is invertible, and the key is
+/// checked against the whole text. Returns `None` if no triples give a
+/// consistent key.
+///
+/// # Example
+/// ```
+/// use cipher_solver::hill;
+///
+/// let key = [[6, 24, 1], [13, 16, 10], [20, 17, 15]];
+/// let plaintext = "a longer known plaintext";
+/// let encrypted = hill::encrypt_3x3(plaintext, key).unwrap();
+/// assert_eq!(hill::solve_known_plaintext_3x3(&encrypted, plaintext), Some(key));
+/// ```
+#[must_use]
+pub fn solve_known_plaintext_3x3(ciphertext: &str, plaintext: &str) -> Option<[[u8; 3]; 3]> {
+    let ciphertext = LowercaseString::normalize(ciphertext);
+    let plaintext = LowercaseString::normalize(plaintext);
+    let len = ciphertext
+        .to_indices()
+        .len()
+        .min(plaintext.to_indices().len())
+        / 3
+        * 3;
+    let c = &ciphertext.to_indices()[..len];
+    let p = &plaintext.to_indices()[..len];
+    let trigraphs = len / 3;
+    let known = LowercaseString::from_indices(p.to_vec());
+    // The trigraphs starting at the given offsets, as the columns of a matrix.
+    let columns = |text: &[u8], offsets: [usize; 3]| -> [[u8; 3]; 3] {
+        std::array::from_fn(|row| offsets.map(|offset| text[offset + row]))
+    };
+
+    (0..trigraphs)
+        .flat_map(|i| {
+            (i + 1..trigraphs)
+                .flat_map(move |j| (j + 1..trigraphs).map(move |k| [3 * i, 3 * j, 3 * k]))
+        })
+        .find_map(|offsets| {
+            let p_inverse = utils::matrix_inverse_mod26_3x3(columns(p, offsets))?;
+            let key = multiply(columns(c, offsets), p_inverse);
+            (utils::matrix_inverse_mod26_3x3(key).is_some() && apply(&known, key).to_indices() == c)
+                .then_some(key)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::OZYMANDIAS;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        let key = [[5, 17], [4, 15]];
+        let encrypted = encrypt(OZYMANDIAS, key).unwrap();
+        assert_eq!(decrypt(&encrypted, key).unwrap(), normalized);
+
+        assert_eq!(decrypt(&encrypt("odd", key).unwrap(), key).unwrap(), "oddx");
+        assert_eq!(encrypt("abc", [[1, 0], [0, 1]]).unwrap(), "abcx");
+        assert_eq!(encrypt(OZYMANDIAS, [[2, 0], [0, 1]]), None);
+        assert_eq!(decrypt(OZYMANDIAS, [[13, 0], [0, 1]]), None);
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_3x3() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        for key in [
+            [[6, 24, 1], [13, 16, 10], [20, 17, 15]],
+            [[2, 4, 5], [9, 2, 1], [3, 17, 7]],
+            [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+        ] {
+            let encrypted = encrypt_3x3(OZYMANDIAS, key).unwrap();
+            let decrypted = decrypt_3x3(&encrypted, key).unwrap();
+            assert_eq!(decrypted.trim_end_matches('x'), normalized);
+            assert_eq!(decrypted.len() % 3, 0);
+        }
+
+        let identity = [[1, 0, 0], [0, 1, 0], [0, 0, 1]];
+        assert_eq!(encrypt_3x3("abcd", identity).unwrap(), "abcdxx");
+        assert_eq!(
+            encrypt_3x3("abc", [[27, 0, 0], [0, 1, 0], [0, 0, 1]]),
+            encrypt_3x3("abc", identity)
+        );
+        assert_eq!(
+            encrypt_3x3("abc", [[2, 0, 0], [0, 1, 0], [0, 0, 1]]),
+            Err(HillError::NonInvertibleKey(2))
+        );
+        assert_eq!(
+            decrypt_3x3("abc", [[1, 2, 3], [4, 5, 6], [7, 8, 9]]),
+            Err(HillError::NonInvertibleKey(0))
+        );
+    }
+
+    #[test]
+    fn test_solve_known_plaintext() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        let plaintext = &normalized[..40];
+        for key in [[[3, 3], [2, 5]], [[5, 17], [4, 15]], [[1, 2], [3, 5]]] {
+            let encrypted = encrypt(plaintext, key).unwrap();
+            assert_eq!(solve_known_plaintext(&encrypted, plaintext), Some(key));
+        }
+
+        // Every pair with "aa" is singular, as is "bb" with itself, so only
+        // "bb" and "cd" determine the key.
+        let plaintext = "aabbaacd";
+        let key = [[3, 3], [2, 5]];
+        let encrypted = encrypt(plaintext, key).unwrap();
+        assert_eq!(solve_known_plaintext(&encrypted, plaintext), Some(key));
+        assert_eq!(solve_known_plaintext(&encrypted, "aabb"), None);
+    }
+
+    #[test]
+    fn test_solve_known_plaintext_3x3() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        let plaintext = &normalized[..60];
+        for key in [
+            [[6, 24, 1], [13, 16, 10], [20, 17, 15]],
+            [[2, 4, 5], [9, 2, 1], [3, 17, 7]],
+            [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+        ] {
+            let encrypted = encrypt_3x3(plaintext, key).unwrap();
+            assert_eq!(solve_known_plaintext_3x3(&encrypted, plaintext), Some(key));
+        }
+
+        // Three copies of one trigraph never give an invertible matrix.
+        let encrypted = encrypt_3x3("abcabcabc", [[6, 24, 1], [13, 16, 10], [20, 17, 15]]).unwrap();
+        assert_eq!(solve_known_plaintext_3x3(&encrypted, "abcabcabc"), None);
+        assert_eq!(solve_known_plaintext_3x3(&encrypted, "ab"), None);
+    }
+}
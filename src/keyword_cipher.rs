@@ -0,0 +1,207 @@
+use crate::lowercase_string::LowercaseString;
+use crate::ngram::NgramModel;
+use crate::substitution::{self, SubstitutionKey};
+use crate::utils::{self, Rng, SolverConfig, ENGLISH_FREQUENCIES};
+
+const SEED: u64 = 0x5eed;
+
+/// The annealing schedule of [`solve`], apart from the number of steps.
+const DEFAULT_CONFIG: SolverConfig = SolverConfig {
+    initial_temperature: 3.0,
+    cooling_rate: 0.999_85,
+    steps: 50_000,
+    model: None,
+};
+
+/// Encrypts a message using a keyword cipher.
+/// Letter `i` of the standard alphabet maps to letter `i` of the mixed
+/// alphabet built by [`utils::keyword_alphabet`].
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::keyword_cipher;
+///
+/// let encrypted = keyword_cipher::encrypt("hello world", "zebras");
+/// assert_eq!(encrypted, "daiilvloir");
+/// ```
+#[must_use]
+pub fn encrypt(text: &str, keyword: &str) -> String {
+    substitution::encrypt(text, &utils::keyword_alphabet_permutation(keyword))
+}
+
+/// Decrypts a message using a keyword cipher.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::keyword_cipher;
+///
+/// let decrypted = keyword_cipher::decrypt("daiilvloir", "zebras");
+/// assert_eq!(decrypted, "helloworld");
+/// ```
+#[must_use]
+pub fn decrypt(text: &str, keyword: &str) -> String {
+    substitution::decrypt(text, &utils::keyword_alphabet_permutation(keyword))
+}
+
+/// Maps the ciphertext letters, from most to least frequent, onto the
+/// English letters in the same order.
+#[allow(clippy::cast_possible_truncation)]
+fn frequency_guess(text: &LowercaseString) -> SubstitutionKey {
+    let counts = text.letter_counts();
+    let mut cipher_order: Vec<usize> = (0..26).collect();
+    cipher_order.sort_by(|&a, &b| counts[b].cmp(&counts[a]));
+    let mut english_order: Vec<usize> = (0..26).collect();
+    english_order.sort_by(|&a, &b| ENGLISH_FREQUENCIES[b].total_cmp(&ENGLISH_FREQUENCIES[a]));
+
+    let mut alphabet = [0; 26];
+    for (&plain, &cipher) in english_order.iter().zip(&cipher_order) {
+        alphabet[plain] = cipher as u8;
+    }
+    SubstitutionKey::from_alphabet(&LowercaseString::from_indices(alphabet.to_vec())).unwrap()
+}
+
+/// Solves a keyword cipher with a default annealing schedule of
+/// `max_attempts` steps. See [`solve_with_config`].
+///
+/// `max_attempts` is the number of candidate alphabets tried; tens of
+/// thousands are usually needed for a few hundred letters of ciphertext.
+///
+/// # Example
+/// ```
+/// use cipher_solver::keyword_cipher;
+///
+/// let text = "It was the best of times, it was the worst of times, it was the age \
+///     of wisdom, it was the age of foolishness, it was the epoch of belief, it was \
+///     the epoch of incredulity, it was the season of light, it was the season of \
+///     darkness, it was the spring of hope, it was the winter of despair";
+/// let encrypted = keyword_cipher::encrypt(text, "dickens");
+/// let (solved, alphabet) = keyword_cipher::solve(&encrypted, 50_000);
+/// assert_eq!(keyword_cipher::decrypt(&encrypted, &alphabet), solved);
+/// ```
+#[must_use]
+pub fn solve(text: &str, max_attempts: u32) -> (String, String) {
+    let config = SolverConfig {
+        steps: max_attempts,
+        ..DEFAULT_CONFIG
+    };
+    solve_with_config(text, &config)
+}
+
+/// Solves a keyword cipher using simulated annealing over the mixed
+/// alphabet, scoring candidates with the model of the config (by default
+/// [`NgramModel::english_quadgrams`]).
+///
+/// Letters that do not occur in the plaintext cannot be recovered.
+/// Returns the plaintext and the recovered mixed alphabet. Only a fully
+/// recovered alphabet is guaranteed to start with the keyword. Otherwise
+/// letters that do not occur can be out of place, even within the keyword.
+#[must_use]
+pub fn solve_with_config(text: &str, config: &SolverConfig) -> (String, String) {
+    let text = LowercaseString::normalize(text);
+    let (n, table) = config.scoring_table();
+    let score = |key: &SubstitutionKey| {
+        let decrypted = text.apply_substitution_inverse(key);
+        NgramModel::table_score(&table, n, decrypted.to_indices().iter().copied())
+    };
+    let mut rng = Rng::new(SEED);
+
+    let mut current = frequency_guess(&text);
+    let mut current_score = score(&current);
+    let mut best = (current_score, current.clone());
+    let mut temperature = config.initial_temperature;
+
+    for _ in 0..config.steps {
+        let mut candidate = current.clone();
+        candidate.swap(rng.below(26), rng.below(26));
+        let candidate_score = score(&candidate);
+
+        let accept = candidate_score < current_score
+            || rng.next_f64() < ((current_score - candidate_score) / temperature).exp();
+        if accept {
+            current = candidate;
+            current_score = candidate_score;
+            if current_score < best.0 {
+                best = (current_score, current.clone());
+            }
+        }
+        temperature *= config.cooling_rate;
+    }
+
+    let key = best.1;
+    (
+        text.apply_substitution_inverse(&key).to_string(),
+        key.to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::caesar;
+    use crate::test_data::OZYMANDIAS;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let original = "The quick brown fox jumps over the lazy dog";
+        let normalized = LowercaseString::normalize(original);
+        let encrypted = encrypt(original, "zebras");
+        let decrypted = decrypt(&encrypted, "zebras");
+        assert_eq!(decrypted, normalized.to_string());
+    }
+
+    #[test]
+    fn test_differs_from_caesar_and_identity() {
+        let original = "The quick brown fox jumps over the lazy dog";
+        let encrypted = encrypt(original, "zebras");
+        for shift in 0..26 {
+            assert_ne!(encrypted, caesar::encrypt(original, shift));
+        }
+
+        let identity = SubstitutionKey::identity();
+        assert_ne!(encrypted, substitution::encrypt(original, &identity));
+        assert_ne!(
+            encrypted,
+            substitution::encrypt(
+                original,
+                &utils::keyword_alphabet_permutation("zebras").inverse()
+            )
+        );
+    }
+
+    #[test]
+    fn test_solve() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        let encrypted = encrypt(OZYMANDIAS, "shelley");
+        let (solved, alphabet) = solve(&encrypted, 50_000);
+
+        let correct = solved
+            .chars()
+            .zip(normalized.chars())
+            .filter(|(a, b)| a == b)
+            .count();
+        assert!(correct * 10 >= normalized.len() * 9);
+        assert_eq!(decrypt(&encrypted, &alphabet), solved);
+    }
+}
+
+#[cfg(test)]
+mod prop_roundtrip {
+    use super::*;
+    use crate::test_data::{random_keyword, random_text};
+
+    #[test]
+    fn encrypt_decrypt() {
+        let mut rng = Rng::new(4);
+        for _ in 0..1000 {
+            let plain = random_text(&mut rng, 50);
+            let keyword = random_keyword(&mut rng, 10);
+            let encrypted = encrypt(&plain, &keyword);
+            assert_eq!(
+                decrypt(&encrypted, &keyword),
+                LowercaseString::normalize(&plain).to_string()
+            );
+        }
+    }
+}
@@ -1,8 +1,34 @@
 #![warn(clippy::pedantic)]
 #![allow(clippy::missing_panics_doc)]
 
+mod docs_quadgrams;
+mod docs_words;
 mod lowercase_string;
-mod utils;
+#[cfg(test)]
+mod test_data;
 
+pub mod affine;
+pub mod atbash;
+pub mod beaufort;
+pub mod bellaso;
 pub mod caesar;
+pub mod caesar_digits;
+pub mod cipher_kind;
+pub mod columnar;
+pub mod double_transposition;
+pub mod frequency_analysis;
+pub mod hill;
+pub mod keyword_cipher;
+pub mod ngram;
+pub mod playfair;
+pub mod polybius;
+/// The Porta cipher, the name under which Bellaso's reciprocal cipher is
+/// usually known. The same functions as [`bellaso`].
+pub use bellaso as porta;
+pub mod rail_fence;
+pub mod substitution;
+pub mod utils;
 pub mod vigenere;
+
+pub use lowercase_string::{LengthMismatch, LowercaseString, NormalizationStats, ParseError};
+pub use utils::display_alignment;
@@ -1,17 +1,205 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
 use std::fmt::{self, Display, Formatter};
 
+use crate::docs_words;
+use crate::ngram::NgramModel;
+use crate::substitution::SubstitutionKey;
+use crate::utils;
+
+/// The index of coincidence of typical English text.
+const ENGLISH_IC: f64 = 0.06553;
+
+/// Counts of the characters kept and discarded by
+/// [`LowercaseString::normalize_with_stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct NormalizationStats {
+    pub letters_kept: usize,
+    pub digits_stripped: usize,
+    pub spaces_stripped: usize,
+    pub punctuation_stripped: usize,
+    /// Non-ASCII characters other than whitespace, including accented and
+    /// non-Latin letters.
+    pub unicode_stripped: usize,
+}
+
+/// An error from [`LowercaseString::from_numeric_string`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// A token is not a number that fits in a byte.
+    InvalidToken(String),
+    /// A number is not a letter index (at least 26).
+    OutOfRange(u8),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidToken(token) => write!(f, "invalid token '{token}'"),
+            Self::OutOfRange(value) => write!(f, "{value} is not below 26"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// An error from [`LowercaseString::diff`]: the two strings have different
+/// lengths.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LengthMismatch {
+    pub left: usize,
+    pub right: usize,
+}
+
+impl Display for LengthMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "strings have different lengths, {} and {}",
+            self.left, self.right
+        )
+    }
+}
+
+impl Error for LengthMismatch {}
+
+fn letter_index(c: char) -> Option<u8> {
+    c.is_ascii_alphabetic()
+        .then(|| (c.to_ascii_lowercase() as u8) - b'a')
+}
+
+/// A string of lowercase ASCII letters, stored as alphabet indices (a = 0).
 #[derive(Debug, Clone, PartialEq)]
 pub struct LowercaseString(Vec<u8>);
 
 impl LowercaseString {
     #[must_use]
     pub fn normalize(s: &str) -> Self {
-        Self(
-            s.chars()
-                .filter(char::is_ascii_alphabetic)
-                .map(|c| (c.to_ascii_lowercase() as u8) - b'a')
-                .collect(),
-        )
+        Self::normalize_with_stats(s).0
+    }
+
+    /// Normalizes a string as [`LowercaseString::normalize`] does, also
+    /// counting what was discarded. No letters kept but some Unicode
+    /// stripped suggests a non-Latin text.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// let (s, stats) = LowercaseString::normalize_with_stats("Café, 3 €!");
+    /// assert_eq!(s.to_string(), "caf");
+    /// assert_eq!(stats.letters_kept, 3);
+    /// assert_eq!(stats.unicode_stripped, 2);
+    /// ```
+    #[must_use]
+    pub fn normalize_with_stats(s: &str) -> (Self, NormalizationStats) {
+        let mut stats = NormalizationStats::default();
+        let mut indices = Vec::with_capacity(s.len());
+        for c in s.chars() {
+            if let Some(index) = letter_index(c) {
+                indices.push(index);
+                stats.letters_kept += 1;
+            } else if c.is_ascii_digit() {
+                stats.digits_stripped += 1;
+            } else if c.is_whitespace() {
+                stats.spaces_stripped += 1;
+            } else if c.is_ascii() {
+                stats.punctuation_stripped += 1;
+            } else {
+                stats.unicode_stripped += 1;
+            }
+        }
+        (Self(indices), stats)
+    }
+
+    /// Normalizes arbitrary bytes, decoding them as UTF-8 and discarding
+    /// invalid sequences along with everything but ASCII letters.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// let s = LowercaseString::from_utf8_lossy_normalized(b"Hi\xff there");
+    /// assert_eq!(s.to_string(), "hithere");
+    /// ```
+    #[must_use]
+    pub fn from_utf8_lossy_normalized(bytes: &[u8]) -> Self {
+        Self::normalize(&String::from_utf8_lossy(bytes))
+    }
+
+    /// Builds a string from any iterator of characters, keeping only ASCII
+    /// letters as [`LowercaseString::normalize`] does.
+    #[must_use]
+    pub fn from_char_iter(iter: impl Iterator<Item = char>) -> Self {
+        let mut s = Self(Vec::new());
+        s.extend(iter);
+        s
+    }
+
+    /// Builds the mixed alphabet of a keyword: the distinct letters of the
+    /// keyword in order of first occurrence, followed by the rest of the
+    /// alphabet in order. The result always has 26 letters.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// let alphabet = LowercaseString::keyword_mixed_alphabet("zebras");
+    /// assert_eq!(alphabet.to_string(), "zebrascdfghijklmnopqtuvwxy");
+    /// assert!(alphabet.is_valid_permutation());
+    /// ```
+    #[must_use]
+    pub fn keyword_mixed_alphabet(keyword: &str) -> Self {
+        let keyword = Self::normalize(keyword);
+        let mut seen = [false; 26];
+        let mut alphabet = Vec::with_capacity(26);
+        for i in keyword.0.iter().copied().chain(0..26) {
+            if !seen[i as usize] {
+                seen[i as usize] = true;
+                alphabet.push(i);
+            }
+        }
+        Self(alphabet)
+    }
+
+    /// Returns whether the string contains each of the 26 letters exactly
+    /// once, as a cipher alphabet must.
+    #[must_use]
+    pub fn is_valid_permutation(&self) -> bool {
+        self.0.len() == 26 && self.letter_counts() == [1; 26]
+    }
+
+    /// Checks that the string is a permutation of the alphabet, as
+    /// [`is_valid_permutation`](Self::is_valid_permutation) does.
+    ///
+    /// # Panics
+    /// Panics if it is not, listing the letters that are missing and those
+    /// that occur more than once.
+    ///
+    /// # Example
+    /// ```should_panic
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// // Panics with "not a permutation of the alphabet: missing "z", duplicated "a""
+    /// LowercaseString::normalize("abcdefghijklmnopqrstuvwxya").assert_permutation();
+    /// ```
+    pub fn assert_permutation(&self) {
+        if self.is_valid_permutation() {
+            return;
+        }
+        let counts = self.letter_counts();
+        let letters = |keep: fn(usize) -> bool| -> String {
+            (b'a'..=b'z')
+                .zip(counts)
+                .filter(|&(_, count)| keep(count))
+                .map(|(letter, _)| char::from(letter))
+                .collect()
+        };
+        panic!(
+            "not a permutation of the alphabet: missing {:?}, duplicated {:?}",
+            letters(|count| count == 0),
+            letters(|count| count > 1)
+        );
     }
 
     #[must_use]
@@ -24,6 +212,54 @@ impl LowercaseString {
         Self(indices.into_iter().map(|i| i.rem_euclid(26)).collect())
     }
 
+    /// Represents each letter as a two-digit number (a = 00, z = 25),
+    /// separated by spaces.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// let text = LowercaseString::normalize("hi z");
+    /// assert_eq!(text.to_numeric_string(), "07 08 25");
+    /// ```
+    #[must_use]
+    pub fn to_numeric_string(&self) -> String {
+        self.0
+            .iter()
+            .map(|i| format!("{i:02}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Parses the format of [`to_numeric_string`](Self::to_numeric_string).
+    /// Tokens may be separated by any whitespace and need not be padded.
+    ///
+    /// # Errors
+    /// Returns [`ParseError::InvalidToken`] if a token is not a number, and
+    /// [`ParseError::OutOfRange`] if a number is not below 26.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::{LowercaseString, ParseError};
+    ///
+    /// let text = LowercaseString::from_numeric_string("07 08 25").unwrap();
+    /// assert_eq!(text.to_string(), "hiz");
+    /// assert_eq!(
+    ///     LowercaseString::from_numeric_string("07 26"),
+    ///     Err(ParseError::OutOfRange(26))
+    /// );
+    /// ```
+    pub fn from_numeric_string(s: &str) -> Result<Self, ParseError> {
+        s.split_whitespace()
+            .map(|token| match token.parse::<u8>() {
+                Ok(value) if value < 26 => Ok(value),
+                Ok(value) => Err(ParseError::OutOfRange(value)),
+                Err(_) => Err(ParseError::InvalidToken(token.to_string())),
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
     #[must_use]
     pub fn letter_counts(&self) -> [usize; 26] {
         let mut counts = [0; 26];
@@ -47,11 +283,719 @@ impl LowercaseString {
         frequencies
     }
 
+    /// Counts the occurrences of the letter with index `letter`.
+    #[must_use]
+    pub fn count_occurrences(&self, letter: u8) -> usize {
+        assert!(letter < 26, "letter index must be below 26");
+        #[allow(clippy::naive_bytecount)]
+        self.0.iter().filter(|&&i| i == letter).count()
+    }
+
+    /// Returns a copy of the string with the letter at `pos` replaced by the
+    /// letter with index `letter`.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// let s = LowercaseString::normalize("cat");
+    /// assert_eq!(s.replace_at(0, 1).to_string(), "bat");
+    /// ```
+    #[must_use]
+    pub fn replace_at(&self, pos: usize, letter: u8) -> Self {
+        let mut s = self.clone();
+        s.set_at(pos, letter);
+        s
+    }
+
+    /// Replaces the letter at `pos` by the letter with index `letter`.
+    pub fn set_at(&mut self, pos: usize, letter: u8) {
+        assert!(letter < 26, "letter index must be below 26");
+        assert!(pos < self.0.len(), "position must be within the string");
+        self.0[pos] = letter;
+    }
+
+    /// Returns the positions at which the letter with index `letter` occurs.
+    #[must_use]
+    pub fn positions_of(&self, letter: u8) -> Vec<usize> {
+        assert!(letter < 26, "letter index must be below 26");
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|&(_, &i)| i == letter)
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    /// Returns the letters present in the text by descending count,
+    /// with ties broken alphabetically.
+    #[must_use]
+    pub fn letters_by_frequency(&self) -> Vec<(char, usize)> {
+        let mut letters: Vec<_> = ('a'..='z')
+            .zip(self.letter_counts())
+            .filter(|&(_, count)| count > 0)
+            .collect();
+        letters.sort_by(|(_, count1), (_, count2)| count2.cmp(count1));
+        letters
+    }
+
+    /// Returns the frequency rank of each letter (0 = most frequent), with
+    /// ties broken alphabetically, so `result[i]` is the rank of letter `i`.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// let ranks = LowercaseString::normalize("banana").frequency_rank();
+    /// assert_eq!(&ranks[..4], [0, 2, 3, 4]);
+    /// assert_eq!(ranks[13], 1);
+    /// ```
+    #[must_use]
+    pub fn frequency_rank(&self) -> [u8; 26] {
+        utils::frequency_ranks(&self.letter_frequencies())
+    }
+
+    #[must_use]
+    pub fn most_common_letter(&self) -> Option<char> {
+        self.letters_by_frequency()
+            .first()
+            .map(|&(letter, _)| letter)
+    }
+
+    /// Guesses the Caesar shift of the text by assuming its most common
+    /// letter is 'e'. Returns the shift in `0..26`, or 0 for an empty text.
+    ///
+    /// This is much cheaper than scoring all 26 shifts, but only reliable on
+    /// long texts. It fails whenever 'e' is not the most common plaintext
+    /// letter: on short texts, texts dominated by a single letter, and
+    /// languages or styles with a different frequency order.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// let text = LowercaseString::normalize("we meet here at seven");
+    /// assert_eq!(text.caesar_shift(3).caesar_frequency_shift(), 3);
+    /// ```
+    #[must_use]
+    pub fn caesar_frequency_shift(&self) -> i32 {
+        self.most_common_letter().map_or(0, |letter| {
+            (i32::from(letter as u8) - i32::from(b'e')).rem_euclid(26)
+        })
+    }
+
+    /// Finds the shift in `0..26` that makes the text most English-like, by
+    /// the chi-squared score of [`caesar_shift`](Self::caesar_shift). For a
+    /// Caesar ciphertext this is the shift that decrypts it.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// let text = LowercaseString::normalize("we meet here at seven");
+    /// let encrypted = text.caesar_shift(3);
+    /// assert_eq!(encrypted.caesar_shift_to_match_english(), 23);
+    /// ```
+    #[must_use]
+    pub fn caesar_shift_to_match_english(&self) -> i32 {
+        (0..26)
+            .map(|shift| {
+                (
+                    utils::chi_squared_english_score(&self.caesar_shift(shift)),
+                    shift,
+                )
+            })
+            .min_by(|(score1, _), (score2, _)| score1.total_cmp(score2))
+            .map(|(_, shift)| shift)
+            .unwrap()
+    }
+
+    #[must_use]
+    pub fn least_common_letter(&self) -> Option<char> {
+        self.letters_by_frequency()
+            .into_iter()
+            .min_by_key(|&(_, count)| count)
+            .map(|(letter, _)| letter)
+    }
+
+    /// Splits the string into consecutive groups of `n` letters.
+    /// The last group may be shorter.
+    #[must_use]
+    pub fn to_groups(&self, n: usize) -> Vec<Self> {
+        assert!(n > 0, "group size must be positive");
+        self.0.chunks(n).map(|group| Self(group.to_vec())).collect()
+    }
+
+    /// Splits the string into consecutive groups of exactly `n` letters,
+    /// dropping any leftover letters at the end.
+    #[must_use]
+    pub fn to_complete_groups(&self, n: usize) -> Vec<Self> {
+        assert!(n > 0, "group size must be positive");
+        self.0
+            .chunks_exact(n)
+            .map(|group| Self(group.to_vec()))
+            .collect()
+    }
+
+    /// Splits the string into `n` interleaved columns, where column `k`
+    /// holds the letters at positions `k`, `k + n`, `k + 2n`, ...
+    #[must_use]
+    pub fn columns(&self, n: usize) -> Vec<Self> {
+        assert!(n > 0, "column count must be positive");
+        (0..n)
+            .map(|k| Self(self.0.iter().skip(k).step_by(n).copied().collect()))
+            .collect()
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn index_of_coincidence(&self) -> f64 {
+        let total = self.0.len();
+        if total < 2 {
+            return 0.0;
+        }
+        let matching: usize = self
+            .letter_counts()
+            .iter()
+            .map(|&n| n * n.saturating_sub(1))
+            .sum();
+        matching as f64 / (total * (total - 1)) as f64
+    }
+
+    /// Returns the [`index_of_coincidence`](Self::index_of_coincidence) of
+    /// each window of `window` letters, starting every `step` letters. A
+    /// steady profile suggests one cipher throughout, while a shift suggests
+    /// the text mixes ciphers, or ciphertext and plaintext. Returns an empty
+    /// `Vec` if the window is longer than the string.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// let s = LowercaseString::normalize("aaaabcde");
+    /// let profile = s.sliding_window_ic(4, 2);
+    /// assert_eq!(profile.len(), 3);
+    /// assert!((profile[0] - 1.0).abs() < 1e-10);
+    /// assert!(profile[2].abs() < 1e-10);
+    /// ```
+    #[must_use]
+    pub fn sliding_window_ic(&self, window: usize, step: usize) -> Vec<f64> {
+        assert!(window > 0 && step > 0, "window and step must be positive");
+        if window > self.0.len() {
+            return Vec::new();
+        }
+        (0..=self.0.len() - window)
+            .step_by(step)
+            .map(|start| Self(self.0[start..start + window].to_vec()).index_of_coincidence())
+            .collect()
+    }
+
+    /// See [`utils::mutual_ic`].
+    #[must_use]
+    pub fn mutual_ic_with(&self, other: &LowercaseString) -> f64 {
+        utils::mutual_ic(self, other)
+    }
+
+    /// Returns how far the index of coincidence is from that of typical
+    /// English, as a quick check of whether a text is monoalphabetic.
+    #[must_use]
+    pub fn ic_distance_to_english(&self) -> f64 {
+        (self.index_of_coincidence() - ENGLISH_IC).abs()
+    }
+
+    /// Returns the Kullback–Leibler divergence of the letter frequencies from
+    /// those of English, using [`utils::kl_divergence`]. Lower scores are
+    /// more English-like, so it can be used as a scorer.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::{caesar, LowercaseString};
+    ///
+    /// let english = LowercaseString::normalize("the quick brown fox jumps over the lazy dog");
+    /// let shifted = english.caesar_shift(7);
+    /// assert!(english.kl_divergence_from_english() < shifted.kl_divergence_from_english());
+    ///
+    /// let encrypted = caesar::encrypt("attack at dawn on the eastern ridge", 9);
+    /// let scorer = LowercaseString::kl_divergence_from_english;
+    /// let solved = caesar::solve_with_scorer(&encrypted, scorer);
+    /// assert_eq!(solved, "attackatdawnontheeasternridge");
+    /// ```
+    #[must_use]
+    pub fn kl_divergence_from_english(&self) -> f64 {
+        utils::kl_divergence(&self.letter_frequencies(), &utils::ENGLISH_FREQUENCIES)
+    }
+
+    /// Counts, for each distance `d` below `max_dist`, the pairs of positions
+    /// `d` apart holding the same letter. `result[0]` is always 0.
+    ///
+    /// In a Vigenère ciphertext the counts peak at multiples of the key
+    /// length.
+    #[must_use]
+    pub fn distance_distribution(&self, max_dist: usize) -> Vec<usize> {
+        (0..max_dist)
+            .map(|d| match d {
+                0 => 0,
+                d => self
+                    .0
+                    .iter()
+                    .zip(&self.0[d.min(self.0.len())..])
+                    .filter(|(a, b)| a == b)
+                    .count(),
+            })
+            .collect()
+    }
+
+    /// Returns the fraction of positions `i` where the letter at `i + lag`
+    /// is the same, or 0 if no position has a letter `lag` further on.
+    ///
+    /// In a Vigenère ciphertext this peaks when `lag` is a multiple of the
+    /// key length, at about the index of coincidence of the plaintext.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// let s = LowercaseString::normalize("abcabd");
+    /// assert!((s.autocorrelation(0) - 1.0).abs() < 1e-10);
+    /// assert!((s.autocorrelation(3) - 2.0 / 3.0).abs() < 1e-10);
+    /// ```
+    #[must_use]
+    pub fn autocorrelation(&self, lag: usize) -> f64 {
+        let positions = self.0.len().saturating_sub(lag);
+        if positions == 0 {
+            return 0.0;
+        }
+        let matching = self
+            .0
+            .iter()
+            .zip(&self.0[lag..])
+            .filter(|(a, b)| a == b)
+            .count();
+        #[allow(clippy::cast_precision_loss)]
+        let fraction = matching as f64 / positions as f64;
+        fraction
+    }
+
+    /// Returns the [`autocorrelation`](Self::autocorrelation) of every lag
+    /// from 0 to `max_lag`.
+    #[must_use]
+    pub fn autocorrelation_profile(&self, max_lag: usize) -> Vec<f64> {
+        (0..=max_lag).map(|lag| self.autocorrelation(lag)).collect()
+    }
+
+    /// Finds the longest string that appears in both strings, preferring the
+    /// earliest in `self` if several are equally long.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// let a = LowercaseString::normalize("abcdef");
+    /// let b = LowercaseString::normalize("xcdey");
+    /// assert_eq!(a.longest_common_substring(&b).to_string(), "cde");
+    /// ```
+    #[must_use]
+    pub fn longest_common_substring(&self, other: &LowercaseString) -> Self {
+        // lengths[j] is the length of the common suffix of the prefixes of
+        // `self` up to the current letter and of `other` up to letter j.
+        let mut lengths = vec![0; other.0.len() + 1];
+        let (mut best_len, mut best_end) = (0, 0);
+        for (i, &a) in self.0.iter().enumerate() {
+            for j in (0..other.0.len()).rev() {
+                lengths[j + 1] = if a == other.0[j] { lengths[j] + 1 } else { 0 };
+                if lengths[j + 1] > best_len {
+                    (best_len, best_end) = (lengths[j + 1], i + 1);
+                }
+            }
+        }
+        Self(self.0[best_end - best_len..best_end].to_vec())
+    }
+
+    /// Returns the length of the longest sequence of letters appearing in
+    /// order, though not necessarily together, in both strings.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// let a = LowercaseString::normalize("abcde");
+    /// let b = LowercaseString::normalize("axcye");
+    /// assert_eq!(a.longest_common_subsequence_len(&b), 3);
+    /// ```
+    #[must_use]
+    pub fn longest_common_subsequence_len(&self, other: &LowercaseString) -> usize {
+        let mut previous = vec![0; other.0.len() + 1];
+        for &a in &self.0 {
+            let mut current = vec![0; other.0.len() + 1];
+            for (j, &b) in other.0.iter().enumerate() {
+                current[j + 1] = if a == b {
+                    previous[j] + 1
+                } else {
+                    previous[j + 1].max(current[j])
+                };
+            }
+            previous = current;
+        }
+        previous[other.0.len()]
+    }
+
+    /// Splits the string at each of the given positions, which must be
+    /// sorted and at most the length of the string, returning one more
+    /// segment than there are positions.
+    #[must_use]
+    pub fn split_at_indices(&self, positions: &[usize]) -> Vec<Self> {
+        assert!(
+            positions.windows(2).all(|pair| pair[0] <= pair[1]),
+            "positions must be sorted"
+        );
+        assert!(
+            positions.last().is_none_or(|&last| last <= self.0.len()),
+            "positions must be within the string"
+        );
+        let mut segments = Vec::with_capacity(positions.len() + 1);
+        let mut start = 0;
+        for &end in positions.iter().chain([&self.0.len()]) {
+            segments.push(Self(self.0[start..end].to_vec()));
+            start = end;
+        }
+        segments
+    }
+
+    /// Concatenates strings, the inverse of
+    /// [`LowercaseString::split_at_indices`].
+    #[must_use]
+    pub fn join(parts: &[Self]) -> Self {
+        Self(
+            parts
+                .iter()
+                .flat_map(|part| part.0.iter().copied())
+                .collect(),
+        )
+    }
+
+    /// Checks whether the string is one of the 10,000 most common words in
+    /// the prose of the Rust documentation, for example to see whether a
+    /// recovered key is a word.
+    #[must_use]
+    pub fn appears_in_english_word_list(&self) -> bool {
+        docs_words::docs_words_sorted()
+            .binary_search(&self.to_string().as_str())
+            .is_ok()
+    }
+
+    /// Returns the fraction of letters covered when the string is split
+    /// greedily into the longest words from the same list as
+    /// [`LowercaseString::appears_in_english_word_list`], skipping a letter
+    /// wherever no word starts. Returns 0 for an empty string.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// let text = LowercaseString::normalize("this is the value xqz");
+    /// assert!((text.english_word_coverage() - 14.0 / 17.0).abs() < 1e-10);
+    /// ```
+    #[must_use]
+    pub fn english_word_coverage(&self) -> f64 {
+        let words = docs_words::docs_words_sorted();
+        self.greedy_word_coverage(docs_words::longest_docs_word_len(), |word| {
+            words.binary_search(&word).is_ok()
+        })
+    }
+
+    /// Splits the string greedily into the longest words accepted by
+    /// `is_word`, skipping a letter wherever no word starts, and returns the
+    /// fraction of letters covered.
+    fn greedy_word_coverage(&self, max_word_len: usize, is_word: impl Fn(&str) -> bool) -> f64 {
+        let text = self.to_string();
+        let mut covered = 0;
+        let mut start = 0;
+        while start < text.len() {
+            let longest = (1..=max_word_len.min(text.len() - start))
+                .rev()
+                .find(|&len| is_word(&text[start..start + len]));
+            if let Some(len) = longest {
+                covered += len;
+                start += len;
+            } else {
+                start += 1;
+            }
+        }
+        #[allow(clippy::cast_precision_loss)]
+        if text.is_empty() {
+            0.0
+        } else {
+            covered as f64 / text.len() as f64
+        }
+    }
+
+    /// Splits the whole string into lowercase words from `wordlist`,
+    /// preferring the longest word at each position. Returns an empty `Vec`
+    /// if the string cannot be split into words, or is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// let text = LowercaseString::normalize("the quick");
+    /// assert_eq!(text.to_words(&["the", "quick", "brown"]), ["the", "quick"]);
+    /// assert!(text.to_words(&["the", "brown"]).is_empty());
+    /// ```
+    #[must_use]
+    pub fn to_words<'a>(&self, wordlist: &[&'a str]) -> Vec<&'a str> {
+        let text = self.to_string();
+        let len = text.len();
+        let fits = |start: usize, word: &str| !word.is_empty() && text[start..].starts_with(word);
+        // Whether the text from each position onwards splits into words, so
+        // a longer word is only taken if the rest can still be split.
+        let mut splits = vec![false; len + 1];
+        splits[len] = true;
+        for start in (0..len).rev() {
+            splits[start] = wordlist
+                .iter()
+                .any(|word| fits(start, word) && splits[start + word.len()]);
+        }
+        if len == 0 || !splits[0] {
+            return Vec::new();
+        }
+
+        let mut words = Vec::new();
+        let mut start = 0;
+        while start < len {
+            let word = wordlist
+                .iter()
+                .filter(|word| fits(start, word) && splits[start + word.len()])
+                .max_by_key(|word| word.len())
+                .unwrap();
+            words.push(*word);
+            start += word.len();
+        }
+        words
+    }
+
+    /// Returns the fraction of letters covered when the string is split
+    /// greedily into the longest words from `wordlist`, as
+    /// [`LowercaseString::english_word_coverage`] does for its own list.
+    /// Returns 0 for an empty string.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// let text = LowercaseString::normalize("the quick fox");
+    /// assert!((text.to_words_coverage(&["the", "quick"]) - 8.0 / 11.0).abs() < 1e-10);
+    /// ```
+    #[must_use]
+    pub fn to_words_coverage(&self, wordlist: &[&str]) -> f64 {
+        let words: HashSet<_> = wordlist.iter().copied().collect();
+        let max_word_len = wordlist.iter().map(|word| word.len()).max().unwrap_or(0);
+        self.greedy_word_coverage(max_word_len, |word| words.contains(word))
+    }
+
+    /// Interprets the letters as the digits of a base-26 number, most
+    /// significant first, with a = 0. Returns `None` on overflow.
+    #[must_use]
+    pub fn to_base26_number(&self) -> Option<u64> {
+        self.0.iter().try_fold(0u64, |n, &digit| {
+            n.checked_mul(26)?.checked_add(u64::from(digit))
+        })
+    }
+
+    /// Writes `n` in base 26 with a = 0, left-padded with `a` to at least
+    /// `width` letters.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_base26_number(mut n: u64, width: usize) -> Self {
+        let mut digits = Vec::new();
+        while n > 0 {
+            digits.push((n % 26) as u8);
+            n /= 26;
+        }
+        digits.resize(digits.len().max(width), 0);
+        digits.reverse();
+        Self(digits)
+    }
+
+    /// Finds every substring at least `min_len` letters long that occurs
+    /// more than once, mapped to all of its start positions in ascending
+    /// order. A repeat also counts each of its longer extensions that
+    /// repeat, so "abcd" occurring twice gives both "abc" and "abcd" for a
+    /// `min_len` of 3. A `min_len` of 0 is treated as 1.
+    ///
+    /// Each length is searched with a Rabin–Karp rolling hash, from
+    /// `min_len` up to the first length with no repeats, since no longer
+    /// substring can repeat after that. This takes `O(n·k)` expected time
+    /// for a longest repeat of `k` letters, rather than comparing every pair
+    /// of positions.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// let text = LowercaseString::normalize("abcdxabcd");
+    /// let repeats = text.repeated_substrings(3);
+    /// assert_eq!(repeats.len(), 3);
+    /// assert_eq!(repeats[&vec![0, 1, 2, 3]], [0, 5]);
+    /// ```
+    #[must_use]
+    pub fn repeated_substrings(&self, min_len: usize) -> HashMap<Vec<u8>, Vec<usize>> {
+        let mut repeats = HashMap::new();
+        for len in min_len.max(1)..=self.0.len() {
+            let of_len = self.repeated_substrings_of_len(len);
+            if of_len.is_empty() {
+                break;
+            }
+            repeats.extend(of_len);
+        }
+        repeats
+    }
+
+    /// Finds every substring of exactly `len` letters that occurs more than
+    /// once, as [`LowercaseString::repeated_substrings`] does for each
+    /// length.
+    pub(crate) fn repeated_substrings_of_len(&self, len: usize) -> HashMap<Vec<u8>, Vec<usize>> {
+        const BASE: u64 = 26;
+        let mut repeats = HashMap::new();
+        if len == 0 || len > self.0.len() {
+            return repeats;
+        }
+
+        let high = BASE.wrapping_pow(u32::try_from(len - 1).unwrap_or(u32::MAX));
+        let mut hash = self.0[..len].iter().fold(0u64, |h, &c| {
+            h.wrapping_mul(BASE).wrapping_add(u64::from(c))
+        });
+        let mut buckets: HashMap<u64, Vec<usize>> = HashMap::new();
+        buckets.entry(hash).or_default().push(0);
+        for start in 1..=self.0.len() - len {
+            let outgoing = u64::from(self.0[start - 1]).wrapping_mul(high);
+            let incoming = u64::from(self.0[start + len - 1]);
+            hash = hash
+                .wrapping_sub(outgoing)
+                .wrapping_mul(BASE)
+                .wrapping_add(incoming);
+            buckets.entry(hash).or_default().push(start);
+        }
+
+        for positions in buckets.into_values().filter(|p| p.len() > 1) {
+            // Positions sharing a hash may still differ after a collision.
+            let mut by_substring: HashMap<&[u8], Vec<usize>> = HashMap::new();
+            for start in positions {
+                by_substring
+                    .entry(&self.0[start..start + len])
+                    .or_default()
+                    .push(start);
+            }
+            repeats.extend(
+                by_substring
+                    .into_iter()
+                    .filter(|(_, p)| p.len() > 1)
+                    .map(|(substring, p)| (substring.to_vec(), p)),
+            );
+        }
+        repeats
+    }
+
     #[must_use]
     pub fn caesar_shift(&self, shift: i32) -> Self {
-        #[allow(clippy::cast_possible_truncation)]
-        let shift = shift.rem_euclid(26) as u8;
-        Self(self.0.iter().map(|&i| (i + shift) % 26).collect())
+        self.shift_columns(&[shift])
+    }
+
+    /// Shifts the letter at each position `i` by `key[i % key.len()]`, a
+    /// Vigenère encryption with the key given as shifts.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// let text = LowercaseString::normalize("hello world");
+    /// assert_eq!(text.shift_columns(&[10, 4, 24]).to_string(), "rijvsuyvjn");
+    /// assert_eq!(text.shift_columns(&[3, 3]), text.caesar_shift(3));
+    /// ```
+    #[must_use]
+    pub fn shift_columns(&self, key: &[i32]) -> Self {
+        assert!(!key.is_empty(), "key must not be empty");
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let shifts: Vec<_> = key
+            .iter()
+            .map(|&shift| shift.rem_euclid(26) as u8)
+            .collect();
+        Self(
+            self.0
+                .iter()
+                .zip(shifts.iter().cycle())
+                .map(|(&i, &shift)| (i + shift) % 26)
+                .collect(),
+        )
+    }
+
+    /// Subtracts the letters of `other` from the letters at the same
+    /// positions, modulo 26. For a Vigenère ciphertext and its plaintext
+    /// this recovers the key, repeated to the length of the text.
+    ///
+    /// # Errors
+    /// Returns [`LengthMismatch`] if the strings have different lengths.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::{vigenere, LowercaseString};
+    ///
+    /// let plaintext = LowercaseString::normalize("attack at dawn");
+    /// let key = LowercaseString::normalize("lemon");
+    /// let encrypted = vigenere::encrypt_ls(&plaintext, &key);
+    /// assert_eq!(encrypted.diff(&plaintext).unwrap().to_string(), "lemonlemonle");
+    /// ```
+    pub fn diff(&self, other: &LowercaseString) -> Result<Self, LengthMismatch> {
+        if self.0.len() != other.0.len() {
+            return Err(LengthMismatch {
+                left: self.0.len(),
+                right: other.0.len(),
+            });
+        }
+        Ok(Self(
+            self.0
+                .iter()
+                .zip(&other.0)
+                .map(|(&a, &b)| (a + 26 - b) % 26)
+                .collect(),
+        ))
+    }
+
+    /// Sums the probabilities under `model` of the overlapping n-grams of
+    /// the text. Unlike [`NgramModel::score`], higher scores are better, and
+    /// longer texts score higher.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::ngram::NgramModel;
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// let model = NgramModel::english_bigrams();
+    /// let english = LowercaseString::normalize("there");
+    /// let gibberish = LowercaseString::normalize("qzjxv");
+    /// assert!(english.score_with_model(&model) > gibberish.score_with_model(&model));
+    /// ```
+    #[must_use]
+    pub fn score_with_model(&self, model: &NgramModel) -> f64 {
+        if self.0.len() < model.n() {
+            return 0.0;
+        }
+        self.0
+            .windows(model.n())
+            .map(|ngram| model.probability(ngram))
+            .sum()
+    }
+
+    /// Maps each letter `i` to `key[i]`.
+    #[must_use]
+    pub fn apply_substitution(&self, key: &SubstitutionKey) -> Self {
+        Self(self.0.iter().map(|&i| key[i as usize]).collect())
+    }
+
+    /// Maps each letter through the inverse of `key`, undoing
+    /// [`apply_substitution`](Self::apply_substitution).
+    #[must_use]
+    pub fn apply_substitution_inverse(&self, key: &SubstitutionKey) -> Self {
+        self.apply_substitution(&key.inverse())
     }
 }
 
@@ -64,9 +1008,17 @@ impl Display for LowercaseString {
     }
 }
 
+impl Extend<char> for LowercaseString {
+    fn extend<T: IntoIterator<Item = char>>(&mut self, iter: T) {
+        self.0.extend(iter.into_iter().filter_map(letter_index));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_data::OZYMANDIAS;
+    use crate::{caesar, vigenere};
 
     #[test]
     fn test_lowercase_string_normalize() {
@@ -75,6 +1027,70 @@ mod tests {
         assert_eq!(LowercaseString::normalize("").to_string(), "");
     }
 
+    #[test]
+    fn test_numeric_string() {
+        let text = LowercaseString::normalize("The quick brown fox");
+        let numeric = text.to_numeric_string();
+        assert!(numeric.starts_with("19 07 04 16"));
+        assert_eq!(LowercaseString::from_numeric_string(&numeric), Ok(text));
+
+        let empty = LowercaseString::normalize("");
+        assert_eq!(empty.to_numeric_string(), "");
+        assert_eq!(LowercaseString::from_numeric_string(""), Ok(empty));
+        assert_eq!(
+            LowercaseString::from_numeric_string(" 1\n2  3 ")
+                .unwrap()
+                .to_string(),
+            "bcd"
+        );
+
+        for (input, error) in [
+            ("01 x2", ParseError::InvalidToken("x2".to_string())),
+            ("-1", ParseError::InvalidToken("-1".to_string())),
+            ("300", ParseError::InvalidToken("300".to_string())),
+            ("00 99", ParseError::OutOfRange(99)),
+        ] {
+            assert_eq!(LowercaseString::from_numeric_string(input), Err(error));
+        }
+    }
+
+    #[test]
+    fn test_from_utf8_lossy_normalized() {
+        let valid = "Grüße, World!".as_bytes();
+        assert_eq!(
+            LowercaseString::from_utf8_lossy_normalized(valid).to_string(),
+            "greworld"
+        );
+        let invalid = [0xff, 0xfe, 0xc3, 0x28, 0x80];
+        assert_eq!(
+            LowercaseString::from_utf8_lossy_normalized(&invalid).to_string(),
+            ""
+        );
+        let mixed = b"ab\xffcd\xe2\x82ef 12";
+        assert_eq!(
+            LowercaseString::from_utf8_lossy_normalized(mixed).to_string(),
+            "abcdef"
+        );
+    }
+
+    #[test]
+    fn test_score_with_model() {
+        let model = NgramModel::load(&[(vec![0, 1], 1.0)]);
+        let matching = LowercaseString::normalize("ab");
+        let other = LowercaseString::normalize("ba");
+        assert!((matching.score_with_model(&model) - 1.0).abs() < 1e-10);
+        assert!(matching.score_with_model(&model) > other.score_with_model(&model));
+
+        let repeated = LowercaseString::normalize("abab");
+        assert!((repeated.score_with_model(&model) - 2.01).abs() < 1e-10);
+        assert!(
+            LowercaseString::normalize("a")
+                .score_with_model(&model)
+                .abs()
+                < 1e-10
+        );
+    }
+
     #[test]
     fn test_to_indices() {
         assert_eq!(
@@ -134,6 +1150,488 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_letters_by_frequency() {
+        assert_eq!(
+            LowercaseString::normalize("eeeabc").letters_by_frequency(),
+            vec![('e', 3), ('a', 1), ('b', 1), ('c', 1)]
+        );
+        assert_eq!(
+            LowercaseString::normalize("").letters_by_frequency(),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_most_least_common_letter() {
+        let text = LowercaseString::normalize("eeeabbc");
+        assert_eq!(text.most_common_letter(), Some('e'));
+        assert_eq!(text.least_common_letter(), Some('a'));
+
+        let empty = LowercaseString::normalize("");
+        assert_eq!(empty.most_common_letter(), None);
+        assert_eq!(empty.least_common_letter(), None);
+    }
+
+    #[test]
+    fn test_to_groups() {
+        let text = LowercaseString::normalize("abcdefg");
+        let groups: Vec<_> = text.to_groups(3).iter().map(ToString::to_string).collect();
+        assert_eq!(groups, vec!["abc", "def", "g"]);
+        let groups: Vec<_> = text
+            .to_complete_groups(3)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(groups, vec!["abc", "def"]);
+        assert!(LowercaseString::normalize("").to_groups(3).is_empty());
+    }
+
+    #[test]
+    fn test_to_groups_concatenation() {
+        let text = LowercaseString::normalize("The quick brown fox jumps over the lazy dog");
+        for n in 1..=40 {
+            let concatenated: String = text.to_groups(n).iter().map(ToString::to_string).collect();
+            assert_eq!(concatenated, text.to_string());
+            assert!(text
+                .to_complete_groups(n)
+                .iter()
+                .all(|g| g.to_indices().len() == n));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "group size must be positive")]
+    fn test_to_groups_zero() {
+        let _ = LowercaseString::normalize("abc").to_groups(0);
+    }
+
+    #[test]
+    fn test_columns() {
+        let text = LowercaseString::normalize("abcdefg");
+        let columns: Vec<_> = text.columns(3).iter().map(ToString::to_string).collect();
+        assert_eq!(columns, vec!["adg", "be", "cf"]);
+        let columns: Vec<_> = text.columns(1).iter().map(ToString::to_string).collect();
+        assert_eq!(columns, vec!["abcdefg"]);
+    }
+
+    #[test]
+    fn test_index_of_coincidence() {
+        let ic = LowercaseString::normalize("aabb").index_of_coincidence();
+        assert!((ic - 4.0 / 12.0).abs() < 1e-10);
+        let ic = LowercaseString::normalize("aaaa").index_of_coincidence();
+        assert!((ic - 1.0).abs() < 1e-10);
+        let ic = LowercaseString::normalize("a").index_of_coincidence();
+        assert!(ic.abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_sliding_window_ic() {
+        let english = LowercaseString::normalize(OZYMANDIAS);
+        let len = english.to_indices().len();
+        let mut rng = utils::Rng::new(8);
+        let random = (0..len).map(|_| u8::try_from(rng.below(26)).unwrap());
+        let mixed = LowercaseString::from_indices(
+            english.to_indices().iter().copied().chain(random).collect(),
+        );
+
+        let profile = mixed.sliding_window_ic(100, 50);
+        assert_eq!(profile.len(), (2 * len - 100) / 50 + 1);
+        let (last_english, first_random) = ((len - 100) / 50, len.div_ceil(50));
+        assert!(profile[..=last_english].iter().all(|&ic| ic > 0.05));
+        assert!(profile[first_random..].iter().all(|&ic| ic < 0.045));
+
+        assert_eq!(english.sliding_window_ic(len, 1).len(), 1);
+        assert!(english.sliding_window_ic(len + 1, 1).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "window and step must be positive")]
+    fn test_sliding_window_ic_zero_step() {
+        let _ = LowercaseString::normalize("abc").sliding_window_ic(2, 0);
+    }
+
+    #[test]
+    fn test_to_base26_number() {
+        assert_eq!(
+            LowercaseString::normalize("abc").to_base26_number(),
+            Some(28)
+        );
+        assert_eq!(
+            LowercaseString::normalize("ba").to_base26_number(),
+            Some(26)
+        );
+        assert_eq!(LowercaseString::normalize("").to_base26_number(), Some(0));
+        assert_eq!(
+            LowercaseString::normalize("zzzzzzzzzzzzz").to_base26_number(),
+            Some(26u64.pow(13) - 1)
+        );
+        assert_eq!(
+            LowercaseString::normalize("zzzzzzzzzzzzzz").to_base26_number(),
+            None
+        );
+        assert_eq!(
+            LowercaseString::normalize("aaaaaaaaaaaaaaaaaaaaz").to_base26_number(),
+            Some(25)
+        );
+    }
+
+    #[test]
+    fn test_from_base26_number() {
+        assert_eq!(
+            LowercaseString::from_base26_number(28, 3).to_string(),
+            "abc"
+        );
+        assert_eq!(LowercaseString::from_base26_number(28, 0).to_string(), "bc");
+        assert_eq!(LowercaseString::from_base26_number(0, 2).to_string(), "aa");
+        assert_eq!(LowercaseString::from_base26_number(0, 0).to_string(), "");
+        for n in 0..2000 {
+            let text = LowercaseString::from_base26_number(n, 4);
+            assert_eq!(text.to_indices().len(), 4);
+            assert_eq!(text.to_base26_number(), Some(n));
+        }
+        let max = LowercaseString::from_base26_number(u64::MAX, 0);
+        assert_eq!(max.to_base26_number(), Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_repeated_substrings() {
+        let text = LowercaseString::normalize("abcxyzabcqqabcxyz");
+        let repeats = text.repeated_substrings(3);
+        assert_eq!(repeats.get(&vec![0, 1, 2]), Some(&vec![0, 6, 11]));
+        assert_eq!(repeats.get(&vec![23, 24, 25]), Some(&vec![3, 14]));
+        assert_eq!(repeats.get(&vec![16, 16, 0]), None);
+
+        // Longer repeats are found without asking for their length.
+        assert_eq!(repeats.get(&vec![0, 1, 2, 23, 24, 25]), Some(&vec![0, 11]));
+
+        let repeats = text.repeated_substrings(6);
+        assert_eq!(repeats.len(), 1);
+        assert_eq!(repeats.get(&vec![0, 1, 2, 23, 24, 25]), Some(&vec![0, 11]));
+
+        assert_eq!(text.repeated_substrings(0), text.repeated_substrings(1));
+        assert!(text.repeated_substrings(100).is_empty());
+        assert!(text.repeated_substrings_of_len(0).is_empty());
+        assert_eq!(
+            text.repeated_substrings_of_len(6),
+            text.repeated_substrings(6)
+        );
+    }
+
+    #[test]
+    fn test_repeated_substrings_matches_naive() {
+        let text = LowercaseString::normalize(crate::test_data::OZYMANDIAS);
+        let indices = text.to_indices();
+        for min_len in 1..6 {
+            let mut naive: HashMap<Vec<u8>, Vec<usize>> = HashMap::new();
+            for len in min_len..=indices.len() {
+                for (start, window) in indices.windows(len).enumerate() {
+                    naive.entry(window.to_vec()).or_default().push(start);
+                }
+            }
+            naive.retain(|_, positions| positions.len() > 1);
+            assert_eq!(text.repeated_substrings(min_len), naive);
+        }
+    }
+
+    #[test]
+    fn test_normalize_with_stats() {
+        let (s, stats) = LowercaseString::normalize_with_stats("Héllo, Wörld 42!\tПривет");
+        assert_eq!(s, LowercaseString::normalize("Héllo, Wörld 42!\tПривет"));
+        assert_eq!(s.to_string(), "hllowrld");
+        assert_eq!(
+            stats,
+            NormalizationStats {
+                letters_kept: 8,
+                digits_stripped: 2,
+                spaces_stripped: 3,
+                punctuation_stripped: 2,
+                unicode_stripped: 8,
+            }
+        );
+
+        let (s, stats) = LowercaseString::normalize_with_stats("Привет мир");
+        assert_eq!(s.to_string(), "");
+        assert_eq!(stats.letters_kept, 0);
+        assert_eq!(stats.unicode_stripped, 9);
+    }
+
+    #[test]
+    fn test_from_char_iter() {
+        assert_eq!(
+            LowercaseString::from_char_iter("Hello World 123".chars()),
+            LowercaseString::normalize("Hello World 123")
+        );
+
+        let mut s = LowercaseString::normalize("Hello");
+        s.extend(" World!".chars());
+        s.extend(['1', 'X']);
+        assert_eq!(s.to_string(), "helloworldx");
+    }
+
+    #[test]
+    fn test_count_occurrences() {
+        let s = LowercaseString::normalize("Hello World");
+        assert_eq!(s.count_occurrences(11), 3);
+        assert_eq!(s.count_occurrences(25), 0);
+        assert_eq!(s.positions_of(11), vec![2, 3, 8]);
+        assert_eq!(s.positions_of(25), Vec::<usize>::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "letter index must be below 26")]
+    fn test_count_occurrences_invalid_letter() {
+        let _ = LowercaseString::normalize("hello").count_occurrences(26);
+    }
+
+    #[test]
+    fn test_mutual_ic_with() {
+        let a = LowercaseString::normalize(&"a".repeat(50));
+        assert!((a.index_of_coincidence() - 1.0).abs() < 1e-10);
+        assert!((a.mutual_ic_with(&a) - 1.0).abs() < 1e-10);
+        assert!((a.ic_distance_to_english() - (1.0 - ENGLISH_IC)).abs() < 1e-10);
+
+        let uniform = LowercaseString::normalize(&"abcdefghijklmnopqrstuvwxyz".repeat(100));
+        assert!((uniform.index_of_coincidence() - 1.0 / 26.0).abs() < 1e-3);
+        assert!((uniform.mutual_ic_with(&a) - 1.0 / 26.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_distance_distribution() {
+        let s = LowercaseString::normalize("abcabd");
+        assert_eq!(s.distance_distribution(8), vec![0, 0, 0, 2, 0, 0, 0, 0]);
+
+        let encrypted = vigenere::encrypt(OZYMANDIAS, "lips").unwrap();
+        let distribution = LowercaseString::normalize(&encrypted).distance_distribution(18);
+        for d in [4, 8, 12, 16] {
+            assert!(distribution[d] * 10 > distribution[d - 1] * 13);
+            assert!(distribution[d] * 10 > distribution[d + 1] * 13);
+        }
+    }
+
+    #[test]
+    fn test_autocorrelation() {
+        let text = LowercaseString::normalize(OZYMANDIAS);
+        assert!((text.autocorrelation(0) - 1.0).abs() < 1e-10);
+        assert!(LowercaseString::normalize("abc").autocorrelation(3).abs() < 1e-10);
+        assert!(LowercaseString::normalize("").autocorrelation(0).abs() < 1e-10);
+
+        let encrypted = LowercaseString::normalize(&vigenere::encrypt(OZYMANDIAS, "lips").unwrap());
+        let profile = encrypted.autocorrelation_profile(9);
+        assert_eq!(profile.len(), 10);
+        assert!((profile[0] - 1.0).abs() < 1e-10);
+        for k in [4, 8] {
+            assert!(profile[k] > 1.3 * profile[k - 1]);
+            assert!(profile[k] > 1.3 * profile[k + 1]);
+        }
+    }
+
+    #[test]
+    fn test_longest_common_substring() {
+        let lcs = |a: &str, b: &str| {
+            LowercaseString::normalize(a)
+                .longest_common_substring(&LowercaseString::normalize(b))
+                .to_string()
+        };
+        assert_eq!(lcs("abcdef", "cde"), "cde");
+        assert_eq!(lcs("abc", "xyz"), "");
+        assert_eq!(lcs("", "abc"), "");
+        assert_eq!(lcs("abxcd", "cdxab"), "ab");
+        assert_eq!(lcs("the colossal wreck", "a colossal statue"), "colossal");
+    }
+
+    #[test]
+    fn test_longest_common_subsequence_len() {
+        let lcs_len = |a: &str, b: &str| {
+            LowercaseString::normalize(a)
+                .longest_common_subsequence_len(&LowercaseString::normalize(b))
+        };
+        assert_eq!(lcs_len("abcdef", "cde"), 3);
+        assert_eq!(lcs_len("abc", "xyz"), 0);
+        assert_eq!(lcs_len("", "abc"), 0);
+        assert_eq!(lcs_len("abcbdab", "bdcaba"), 4);
+    }
+
+    #[test]
+    fn test_split_at_indices() {
+        let s = LowercaseString::normalize("abcdefgh");
+        let parts: Vec<_> = s
+            .split_at_indices(&[2, 2, 5])
+            .iter()
+            .map(LowercaseString::to_string)
+            .collect();
+        assert_eq!(parts, ["ab", "", "cde", "fgh"]);
+        assert_eq!(s.split_at_indices(&[]), vec![s.clone()]);
+        assert_eq!(LowercaseString::join(&[]), LowercaseString::normalize(""));
+    }
+
+    #[test]
+    #[should_panic(expected = "positions must be sorted")]
+    fn test_split_at_indices_unsorted() {
+        let _ = LowercaseString::normalize("abcdefgh").split_at_indices(&[5, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "positions must be within the string")]
+    fn test_split_at_indices_out_of_bounds() {
+        let _ = LowercaseString::normalize("abcdefgh").split_at_indices(&[2, 9]);
+    }
+
+    #[test]
+    fn test_english_word_list() {
+        assert!(LowercaseString::normalize("Value").appears_in_english_word_list());
+        assert!(!LowercaseString::normalize("xqz").appears_in_english_word_list());
+        assert!(!LowercaseString::normalize("").appears_in_english_word_list());
+
+        let english =
+            LowercaseString::normalize("The value of this type is used when the function returns");
+        assert!((english.english_word_coverage() - 1.0).abs() < 1e-10);
+        let encrypted = LowercaseString::normalize(&vigenere::encrypt(OZYMANDIAS, "key").unwrap());
+        assert!(encrypted.english_word_coverage() < 0.5);
+        assert!(LowercaseString::normalize(OZYMANDIAS).english_word_coverage() > 0.8);
+        assert!(LowercaseString::normalize("").english_word_coverage().abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_to_words() {
+        let wordlist = ["the", "quick", "brown"];
+        let text = LowercaseString::normalize("thequick");
+        assert_eq!(text.to_words(&wordlist), ["the", "quick"]);
+        assert!(LowercaseString::normalize("thequack")
+            .to_words(&wordlist)
+            .is_empty());
+        assert!(LowercaseString::normalize("")
+            .to_words(&wordlist)
+            .is_empty());
+
+        // The longest word is taken only where the rest still splits.
+        let wordlist = ["a", "ab", "abc", "cd"];
+        let words = LowercaseString::normalize("abcab").to_words(&wordlist);
+        assert_eq!(words, ["abc", "ab"]);
+        let words = LowercaseString::normalize("abcd").to_words(&wordlist);
+        assert_eq!(words, ["ab", "cd"]);
+    }
+
+    #[test]
+    fn test_to_words_coverage() {
+        let text = LowercaseString::normalize("the quick brown fox");
+        assert!((text.to_words_coverage(&["the", "quick", "brown", "fox"]) - 1.0).abs() < 1e-10);
+        assert!((text.to_words_coverage(&["quick"]) - 5.0 / 16.0).abs() < 1e-10);
+        assert!(text.to_words_coverage(&[]).abs() < 1e-10);
+        assert!(
+            LowercaseString::normalize("")
+                .to_words_coverage(&["a"])
+                .abs()
+                < 1e-10
+        );
+
+        let english = LowercaseString::normalize(OZYMANDIAS);
+        let coverage = english.to_words_coverage(docs_words::docs_words_sorted());
+        assert!((coverage - english.english_word_coverage()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_caesar_frequency_shift() {
+        let text = LowercaseString::normalize(OZYMANDIAS);
+        for shift in [0, 1, 13, 25] {
+            assert_eq!(text.caesar_shift(shift).caesar_frequency_shift(), shift);
+        }
+
+        let rare = LowercaseString::normalize("zzz buzz jazz");
+        assert_eq!(rare.caesar_frequency_shift(), 21);
+        assert_eq!(LowercaseString::normalize("").caesar_frequency_shift(), 0);
+    }
+
+    #[test]
+    fn test_replace_at() {
+        let s = LowercaseString::normalize("hello");
+        assert_eq!(s.replace_at(0, 9).to_string(), "jello");
+        assert_eq!(s.replace_at(4, 25).to_string(), "hellz");
+        for (i, &letter) in s.to_indices().iter().enumerate() {
+            assert_eq!(s.replace_at(i, letter), s);
+        }
+
+        let mut t = s.clone();
+        t.set_at(1, 0);
+        t.set_at(1, 4);
+        assert_eq!(t, s);
+        t.set_at(2, 11);
+        assert_eq!(t, s);
+    }
+
+    #[test]
+    #[should_panic(expected = "position must be within the string")]
+    fn test_replace_at_out_of_bounds() {
+        let _ = LowercaseString::normalize("hello").replace_at(5, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "letter index must be below 26")]
+    fn test_set_at_invalid_letter() {
+        LowercaseString::normalize("hello").set_at(0, 26);
+    }
+
+    #[test]
+    fn test_caesar_shift_to_match_english() {
+        let text = LowercaseString::normalize(OZYMANDIAS);
+        for shift in [0, 5, 25] {
+            let encrypted = text.caesar_shift(shift);
+            let decrypting = encrypted.caesar_shift_to_match_english();
+            assert_eq!(decrypting, (26 - shift) % 26);
+            assert_eq!(
+                encrypted.caesar_shift(decrypting).to_string(),
+                caesar::solve(&encrypted.to_string())
+            );
+        }
+        assert_eq!(
+            LowercaseString::normalize("").caesar_shift_to_match_english(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_keyword_mixed_alphabet() {
+        for keyword in ["zebras", "  zebras!", "123 Zebras...", "-z-e-b-r-a-s-"] {
+            let alphabet = LowercaseString::keyword_mixed_alphabet(keyword);
+            assert_eq!(alphabet.to_string(), "zebrascdfghijklmnopqtuvwxy");
+        }
+        for keyword in ["", "!!!", "abcdefghijklmnopqrstuvwxyz"] {
+            let alphabet = LowercaseString::keyword_mixed_alphabet(keyword);
+            assert_eq!(alphabet.to_string(), "abcdefghijklmnopqrstuvwxyz");
+        }
+        let alphabet = LowercaseString::keyword_mixed_alphabet(OZYMANDIAS);
+        assert_eq!(alphabet.to_indices().len(), 26);
+        assert!(alphabet.is_valid_permutation());
+    }
+
+    #[test]
+    fn test_is_valid_permutation() {
+        assert!(LowercaseString::normalize("qwertyuiopasdfghjklzxcvbnm").is_valid_permutation());
+        assert!(!LowercaseString::normalize("qwertyuiopasdfghjklzxcvbn").is_valid_permutation());
+        assert!(!LowercaseString::normalize("qwertyuiopasdfghjklzxcvbnmm").is_valid_permutation());
+        assert!(!LowercaseString::normalize("qwertyuiopasdfghjklzxcvbnn").is_valid_permutation());
+        assert!(!LowercaseString::normalize("").is_valid_permutation());
+
+        let identity = LowercaseString::normalize("abcdefghijklmnopqrstuvwxyz");
+        let reversed = LowercaseString::normalize("zyxwvutsrqponmlkjihgfedcba");
+        assert!(identity.is_valid_permutation());
+        assert!(reversed.is_valid_permutation());
+        identity.assert_permutation();
+        reversed.assert_permutation();
+    }
+
+    #[test]
+    #[should_panic(expected = "not a permutation of the alphabet: missing \"q\", duplicated \"\"")]
+    fn test_assert_permutation_missing() {
+        LowercaseString::normalize("abcdefghijklmnoprstuvwxyz").assert_permutation();
+    }
+
+    #[test]
+    #[should_panic(expected = "not a permutation of the alphabet: missing \"q\", duplicated \"e\"")]
+    fn test_assert_permutation_duplicate() {
+        LowercaseString::normalize("abcdefghijklmnoprstuvwxyze").assert_permutation();
+    }
+
     #[test]
     fn test_caesar_shift() {
         let text = LowercaseString::normalize("hello");
@@ -148,6 +1646,81 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_shift_columns() {
+        let text = LowercaseString::normalize(OZYMANDIAS);
+        for key in ["sonnet", "a", "kingofkings"] {
+            let shifts: Vec<_> = LowercaseString::normalize(key)
+                .to_indices()
+                .iter()
+                .map(|&k| i32::from(k))
+                .collect();
+            assert_eq!(
+                text.shift_columns(&shifts).to_string(),
+                vigenere::encrypt(OZYMANDIAS, key).unwrap()
+            );
+        }
+        for shift in [-27, 0, 3, 25, 100] {
+            assert_eq!(text.shift_columns(&[shift; 4]), text.caesar_shift(shift));
+        }
+        assert_eq!(text.shift_columns(&[1, -1]).shift_columns(&[-1, 1]), text);
+    }
+
+    #[test]
+    #[should_panic(expected = "key must not be empty")]
+    fn test_shift_columns_empty_key() {
+        let _ = LowercaseString::normalize("abc").shift_columns(&[]);
+    }
+
+    #[test]
+    fn test_diff() {
+        let text = LowercaseString::normalize(OZYMANDIAS);
+        let len = text.to_indices().len();
+        for key in ["sonnet", "a", "kingofkings"] {
+            let key = LowercaseString::normalize(key);
+            let encrypted = vigenere::encrypt_ls(&text, &key);
+            let tiled: Vec<_> = key.to_indices().iter().copied().cycle().take(len).collect();
+            assert_eq!(
+                encrypted.diff(&text),
+                Ok(LowercaseString::from_indices(tiled))
+            );
+            // Subtracting the key back gives the plaintext.
+            let tiled = encrypted.diff(&text).unwrap();
+            assert_eq!(encrypted.diff(&tiled), Ok(text.clone()));
+        }
+        assert_eq!(text.diff(&text).unwrap().letter_counts()[0], len);
+
+        let short = LowercaseString::normalize("abc");
+        assert_eq!(
+            text.diff(&short),
+            Err(LengthMismatch {
+                left: len,
+                right: 3
+            })
+        );
+        assert_eq!(
+            LengthMismatch { left: 4, right: 3 }.to_string(),
+            "strings have different lengths, 4 and 3"
+        );
+    }
+
+    #[test]
+    fn test_apply_substitution() {
+        let text = LowercaseString::normalize("The quick brown fox jumps over the lazy dog");
+        let alphabet = LowercaseString::normalize("phqgiumeaylnofdxjkrcvstzwb");
+        let key = SubstitutionKey::from_alphabet(&alphabet).unwrap();
+
+        let encrypted = text.apply_substitution(&key);
+        assert_eq!(encrypted.to_string()[..3], *"cei");
+        assert_eq!(encrypted.apply_substitution_inverse(&key), text);
+        assert_eq!(
+            text.apply_substitution(&key.inverse())
+                .apply_substitution(&key),
+            text
+        );
+        assert_eq!(text.apply_substitution(&SubstitutionKey::identity()), text);
+    }
+
     #[test]
     fn test_to_string() {
         let text = LowercaseString::normalize("Hello123");
@@ -155,3 +1728,32 @@ mod tests {
         assert_eq!(LowercaseString::normalize("").to_string(), "");
     }
 }
+
+#[cfg(test)]
+mod prop_roundtrip {
+    use super::*;
+    use crate::test_data::random_text;
+    use crate::utils::Rng;
+
+    #[test]
+    fn split_at_indices_join() {
+        let mut rng = Rng::new(8);
+        for _ in 0..1000 {
+            let mut s = LowercaseString::normalize(&random_text(&mut rng, 50));
+            s.extend("abcdefghijkl".chars());
+            let parts = s.split_at_indices(&[3, 7, 12]);
+            assert_eq!(parts.len(), 4);
+            assert_eq!(LowercaseString::join(&parts), s);
+        }
+    }
+
+    #[test]
+    fn numeric_string() {
+        let mut rng = Rng::new(9);
+        for _ in 0..1000 {
+            let s = LowercaseString::normalize(&random_text(&mut rng, 50));
+            let numeric = s.to_numeric_string();
+            assert_eq!(LowercaseString::from_numeric_string(&numeric), Ok(s));
+        }
+    }
+}
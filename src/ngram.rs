@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+
+use crate::docs_quadgrams::DOCS_QUADGRAMS;
+use crate::lowercase_string::LowercaseString;
+use crate::utils::{ENGLISH_BIGRAM_FREQUENCIES, ENGLISH_FREQUENCIES};
+
+/// A language model scoring texts by the probabilities of their n-grams.
+///
+/// # Example
+/// ```
+/// use cipher_solver::ngram::NgramModel;
+/// use cipher_solver::LowercaseString;
+///
+/// let model = NgramModel::english_bigrams();
+/// let english = LowercaseString::normalize("the quick brown fox");
+/// let gibberish = LowercaseString::normalize("xqzjvkwqxzjq");
+/// assert!(model.score(&english) < model.score(&gibberish));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct NgramModel {
+    n: usize,
+    log_probs: HashMap<Vec<u8>, f64>,
+    floor: f64,
+}
+
+impl NgramModel {
+    /// Builds a model from n-grams (as letter indices) and their relative
+    /// frequencies, which are normalized to probabilities. All n-grams must
+    /// have the same length. N-grams missing from the data get a floor
+    /// probability of a hundredth of the rarest one.
+    #[must_use]
+    pub fn load(data: &[(Vec<u8>, f64)]) -> Self {
+        assert!(!data.is_empty(), "n-gram data must not be empty");
+        let n = data[0].0.len();
+        assert!(n > 0, "n-grams must not be empty");
+        assert!(
+            data.iter().all(|(ngram, _)| ngram.len() == n),
+            "n-grams must all have the same length"
+        );
+
+        let total: f64 = data.iter().map(|&(_, frequency)| frequency).sum();
+        let log_probs: HashMap<_, _> = data
+            .iter()
+            .filter(|&&(_, frequency)| frequency > 0.0)
+            .map(|(ngram, frequency)| (ngram.clone(), (frequency / total).ln()))
+            .collect();
+        let floor = log_probs.values().copied().fold(0.0, f64::min) + 0.01f64.ln();
+        Self {
+            n,
+            log_probs,
+            floor,
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn english_unigrams() -> Self {
+        let data: Vec<_> = ENGLISH_FREQUENCIES
+            .iter()
+            .enumerate()
+            .map(|(i, &frequency)| (vec![i as u8], frequency))
+            .collect();
+        Self::load(&data)
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn english_bigrams() -> Self {
+        let data: Vec<_> = ENGLISH_BIGRAM_FREQUENCIES
+            .iter()
+            .enumerate()
+            .flat_map(|(a, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(move |(b, &frequency)| (vec![a as u8, b as u8], frequency))
+            })
+            .collect();
+        Self::load(&data)
+    }
+
+    /// Loads the 5,000 most common quadgrams in the prose of the Rust
+    /// documentation, as a stand-in for English quadgrams. Technical words
+    /// such as "type", "rust" and "value" make up more of it than they would
+    /// in general English. Rarer quadgrams get the floor probability.
+    #[must_use]
+    pub fn english_quadgrams() -> Self {
+        let data: Vec<_> = DOCS_QUADGRAMS
+            .iter()
+            .map(|&(quadgram, count)| {
+                let ngram = LowercaseString::normalize(quadgram).to_indices().to_vec();
+                (ngram, f64::from(count))
+            })
+            .collect();
+        Self::load(&data)
+    }
+
+    #[must_use]
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the probability of an n-gram (as letter indices), or the
+    /// floor probability if it is missing from the model.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::ngram::NgramModel;
+    ///
+    /// let model = NgramModel::english_bigrams();
+    /// assert!(model.probability(&[19, 7]) > model.probability(&[16, 25]));
+    /// ```
+    #[must_use]
+    pub fn probability(&self, ngram: &[u8]) -> f64 {
+        assert_eq!(ngram.len(), self.n, "n-gram length must match the model");
+        self.log_probs
+            .get(ngram)
+            .copied()
+            .unwrap_or(self.floor)
+            .exp()
+    }
+
+    /// Returns the log probability of every n-gram, indexed by the n-gram
+    /// read as a number in base 26, for scoring without hashing.
+    pub(crate) fn log_prob_table(&self) -> Vec<f64> {
+        let len = (0..self.n).fold(1, |len, _| len * 26);
+        let mut table = vec![self.floor; len];
+        for (ngram, &log_prob) in &self.log_probs {
+            let index = ngram
+                .iter()
+                .fold(0, |index, &letter| index * 26 + usize::from(letter));
+            table[index] = log_prob;
+        }
+        table
+    }
+
+    /// Sums the negative log probabilities from a [`log_prob_table`] of
+    /// `n`-grams over the overlapping n-grams of `letters`. Each n-gram's
+    /// index is rolled on from the previous one's, so no n-gram is hashed.
+    ///
+    /// [`log_prob_table`]: NgramModel::log_prob_table
+    pub(crate) fn table_score(
+        table: &[f64],
+        n: usize,
+        letters: impl IntoIterator<Item = u8>,
+    ) -> f64 {
+        let modulus = 26usize.pow(u32::try_from(n - 1).unwrap());
+        let mut index = 0;
+        let mut total = 0.0;
+        for (i, letter) in letters.into_iter().enumerate() {
+            index = index % modulus * 26 + usize::from(letter);
+            if i + 1 >= n {
+                total -= table[index];
+            }
+        }
+        total
+    }
+
+    /// Scores a text by the average negative log probability of its
+    /// overlapping n-grams. Lower scores are more likely under the model.
+    #[must_use]
+    pub fn score(&self, text: &LowercaseString) -> f64 {
+        let indices = text.to_indices();
+        if indices.len() < self.n {
+            return -self.floor;
+        }
+        let total: f64 = indices
+            .windows(self.n)
+            .map(|ngram| -self.log_probs.get(ngram).copied().unwrap_or(self.floor))
+            .sum();
+        #[allow(clippy::cast_precision_loss)]
+        let count = (indices.len() - self.n + 1) as f64;
+        total / count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load() {
+        let model = NgramModel::load(&[(vec![0, 1], 3.0), (vec![1, 0], 1.0)]);
+        assert_eq!(model.n(), 2);
+
+        let ab = LowercaseString::normalize("ab");
+        assert!((model.score(&ab) + 0.75f64.ln()).abs() < 1e-10);
+        let ba = LowercaseString::normalize("ba");
+        assert!((model.score(&ba) + 0.25f64.ln()).abs() < 1e-10);
+
+        let unseen = LowercaseString::normalize("zz");
+        assert!((model.score(&unseen) + 0.0025f64.ln()).abs() < 1e-10);
+        let short = LowercaseString::normalize("a");
+        assert!((model.score(&short) + 0.0025f64.ln()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_probability() {
+        let model = NgramModel::load(&[(vec![0, 1], 3.0), (vec![1, 0], 1.0)]);
+        assert!((model.probability(&[0, 1]) - 0.75).abs() < 1e-10);
+        assert!((model.probability(&[1, 0]) - 0.25).abs() < 1e-10);
+        assert!((model.probability(&[25, 25]) - 0.0025).abs() < 1e-10);
+    }
+
+    #[test]
+    #[should_panic(expected = "n-gram length must match the model")]
+    fn test_probability_wrong_length() {
+        let _ = NgramModel::english_bigrams().probability(&[0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "n-grams must all have the same length")]
+    fn test_load_mixed_lengths() {
+        let _ = NgramModel::load(&[(vec![0, 1], 1.0), (vec![0], 1.0)]);
+    }
+
+    #[test]
+    fn test_english_models() {
+        let english = LowercaseString::normalize("the quick brown fox jumps over the lazy dog");
+        let gibberish = LowercaseString::normalize("zzzzxxxxqqqq");
+        for model in [
+            NgramModel::english_unigrams(),
+            NgramModel::english_bigrams(),
+            NgramModel::english_quadgrams(),
+        ] {
+            assert!(model.score(&english) < model.score(&gibberish));
+        }
+
+        assert_eq!(NgramModel::english_unigrams().n(), 1);
+        assert_eq!(NgramModel::english_bigrams().n(), 2);
+        assert_eq!(NgramModel::english_quadgrams().n(), 4);
+    }
+
+    #[test]
+    fn test_log_prob_table() {
+        let model = NgramModel::english_quadgrams();
+        let table = model.log_prob_table();
+        assert_eq!(table.len(), 26 * 26 * 26 * 26);
+        let tion = ((19 * 26 + 8) * 26 + 14) * 26 + 13;
+        assert!((table[tion].exp() - model.probability(&[19, 8, 14, 13])).abs() < 1e-12);
+        assert!((table[0].exp() - model.probability(&[0, 0, 0, 0])).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_table_score() {
+        let text = LowercaseString::normalize("the quick brown fox jumps over the lazy dog");
+        for model in [
+            NgramModel::english_unigrams(),
+            NgramModel::english_bigrams(),
+            NgramModel::english_quadgrams(),
+        ] {
+            let table = model.log_prob_table();
+            let total =
+                NgramModel::table_score(&table, model.n(), text.to_indices().iter().copied());
+            #[allow(clippy::cast_precision_loss)]
+            let count = (text.to_indices().len() - model.n() + 1) as f64;
+            assert!((total - model.score(&text) * count).abs() < 1e-9);
+        }
+        assert!(NgramModel::table_score(&[0.0; 4], 4, [0, 0]).abs() < 1e-10);
+    }
+}
@@ -0,0 +1,535 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::lowercase_string::LowercaseString;
+use crate::ngram::NgramModel;
+use crate::polybius::PolybiusSquare;
+use crate::utils::{Rng, SolverConfig};
+
+const SEED: u64 = 0x5eed;
+
+/// 'j' shares a cell of the key square with 'i'.
+const I: u8 = 8;
+const J: u8 = 9;
+
+/// The letter inserted between doubled letters and used as padding ('x'),
+/// or 'q' when the letter it separates is itself 'x'.
+const FILLER: u8 = 23;
+const ALTERNATE_FILLER: u8 = 16;
+
+/// A 5×5 key square stored row by row.
+type Square = [u8; 25];
+
+/// The number of columns of the 2×13 key grid of the 26-letter variant.
+const COLUMNS_26: usize = 13;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlayfairError {
+    /// The keyword has a character other than an ASCII letter or whitespace,
+    /// such as a digit, which has no cell in the 2×13 grid of the 26-letter
+    /// variant: placing it would need a larger grid.
+    InvalidGrid,
+}
+
+impl Display for PlayfairError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidGrid => write!(f, "keyword has a character with no cell in the key grid"),
+        }
+    }
+}
+
+impl Error for PlayfairError {}
+
+/// Builds the key square from the keyword's distinct letters followed by the
+/// rest of the alphabet, with 'j' merged into 'i'.
+fn square_from_keyword(keyword: &str) -> Square {
+    *PolybiusSquare::from_keyword(keyword).letters()
+}
+
+/// Returns the position of every letter in the grid, with 'j' at the
+/// position of 'i' in a 5×5 square.
+fn positions(grid: &[u8]) -> [usize; 26] {
+    let mut positions = [0; 26];
+    for (position, &letter) in grid.iter().enumerate() {
+        positions[usize::from(letter)] = position;
+    }
+    if grid.len() == 25 {
+        positions[usize::from(J)] = positions[usize::from(I)];
+    }
+    positions
+}
+
+/// Splits a plaintext into digraphs, separating doubled letters within a
+/// digraph and padding an odd final letter. 'j' is replaced with 'i' if
+/// `merge_j` is set.
+fn prepare(text: &LowercaseString, merge_j: bool) -> Vec<u8> {
+    let mut prepared = Vec::with_capacity(text.to_indices().len() + 8);
+    for &letter in text.to_indices() {
+        let letter = if merge_j && letter == J { I } else { letter };
+        if prepared.len() % 2 == 1 && prepared.last() == Some(&letter) {
+            prepared.push(filler(letter));
+        }
+        prepared.push(letter);
+    }
+    if let Some(&last) = prepared.last().filter(|_| prepared.len() % 2 == 1) {
+        prepared.push(filler(last));
+    }
+    prepared
+}
+
+fn filler(letter: u8) -> u8 {
+    if letter == FILLER {
+        ALTERNATE_FILLER
+    } else {
+        FILLER
+    }
+}
+
+/// Substitutes each digraph using a grid with `columns` columns, moving one
+/// cell along a shared row or column: forwards to encrypt, backwards to
+/// decrypt. Letters in different rows and columns swap columns.
+fn apply(text: &[u8], grid: &[u8], columns: usize, decrypt: bool, output: &mut Vec<u8>) {
+    let rows = grid.len() / columns;
+    let (row_shift, column_shift) = if decrypt {
+        (rows - 1, columns - 1)
+    } else {
+        (1, 1)
+    };
+    let positions = positions(grid);
+    output.clear();
+    for pair in text.chunks_exact(2) {
+        let a = positions[usize::from(pair[0])];
+        let b = positions[usize::from(pair[1])];
+        let (row_a, column_a) = (a / columns, a % columns);
+        let (row_b, column_b) = (b / columns, b % columns);
+        let (a, b) = if row_a == row_b {
+            (
+                row_a * columns + (column_a + column_shift) % columns,
+                row_b * columns + (column_b + column_shift) % columns,
+            )
+        } else if column_a == column_b {
+            (
+                (row_a + row_shift) % rows * columns + column_a,
+                (row_b + row_shift) % rows * columns + column_b,
+            )
+        } else {
+            (row_a * columns + column_b, row_b * columns + column_a)
+        };
+        output.push(grid[a]);
+        output.push(grid[b]);
+    }
+}
+
+/// Builds the 2×13 key grid of the 26-letter variant from the keyword's
+/// distinct letters followed by the rest of the alphabet.
+fn grid_26(keyword: &str) -> Result<Vec<u8>, PlayfairError> {
+    if !keyword
+        .chars()
+        .all(|c| c.is_ascii_alphabetic() || c.is_whitespace())
+    {
+        return Err(PlayfairError::InvalidGrid);
+    }
+    let grid = LowercaseString::keyword_mixed_alphabet(keyword)
+        .to_indices()
+        .to_vec();
+    debug_assert_eq!(grid.len(), 2 * COLUMNS_26);
+    Ok(grid)
+}
+
+/// Normalizes a ciphertext to letters, with 'j' as 'i' if `merge_j` is set
+/// and an odd final letter padded.
+fn normalize_ciphertext(text: &str, merge_j: bool) -> Vec<u8> {
+    let mut indices: Vec<_> = LowercaseString::normalize(text)
+        .to_indices()
+        .iter()
+        .map(|&letter| if merge_j && letter == J { I } else { letter })
+        .collect();
+    if indices.len() % 2 == 1 {
+        indices.push(FILLER);
+    }
+    indices
+}
+
+/// Encrypts a message using a Playfair cipher with a key square built from
+/// the keyword. 'j' is replaced with 'i', doubled letters within a digraph
+/// are separated with 'x' (or 'q' for "xx"), and an odd-length text is
+/// padded the same way.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::playfair;
+///
+/// let encrypted = playfair::encrypt("hide the gold in the tree stump", "playfair example");
+/// assert_eq!(encrypted, "bmodzbxdnabekudmuixmmouvif");
+/// ```
+#[must_use]
+pub fn encrypt(text: &str, keyword: &str) -> String {
+    let mut output = Vec::new();
+    apply(
+        &prepare(&LowercaseString::normalize(text), true),
+        &square_from_keyword(keyword),
+        5,
+        false,
+        &mut output,
+    );
+    LowercaseString::from_indices(output).to_string()
+}
+
+/// Decrypts a message using a Playfair cipher with a key square built from
+/// the keyword. Separators and padding are not removed.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::playfair;
+///
+/// let decrypted = playfair::decrypt("bmodzbxdnabekudmuixmmouvif", "playfair example");
+/// assert_eq!(decrypted, "hidethegoldinthetrexestump");
+/// ```
+#[must_use]
+pub fn decrypt(text: &str, keyword: &str) -> String {
+    let mut output = Vec::new();
+    apply(
+        &normalize_ciphertext(text, true),
+        &square_from_keyword(keyword),
+        5,
+        true,
+        &mut output,
+    );
+    LowercaseString::from_indices(output).to_string()
+}
+
+/// Encrypts a message using a 26-letter Playfair variant, which keeps 'i'
+/// and 'j' apart by using a 2×13 key grid: the keyword's distinct letters
+/// followed by the rest of the alphabet. Doubled letters within a digraph
+/// are separated with 'x' (or 'q' for "xx"), and an odd-length text is
+/// padded the same way.
+/// Punctuation and whitespace are removed.
+///
+/// # Errors
+/// Returns [`PlayfairError::InvalidGrid`] if the keyword has a character
+/// other than an ASCII letter or whitespace, as the grid has no cell for it.
+///
+/// # Example
+/// ```
+/// use cipher_solver::playfair;
+///
+/// let encrypted = playfair::encrypt_26("jump", "playfair example").unwrap();
+/// assert_eq!(playfair::decrypt_26(&encrypted, "playfair example").unwrap(), "jump");
+/// assert_ne!(playfair::decrypt(&playfair::encrypt("jump", "playfair example"), "playfair example"), "jump");
+/// ```
+pub fn encrypt_26(text: &str, keyword: &str) -> Result<String, PlayfairError> {
+    let mut output = Vec::new();
+    apply(
+        &prepare(&LowercaseString::normalize(text), false),
+        &grid_26(keyword)?,
+        COLUMNS_26,
+        false,
+        &mut output,
+    );
+    Ok(LowercaseString::from_indices(output).to_string())
+}
+
+/// Decrypts a message encrypted with [`encrypt_26`]. Separators and padding
+/// are not removed.
+/// Punctuation and whitespace are removed.
+///
+/// # Errors
+/// Returns [`PlayfairError::InvalidGrid`] if the keyword has a character
+/// other than an ASCII letter or whitespace, as the grid has no cell for it.
+pub fn decrypt_26(text: &str, keyword: &str) -> Result<String, PlayfairError> {
+    let mut output = Vec::new();
+    apply(
+        &normalize_ciphertext(text, false),
+        &grid_26(keyword)?,
+        COLUMNS_26,
+        true,
+        &mut output,
+    );
+    Ok(LowercaseString::from_indices(output).to_string())
+}
+
+/// Renders the key square built from the keyword as rows of uppercase
+/// letters separated by spaces, one row per line.
+///
+/// # Example
+/// ```
+/// use cipher_solver::playfair;
+///
+/// let square = playfair::display_key_square("playfair example");
+/// assert_eq!(square.lines().next(), Some("P L A Y F"));
+/// assert_eq!(square.lines().last(), Some("T U V W Z"));
+/// ```
+#[must_use]
+pub fn display_key_square(keyword: &str) -> String {
+    PolybiusSquare::from_keyword(keyword).display()
+}
+
+/// Recovers the shortest keyword that builds the square, or an equivalent
+/// square: shifting the rows or columns cyclically leaves the cipher
+/// unchanged, so every such shift is tried.
+fn keyword_from_square(square: &Square) -> String {
+    let keyword_len = |square: &Square| {
+        (0..=25)
+            .find(|&len| square[len..].windows(2).all(|pair| pair[0] < pair[1]))
+            .unwrap()
+    };
+    let shifted = (0..25).map(|shift| {
+        let (rows, columns) = (shift / 5, shift % 5);
+        let shifted: Square = std::array::from_fn(|position| {
+            let (row, column) = (position / 5, position % 5);
+            square[(row + rows) % 5 * 5 + (column + columns) % 5]
+        });
+        shifted
+    });
+    let best = shifted.min_by_key(|square| keyword_len(square)).unwrap();
+    LowercaseString::from_indices(best[..keyword_len(&best)].to_vec()).to_string()
+}
+
+/// Applies a random change to the square: usually swapping two letters, and
+/// otherwise reflecting a row or column, swapping two rows or columns, or
+/// rotating the square.
+fn mutate(square: &mut Square, rng: &mut Rng) {
+    match rng.below(50) {
+        0 => {
+            let row = rng.below(5) * 5;
+            square[row..row + 5].reverse();
+        }
+        1 => {
+            let column = rng.below(5);
+            for row in 0..2 {
+                square.swap(row * 5 + column, (4 - row) * 5 + column);
+            }
+        }
+        2 => {
+            let original = *square;
+            for (position, letter) in square.iter_mut().enumerate() {
+                let (row, column) = (position / 5, position % 5);
+                *letter = original[(4 - column) * 5 + row];
+            }
+        }
+        3..=5 => {
+            let (a, b) = (rng.below(5) * 5, rng.below(5) * 5);
+            for column in 0..5 {
+                square.swap(a + column, b + column);
+            }
+        }
+        6..=8 => {
+            let (a, b) = (rng.below(5), rng.below(5));
+            for row in 0..5 {
+                square.swap(row * 5 + a, row * 5 + b);
+            }
+        }
+        _ => {
+            let i = rng.below(25);
+            let j = (i + 1 + rng.below(24)) % 25;
+            square.swap(i, j);
+        }
+    }
+}
+
+/// Anneals a key square from the alphabet square, returning the best square
+/// found.
+fn anneal(text: &[u8], config: &SolverConfig, rng: &mut Rng) -> Square {
+    let (n, table) = config.scoring_table();
+    let mut decrypted = Vec::with_capacity(text.len());
+    let mut score = |square: &Square| {
+        apply(text, square, 5, true, &mut decrypted);
+        NgramModel::table_score(&table, n, decrypted.iter().copied())
+    };
+
+    let mut current = square_from_keyword("");
+    let mut current_score = score(&current);
+    let mut best = (current_score, current);
+    let mut temperature = config.initial_temperature;
+
+    for _ in 0..config.steps {
+        let mut candidate = current;
+        mutate(&mut candidate, rng);
+        let candidate_score = score(&candidate);
+
+        let accept = candidate_score < current_score
+            || rng.next_f64() < ((current_score - candidate_score) / temperature).exp();
+        if accept {
+            current = candidate;
+            current_score = candidate_score;
+            if current_score < best.0 {
+                best = (current_score, current);
+            }
+        }
+        temperature *= config.cooling_rate;
+    }
+    best.1
+}
+
+/// Solves a Playfair cipher with [`SolverConfig::default`], returning the
+/// plaintext and the keyword. See [`solve_with_config`].
+///
+/// # Example
+/// ```no_run
+/// use cipher_solver::playfair;
+///
+/// let text = "I met a traveller from an antique land, Who said: Two vast and \
+///     trunkless legs of stone Stand in the desert. Near them, on the sand, Half \
+///     sunk a shattered visage lies, whose frown, And wrinkled lip, and sneer of \
+///     cold command, Tell that its sculptor well those passions read Which yet \
+///     survive, stamped on these lifeless things, The hand that mocked them, and \
+///     the heart that fed; And on the pedestal, these words appear: My name is \
+///     Ozymandias, King of Kings; Look on my Works, ye Mighty, and despair! \
+///     Nothing beside remains. Round the decay Of that colossal Wreck, boundless \
+///     and bare The lone and level sands stretch far away.";
+/// let encrypted = playfair::encrypt(text, "kingdom");
+/// let (plaintext, keyword) = playfair::solve(&encrypted);
+/// assert_eq!(keyword, "kingdom");
+/// assert!(plaintext.starts_with("imetatravelxler"));
+/// ```
+#[must_use]
+pub fn solve(text: &str) -> (String, String) {
+    solve_with_config(text, &SolverConfig::default())
+}
+
+/// Solves a Playfair cipher using simulated annealing over the key square,
+/// scoring candidates with the model of the config (by default
+/// [`NgramModel::english_quadgrams`]). Returns the plaintext, with
+/// separators and padding left in, and the shortest keyword that builds an
+/// equivalent square.
+///
+/// The search is seeded, so results are reproducible, but it can settle on
+/// a wrong square: short texts, or unlucky ones, may need more steps or a
+/// slower cooling rate. The keyword is only meaningful when the square was
+/// built from one.
+#[must_use]
+pub fn solve_with_config(text: &str, config: &SolverConfig) -> (String, String) {
+    let text = normalize_ciphertext(text, true);
+    let mut rng = Rng::new(SEED);
+    let square = anneal(&text, config, &mut rng);
+    let keyword = keyword_from_square(&square);
+    let mut decrypted = Vec::new();
+    apply(&text, &square, 5, true, &mut decrypted);
+    (
+        LowercaseString::from_indices(decrypted).to_string(),
+        keyword,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::OZYMANDIAS;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let encrypted = encrypt(OZYMANDIAS, "wreck");
+        let decrypted = decrypt(&encrypted, "wreck");
+        let prepared =
+            LowercaseString::from_indices(prepare(&LowercaseString::normalize(OZYMANDIAS), true));
+        assert_eq!(decrypted, prepared.to_string());
+
+        assert_eq!(encrypt("balloon", "monarchy"), "ibsupmna");
+        assert_eq!(decrypt("ibsupmna", "monarchy"), "balxloon");
+        assert_eq!(encrypt("xx", ""), encrypt("xqxq", ""));
+        assert_eq!(encrypt("jam", "jumble"), encrypt("iam", "iumble"));
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_26() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS);
+        let encrypted = encrypt_26(OZYMANDIAS, "wreck").unwrap();
+        let prepared = LowercaseString::from_indices(prepare(&normalized, false));
+        assert_eq!(decrypt_26(&encrypted, "wreck"), Ok(prepared.to_string()));
+
+        assert_eq!(
+            encrypt_26("balloon", "monarchy"),
+            encrypt_26("balxloon", "monarchy")
+        );
+        assert_eq!(
+            decrypt_26(&encrypt_26("jij", "").unwrap(), ""),
+            Ok("jijx".to_string())
+        );
+        // The standard square merges 'j' into 'i'.
+        assert_ne!(
+            encrypt_26("major", "playfair").unwrap(),
+            encrypt("major", "playfair")
+        );
+        assert_eq!(decrypt(&encrypt("major", "playfair"), "playfair"), "maiorx");
+        assert_eq!(
+            decrypt_26(&encrypt_26("major", "playfair").unwrap(), "playfair"),
+            Ok("majorx".to_string())
+        );
+    }
+
+    #[test]
+    fn test_grid_26() {
+        let grid = grid_26("playfair example").unwrap();
+        assert_eq!(grid.len(), 26);
+        assert_eq!(&grid[..8], &[15, 11, 0, 24, 5, 8, 17, 4]);
+
+        assert_eq!(
+            encrypt_26("attack", "agent 007"),
+            Err(PlayfairError::InvalidGrid)
+        );
+        assert_eq!(
+            decrypt_26("attack", "café"),
+            Err(PlayfairError::InvalidGrid)
+        );
+        assert_eq!(
+            PlayfairError::InvalidGrid.to_string(),
+            "keyword has a character with no cell in the key grid"
+        );
+    }
+
+    #[test]
+    fn test_solve_with_model() {
+        let encrypted = encrypt(OZYMANDIAS, "kingdom");
+        let config = SolverConfig {
+            steps: 2_000,
+            ..SolverConfig::default()
+        };
+        let with_model = SolverConfig {
+            model: Some(NgramModel::english_quadgrams()),
+            ..config.clone()
+        };
+        assert_eq!(
+            solve_with_config(&encrypted, &config),
+            solve_with_config(&encrypted, &with_model)
+        );
+
+        let bigrams = SolverConfig {
+            model: Some(NgramModel::english_bigrams()),
+            ..config
+        };
+        let (plaintext, _) = solve_with_config(&encrypted, &bigrams);
+        assert_eq!(plaintext.len(), encrypted.len());
+    }
+
+    #[test]
+    fn test_display_key_square() {
+        assert_eq!(
+            display_key_square("PLAYFAIR"),
+            "P L A Y F\nI R B C D\nE G H K M\nN O Q S T\nU V W X Z"
+        );
+    }
+
+    #[test]
+    fn test_keyword_from_square() {
+        for keyword in ["kingdom", "wreck", "monarchy", ""] {
+            let square = square_from_keyword(keyword);
+            assert_eq!(keyword_from_square(&square), keyword);
+            let shifted: Square = std::array::from_fn(|i| square[(i + 10) % 25]);
+            assert_eq!(keyword_from_square(&shifted), keyword);
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        let encrypted = encrypt(&normalized[..290], "kingdom");
+        assert_eq!(encrypted.len(), 300);
+
+        let (plaintext, keyword) = solve(&encrypted);
+        assert_eq!(keyword, "kingdom");
+        assert_eq!(plaintext, decrypt(&encrypted, "kingdom"));
+    }
+}
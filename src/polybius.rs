@@ -0,0 +1,228 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::lowercase_string::LowercaseString;
+
+/// 'j' shares a cell of the square with 'i'.
+const I: u8 = 8;
+const J: u8 = 9;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolybiusError {
+    /// A coordinate pair outside the square, which runs from 1 to 5.
+    InvalidCoordinate(u8, u8),
+}
+
+impl Display for PolybiusError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCoordinate(row, column) => {
+                write!(f, "coordinate ({row}, {column}) is outside the square")
+            }
+        }
+    }
+}
+
+impl Error for PolybiusError {}
+
+/// A 5×5 Polybius square with 'j' merged into 'i'. Letters are given as
+/// indices (a = 0) and cells by row and column, each from 1 to 5.
+///
+/// # Example
+/// ```
+/// use cipher_solver::polybius::PolybiusSquare;
+/// use cipher_solver::LowercaseString;
+///
+/// let square = PolybiusSquare::default();
+/// let text = LowercaseString::normalize("polybius");
+/// let pairs = square.encode_to_pairs(&text);
+/// assert_eq!(pairs[..2], [(3, 5), (3, 4)]);
+/// assert_eq!(square.decode_from_pairs(&pairs), Ok(text));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolybiusSquare([u8; 25]);
+
+impl Default for PolybiusSquare {
+    /// The square of the alphabet in order.
+    fn default() -> Self {
+        Self::from_keyword("")
+    }
+}
+
+impl PolybiusSquare {
+    /// Builds a square from the keyword's distinct letters followed by the
+    /// rest of the alphabet.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::polybius::PolybiusSquare;
+    ///
+    /// let square = PolybiusSquare::from_keyword("kingdom");
+    /// assert_eq!(square.get(1, 1), Some(10));
+    /// assert_eq!(square.get(2, 3), Some(0));
+    /// ```
+    #[must_use]
+    pub fn from_keyword(keyword: &str) -> Self {
+        let mut seen = [false; 26];
+        seen[usize::from(J)] = true;
+        let mut letters = Vec::with_capacity(25);
+        let keyword = LowercaseString::normalize(keyword);
+        for letter in keyword.to_indices().iter().copied().chain(0..26) {
+            let letter = if letter == J { I } else { letter };
+            if !seen[usize::from(letter)] {
+                seen[usize::from(letter)] = true;
+                letters.push(letter);
+            }
+        }
+        Self(letters.try_into().unwrap())
+    }
+
+    /// Returns the letters of the square row by row.
+    pub(crate) fn letters(&self) -> &[u8; 25] {
+        &self.0
+    }
+
+    /// Renders the square as rows of uppercase letters separated by spaces,
+    /// one row per line.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::polybius::PolybiusSquare;
+    ///
+    /// let square = PolybiusSquare::from_keyword("playfair example");
+    /// assert_eq!(
+    ///     square.display(),
+    ///     "P L A Y F\nI R E X M\nB C D G H\nK N O Q S\nT U V W Z"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn display(&self) -> String {
+        self.0
+            .chunks(5)
+            .map(|row| {
+                row.iter()
+                    .map(|&letter| char::from(b'A' + letter).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Returns the letter at a row and column, or `None` if either is
+    /// outside 1 to 5.
+    #[must_use]
+    pub fn get(&self, row: u8, column: u8) -> Option<u8> {
+        let valid = 1..=5;
+        (valid.contains(&row) && valid.contains(&column))
+            .then(|| self.0[usize::from(row - 1) * 5 + usize::from(column - 1)])
+    }
+
+    /// Returns the row and column of a letter, with 'j' found at 'i'.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::polybius::PolybiusSquare;
+    ///
+    /// let square = PolybiusSquare::default();
+    /// assert_eq!(square.find(0), (1, 1));
+    /// assert_eq!(square.find(9), square.find(8));
+    /// ```
+    #[must_use]
+    pub fn find(&self, letter: u8) -> (u8, u8) {
+        assert!(letter < 26, "letter index must be below 26");
+        let letter = if letter == J { I } else { letter };
+        let position = self.0.iter().position(|&l| l == letter).unwrap();
+        #[allow(clippy::cast_possible_truncation)]
+        let (row, column) = ((position / 5) as u8, (position % 5) as u8);
+        (row + 1, column + 1)
+    }
+
+    /// Encodes each letter of the text as its row and column.
+    #[must_use]
+    pub fn encode_to_pairs(&self, text: &LowercaseString) -> Vec<(u8, u8)> {
+        text.to_indices()
+            .iter()
+            .map(|&letter| self.find(letter))
+            .collect()
+    }
+
+    /// Decodes rows and columns back to letters. 'j' decodes as 'i'.
+    ///
+    /// # Errors
+    /// Returns [`PolybiusError::InvalidCoordinate`] for the first pair with a
+    /// row or column outside 1 to 5.
+    pub fn decode_from_pairs(&self, pairs: &[(u8, u8)]) -> Result<LowercaseString, PolybiusError> {
+        let letters = pairs
+            .iter()
+            .map(|&(row, column)| {
+                self.get(row, column)
+                    .ok_or(PolybiusError::InvalidCoordinate(row, column))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(LowercaseString::from_indices(letters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::OZYMANDIAS;
+
+    #[test]
+    fn test_encode_decode() {
+        let text = LowercaseString::normalize(OZYMANDIAS);
+        let without_j = LowercaseString::normalize(&OZYMANDIAS.replace(['j', 'J'], "i"));
+        for keyword in ["", "kingdom", "ozymandias"] {
+            let square = PolybiusSquare::from_keyword(keyword);
+            let pairs = square.encode_to_pairs(&text);
+            assert_eq!(pairs.len(), text.to_indices().len());
+            assert_eq!(square.decode_from_pairs(&pairs), Ok(without_j.clone()));
+        }
+
+        let square = PolybiusSquare::default();
+        let pairs = square.encode_to_pairs(&LowercaseString::normalize("jazz"));
+        assert_eq!(pairs, [(2, 4), (1, 1), (5, 5), (5, 5)]);
+    }
+
+    #[test]
+    fn test_get_find() {
+        let square = PolybiusSquare::from_keyword("kingdom");
+        for row in 1..=5 {
+            for column in 1..=5 {
+                let letter = square.get(row, column).unwrap();
+                assert_eq!(square.find(letter), (row, column));
+            }
+        }
+        assert_eq!(square.get(0, 1), None);
+        assert_eq!(square.get(1, 6), None);
+        assert_eq!(square.find(9), square.find(8));
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let square = PolybiusSquare::default();
+        assert_eq!(
+            square.decode_from_pairs(&[(1, 1), (6, 2), (0, 0)]),
+            Err(PolybiusError::InvalidCoordinate(6, 2))
+        );
+        assert_eq!(
+            PolybiusError::InvalidCoordinate(0, 3).to_string(),
+            "coordinate (0, 3) is outside the square"
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            PolybiusSquare::default().display(),
+            "A B C D E\nF G H I K\nL M N O P\nQ R S T U\nV W X Y Z"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "letter index must be below 26")]
+    fn test_find_invalid_letter() {
+        let _ = PolybiusSquare::default().find(26);
+    }
+}
@@ -0,0 +1,178 @@
+use crate::lowercase_string::LowercaseString;
+use crate::utils;
+
+/// The positions of the text in the order they are read off the rails.
+fn rail_order(len: usize, rails: usize) -> Vec<usize> {
+    let period = 2 * (rails - 1);
+    let rail = |i: usize| match period {
+        0 => 0,
+        _ => (i % period).min(period - i % period),
+    };
+    let mut order: Vec<usize> = (0..len).collect();
+    order.sort_by_key(|&i| rail(i));
+    order
+}
+
+fn encrypt_ls(text: &LowercaseString, rails: usize) -> LowercaseString {
+    let indices = text.to_indices();
+    LowercaseString::from_indices(
+        rail_order(indices.len(), rails)
+            .into_iter()
+            .map(|i| indices[i])
+            .collect(),
+    )
+}
+
+fn decrypt_ls(text: &LowercaseString, rails: usize) -> LowercaseString {
+    let indices = text.to_indices();
+    let mut plaintext = vec![0; indices.len()];
+    for (&c, i) in indices.iter().zip(rail_order(indices.len(), rails)) {
+        plaintext[i] = c;
+    }
+    LowercaseString::from_indices(plaintext)
+}
+
+/// Encrypts a message using a rail fence cipher: the text is written in a
+/// zigzag across the given number of rails, which are then read off from
+/// top to bottom. Returns `None` if there are no rails.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::rail_fence;
+///
+/// let encrypted = rail_fence::encrypt("we are discovered", 3).unwrap();
+/// assert_eq!(encrypted, "wecrerdsoeeaivd");
+/// ```
+#[must_use]
+pub fn encrypt(text: &str, rails: usize) -> Option<String> {
+    (rails > 0).then(|| encrypt_ls(&LowercaseString::normalize(text), rails).to_string())
+}
+
+/// Decrypts a message using a rail fence cipher with the given number of
+/// rails. Returns `None` if there are no rails.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::rail_fence;
+///
+/// let decrypted = rail_fence::decrypt("wecrerdsoeeaivd", 3).unwrap();
+/// assert_eq!(decrypted, "wearediscovered");
+/// ```
+#[must_use]
+pub fn decrypt(text: &str, rails: usize) -> Option<String> {
+    (rails > 0).then(|| decrypt_ls(&LowercaseString::normalize(text), rails).to_string())
+}
+
+/// Decrypts a rail fence cipher with every rail count from `min` to `max`,
+/// returning each plaintext with its rail count and score, best first.
+///
+/// A transposition leaves the letter frequencies unchanged, so candidates
+/// are scored with [`utils::bigram_english_score`] rather than by letter
+/// frequencies. Lower scores are better.
+///
+/// # Example
+/// ```
+/// use cipher_solver::rail_fence;
+///
+/// let text = "Shall I compare thee to a summer's day? Thou art more lovely and \
+///     more temperate";
+/// let encrypted = rail_fence::encrypt(text, 5).unwrap();
+/// let candidates = rail_fence::solve_depth_range(&encrypted, 2, 8);
+/// assert_eq!(candidates.len(), 7);
+/// assert_eq!(candidates[0].1, 5);
+/// ```
+#[must_use]
+pub fn solve_depth_range(text: &str, min: usize, max: usize) -> Vec<(String, usize, f64)> {
+    let text = LowercaseString::normalize(text);
+    let mut candidates: Vec<_> = (min.max(1)..=max)
+        .map(|rails| {
+            let decrypted = decrypt_ls(&text, rails);
+            let score = utils::bigram_english_score(&decrypted);
+            (decrypted.to_string(), rails, score)
+        })
+        .collect();
+    candidates.sort_by(|(.., a), (.., b)| a.total_cmp(b));
+    candidates
+}
+
+/// Solves a rail fence cipher by trying every rail count from 2 to
+/// `max_rails`. Returns the plaintext and the number of rails, or the text
+/// itself with 1 rail if `max_rails` is below 2.
+///
+/// # Example
+/// ```
+/// use cipher_solver::rail_fence;
+///
+/// let text = "Shall I compare thee to a summer's day? Thou art more lovely and \
+///     more temperate";
+/// let encrypted = rail_fence::encrypt(text, 3).unwrap();
+/// let (solved, rails) = rail_fence::solve(&encrypted, 10);
+/// assert_eq!(rails, 3);
+/// assert!(solved.starts_with("shallicompare"));
+/// ```
+#[must_use]
+pub fn solve(text: &str, max_rails: usize) -> (String, usize) {
+    solve_depth_range(text, 2, max_rails)
+        .into_iter()
+        .next()
+        .map_or_else(
+            || (LowercaseString::normalize(text).to_string(), 1),
+            |(plaintext, rails, _)| (plaintext, rails),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::OZYMANDIAS;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        for rails in 1..=12 {
+            let encrypted = encrypt(OZYMANDIAS, rails).unwrap();
+            assert_eq!(decrypt(&encrypted, rails).unwrap(), normalized);
+        }
+        assert_eq!(encrypt(OZYMANDIAS, 1).unwrap(), normalized);
+        assert_eq!(encrypt(OZYMANDIAS, 0), None);
+        assert_eq!(decrypt(OZYMANDIAS, 0), None);
+    }
+
+    #[test]
+    fn test_solve_depth_range() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        let encrypted = encrypt(OZYMANDIAS, 4).unwrap();
+        let candidates = solve_depth_range(&encrypted, 2, 10);
+        assert_eq!(candidates.len(), 9);
+        assert_eq!(candidates[0].0, normalized);
+        assert_eq!(candidates[0].1, 4);
+        assert!(candidates.windows(2).all(|pair| pair[0].2 <= pair[1].2));
+
+        assert!(solve_depth_range(&encrypted, 5, 4).is_empty());
+        assert_eq!(solve(&encrypted, 10), (normalized, 4));
+        assert_eq!(solve("hello", 1), ("hello".to_string(), 1));
+    }
+}
+
+#[cfg(test)]
+mod prop_roundtrip {
+    use super::*;
+    use crate::test_data::random_text;
+    use crate::utils::Rng;
+
+    #[test]
+    fn encrypt_decrypt() {
+        let mut rng = Rng::new(16);
+        for _ in 0..1000 {
+            let plain = random_text(&mut rng, 50);
+            let rails = 1 + rng.below(12);
+            let encrypted = encrypt(&plain, rails).unwrap();
+            assert_eq!(
+                decrypt(&encrypted, rails),
+                Some(LowercaseString::normalize(&plain).to_string())
+            );
+        }
+    }
+}
@@ -0,0 +1,827 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::Index;
+
+use crate::lowercase_string::LowercaseString;
+use crate::ngram::NgramModel;
+use crate::utils::{self, Language, Rng, SolverConfig};
+
+const SEED: u64 = 0x5eed;
+
+/// The number of steps in a row without improvement after which
+/// [`SubstitutionSolver::solve_until_stable`] stops.
+const STABLE_STEPS: u32 = 2000;
+
+/// A monoalphabetic substitution key. Plaintext letter `i` encrypts to
+/// ciphertext letter `key[i]`.
+///
+/// # Example
+/// ```
+/// use cipher_solver::substitution::SubstitutionKey;
+/// use cipher_solver::LowercaseString;
+///
+/// let alphabet = LowercaseString::normalize("zyxwvutsrqponmlkjihgfedcba");
+/// let key = SubstitutionKey::from_alphabet(&alphabet).unwrap();
+/// assert_eq!(key[0], 25);
+/// assert_eq!(key.to_string(), "zyxwvutsrqponmlkjihgfedcba");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubstitutionKey([u8; 26]);
+
+impl SubstitutionKey {
+    #[must_use]
+    pub fn identity() -> Self {
+        let key = Self(std::array::from_fn(|i| u8::try_from(i).unwrap()));
+        debug_assert_eq!(key.fixed_points().len(), 26);
+        key
+    }
+
+    /// Builds the key of a frequency attack, matching the letters of a
+    /// ciphertext to those of `lang` by frequency rank: the plaintext letter
+    /// of rank `r` encrypts to the ciphertext letter of rank `r`. The ranks
+    /// are as given by [`LowercaseString::frequency_rank`].
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::substitution::SubstitutionKey;
+    /// use cipher_solver::utils::Language;
+    /// use cipher_solver::LowercaseString;
+    ///
+    /// let ciphertext = LowercaseString::normalize("qqqqqqwwwwwzzzz");
+    /// let ranks = ciphertext.frequency_rank();
+    /// let key = SubstitutionKey::from_frequency_ranks(&ranks, Language::English);
+    /// // 'e', 't' and 'a' are the most common English letters.
+    /// assert_eq!(key[4], 16);
+    /// assert_eq!(key[19], 22);
+    /// assert_eq!(key[0], 25);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_frequency_ranks(cipher_ranks: &[u8; 26], lang: Language) -> Self {
+        let mut cipher_by_rank = [0; 26];
+        for (letter, &rank) in cipher_ranks.iter().enumerate() {
+            assert!(rank < 26, "ranks must be below 26");
+            cipher_by_rank[rank as usize] = letter as u8;
+        }
+        let plain_ranks = utils::frequency_ranks(lang.frequencies());
+        let key = Self(plain_ranks.map(|rank| cipher_by_rank[rank as usize]));
+        assert!(
+            Self::from_alphabet(&key.to_alphabet()).is_some(),
+            "ranks must be a permutation"
+        );
+        key
+    }
+
+    /// Lists the keys consistent with `(plaintext letter, ciphertext
+    /// letter)` constraints, such as those found by
+    /// [`utils::probable_word_attack`], in lexicographic order. At most
+    /// `max_count` keys are returned, and none if the constraints map a
+    /// letter to two letters or two letters to one.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::substitution::SubstitutionKey;
+    ///
+    /// // Everything but 'y' and 'z' is fixed, leaving two completions.
+    /// let constraints: Vec<_> = (0..24).map(|i| (i, 25 - i)).collect();
+    /// let keys = SubstitutionKey::from_word_pattern_constraints(&constraints, 10);
+    /// assert_eq!(keys.len(), 2);
+    /// assert_eq!(keys[0].to_string(), "zyxwvutsrqponmlkjihgfedcab");
+    /// assert_eq!(keys[1].to_string(), "zyxwvutsrqponmlkjihgfedcba");
+    /// ```
+    #[must_use]
+    pub fn from_word_pattern_constraints(constraints: &[(u8, u8)], max_count: usize) -> Vec<Self> {
+        let mut partial = [None; 26];
+        let mut used = [false; 26];
+        for &(plain, cipher) in constraints {
+            assert!(plain < 26 && cipher < 26, "letter index must be below 26");
+            match partial[usize::from(plain)] {
+                Some(existing) if existing == cipher => {}
+                None if !used[usize::from(cipher)] => {
+                    partial[usize::from(plain)] = Some(cipher);
+                    used[usize::from(cipher)] = true;
+                }
+                _ => return Vec::new(),
+            }
+        }
+
+        let mut keys = Vec::new();
+        complete(&mut partial, &mut used, 0, max_count, &mut keys);
+        keys
+    }
+
+    /// Builds a random key, from a fixed seed, in which each `(plaintext
+    /// letter, ciphertext letter)` pair in `fixed` holds. Returns `None` if
+    /// a letter index is not below 26, or if the pairs map a letter to two
+    /// letters or two letters to one.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::substitution::SubstitutionKey;
+    ///
+    /// let key = SubstitutionKey::with_fixed_positions(&[(4, 16), (19, 0)]).unwrap();
+    /// assert_eq!(key[4], 16);
+    /// assert_eq!(key[19], 0);
+    /// assert_eq!(SubstitutionKey::with_fixed_positions(&[(4, 16), (19, 16)]), None);
+    /// ```
+    #[must_use]
+    pub fn with_fixed_positions(fixed: &[(u8, u8)]) -> Option<Self> {
+        let mut partial = [None; 26];
+        let mut used = [false; 26];
+        for &(plain, cipher) in fixed {
+            if plain >= 26 || cipher >= 26 {
+                return None;
+            }
+            match partial[usize::from(plain)] {
+                Some(existing) if existing != cipher => return None,
+                None if used[usize::from(cipher)] => return None,
+                _ => {}
+            }
+            partial[usize::from(plain)] = Some(cipher);
+            used[usize::from(cipher)] = true;
+        }
+
+        let mut free: Vec<u8> = (0..26).filter(|&c| !used[usize::from(c)]).collect();
+        Rng::new(SEED).shuffle(&mut free);
+        let mut free = free.into_iter();
+        Some(Self(
+            partial.map(|cipher| cipher.or_else(|| free.next()).unwrap()),
+        ))
+    }
+
+    /// Returns the letters that encrypt to themselves. Each one gives away a
+    /// plaintext letter for free.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::substitution::SubstitutionKey;
+    ///
+    /// let mut key = SubstitutionKey::identity();
+    /// key.swap(0, 1);
+    /// assert_eq!(key.fixed_points().len(), 24);
+    /// assert_eq!(key.hamming_distance_from_identity(), 2);
+    /// ```
+    #[must_use]
+    pub fn fixed_points(&self) -> Vec<usize> {
+        (0..26).filter(|&i| self.0[i] as usize == i).collect()
+    }
+
+    /// Counts the letters that do not encrypt to themselves.
+    #[must_use]
+    pub fn hamming_distance_from_identity(&self) -> usize {
+        26 - self.fixed_points().len()
+    }
+
+    /// Builds a key from a cipher alphabet, where the `i`th letter of the
+    /// alphabet is the encryption of the `i`th letter of the standard alphabet.
+    /// Returns `None` unless the alphabet contains each letter exactly once.
+    #[must_use]
+    pub fn from_alphabet(alphabet: &LowercaseString) -> Option<Self> {
+        alphabet
+            .is_valid_permutation()
+            .then(|| Self(alphabet.to_indices().try_into().unwrap()))
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn inverse(&self) -> Self {
+        let mut inverse = [0; 26];
+        for (i, &c) in self.0.iter().enumerate() {
+            inverse[c as usize] = i as u8;
+        }
+        Self(inverse)
+    }
+
+    /// Swaps the ciphertext letters assigned to plaintext letters `a` and `b`.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.0.swap(a, b);
+    }
+
+    #[must_use]
+    pub fn to_alphabet(&self) -> LowercaseString {
+        LowercaseString::from_indices(self.0.to_vec())
+    }
+
+    /// Builds the key with the given index in the lexicographic order of all
+    /// 26! keys, decoding the index as a Lehmer code. The identity key has
+    /// index 0.
+    #[must_use]
+    pub fn from_permutation_index(n: u128) -> Self {
+        assert!(n < FACTORIALS[26], "permutation index must be below 26!");
+        let mut remaining: Vec<u8> = (0..26).collect();
+        let mut n = n;
+        let mut key = [0; 26];
+        for (i, letter) in key.iter_mut().enumerate() {
+            let place = FACTORIALS[25 - i];
+            #[allow(clippy::cast_possible_truncation)]
+            let digit = (n / place) as usize;
+            n %= place;
+            *letter = remaining.remove(digit);
+        }
+        Self(key)
+    }
+
+    /// Returns the index of the key in the lexicographic order of all 26!
+    /// keys, the inverse of [`SubstitutionKey::from_permutation_index`].
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::substitution::SubstitutionKey;
+    ///
+    /// let mut key = SubstitutionKey::identity();
+    /// assert_eq!(key.to_permutation_index(), 0);
+    /// key.swap(24, 25);
+    /// assert_eq!(key.to_permutation_index(), 1);
+    /// assert_eq!(SubstitutionKey::from_permutation_index(1), key);
+    /// ```
+    #[must_use]
+    pub fn to_permutation_index(&self) -> u128 {
+        (0..26)
+            .map(|i| {
+                let smaller_later = self.0[i + 1..].iter().filter(|&&c| c < self.0[i]).count();
+                smaller_later as u128 * FACTORIALS[25 - i]
+            })
+            .sum()
+    }
+}
+
+/// Fills the unconstrained letters of a partial key from `letter` on with
+/// every remaining ciphertext letter in turn, until `max_count` keys have
+/// been found.
+fn complete(
+    partial: &mut [Option<u8>; 26],
+    used: &mut [bool; 26],
+    letter: usize,
+    max_count: usize,
+    keys: &mut Vec<SubstitutionKey>,
+) {
+    if keys.len() >= max_count {
+        return;
+    }
+    if letter == 26 {
+        keys.push(SubstitutionKey(partial.map(Option::unwrap)));
+        return;
+    }
+    if partial[letter].is_some() {
+        complete(partial, used, letter + 1, max_count, keys);
+        return;
+    }
+    for cipher in 0..26 {
+        if !used[usize::from(cipher)] {
+            partial[letter] = Some(cipher);
+            used[usize::from(cipher)] = true;
+            complete(partial, used, letter + 1, max_count, keys);
+            partial[letter] = None;
+            used[usize::from(cipher)] = false;
+        }
+    }
+}
+
+/// `FACTORIALS[n]` is `n!`.
+const FACTORIALS: [u128; 27] = {
+    let mut factorials = [1; 27];
+    let mut n = 1;
+    while n < 27 {
+        factorials[n] = factorials[n - 1] * n as u128;
+        n += 1;
+    }
+    factorials
+};
+
+impl Index<usize> for SubstitutionKey {
+    type Output = u8;
+
+    fn index(&self, index: usize) -> &u8 {
+        &self.0[index]
+    }
+}
+
+impl Display for SubstitutionKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_alphabet())
+    }
+}
+
+/// Encrypts a message using a monoalphabetic substitution cipher.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::substitution::{self, SubstitutionKey};
+/// use cipher_solver::LowercaseString;
+///
+/// let alphabet = LowercaseString::normalize("qwertyuiopasdfghjklzxcvbnm");
+/// let key = SubstitutionKey::from_alphabet(&alphabet).unwrap();
+/// let encrypted = substitution::encrypt("hello world", &key);
+/// assert_eq!(encrypted, "itssgvgksr");
+/// ```
+#[must_use]
+pub fn encrypt(text: &str, key: &SubstitutionKey) -> String {
+    LowercaseString::normalize(text)
+        .apply_substitution(key)
+        .to_string()
+}
+
+/// Decrypts a message using a monoalphabetic substitution cipher.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::substitution::{self, SubstitutionKey};
+/// use cipher_solver::LowercaseString;
+///
+/// let alphabet = LowercaseString::normalize("qwertyuiopasdfghjklzxcvbnm");
+/// let key = SubstitutionKey::from_alphabet(&alphabet).unwrap();
+/// let decrypted = substitution::decrypt("itssgvgksr", &key);
+/// assert_eq!(decrypted, "helloworld");
+/// ```
+#[must_use]
+pub fn decrypt(text: &str, key: &SubstitutionKey) -> String {
+    LowercaseString::normalize(text)
+        .apply_substitution_inverse(key)
+        .to_string()
+}
+
+/// The annealing schedule of [`partial_solve_with_known_pairs`].
+const ANNEAL_CONFIG: SolverConfig = SolverConfig {
+    initial_temperature: 10.0,
+    cooling_rate: 0.999_95,
+    steps: 60_000,
+    model: None,
+};
+
+/// Anneals a key from `key`, only ever swapping the ciphertext letters of
+/// the plaintext letters in `free`, and returns the best key found.
+fn anneal(
+    text: &LowercaseString,
+    mut key: SubstitutionKey,
+    free: &[usize],
+    rng: &mut Rng,
+) -> SubstitutionKey {
+    let (n, table) = ANNEAL_CONFIG.scoring_table();
+    // The total negative log probability of the quadgrams of the decryption.
+    let score = |key: &SubstitutionKey| {
+        let inverse = key.inverse();
+        let letters = text
+            .to_indices()
+            .iter()
+            .map(|&letter| inverse[usize::from(letter)]);
+        NgramModel::table_score(&table, n, letters)
+    };
+    if free.len() < 2 {
+        return key;
+    }
+
+    let mut current_score = score(&key);
+    let mut best = (current_score, key.clone());
+    let mut temperature = ANNEAL_CONFIG.initial_temperature;
+    for _ in 0..ANNEAL_CONFIG.steps {
+        let a = rng.below(free.len());
+        let b = (a + 1 + rng.below(free.len() - 1)) % free.len();
+        key.swap(free[a], free[b]);
+        let candidate_score = score(&key);
+        let accept = candidate_score < current_score
+            || rng.next_f64() < ((current_score - candidate_score) / temperature).exp();
+        if accept {
+            current_score = candidate_score;
+            if current_score < best.0 {
+                best = (current_score, key.clone());
+            }
+        } else {
+            key.swap(free[a], free[b]);
+        }
+        temperature *= ANNEAL_CONFIG.cooling_rate;
+    }
+    best.1
+}
+
+/// Solves a substitution cipher by simulated annealing with the quadgrams of
+/// [`NgramModel::english_quadgrams`], starting from
+/// [`SubstitutionKey::with_fixed_positions`] and never changing the known
+/// `(plaintext letter, ciphertext letter)` pairs. Returns the plaintext and
+/// the key, or `None` if a known pair has a character that is not an ASCII
+/// letter or the pairs conflict.
+///
+/// The search is seeded, so results are reproducible. Short texts can
+/// score better with a wrong key, and letters that occur once or twice are
+/// easily swapped, so known pairs for rare letters help most. Letters that
+/// do not occur in the text are left wherever the search put them.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::substitution::{self, SubstitutionKey};
+/// use cipher_solver::LowercaseString;
+///
+/// let text = "I met a traveller from an antique land, Who said: Two vast and \
+///     trunkless legs of stone Stand in the desert. Near them, on the sand, Half \
+///     sunk a shattered visage lies, whose frown, And wrinkled lip, and sneer of \
+///     cold command, Tell that its sculptor well those passions read Which yet \
+///     survive, stamped on these lifeless things, The hand that mocked them, and \
+///     the heart that fed; And on the pedestal, these words appear: My name is \
+///     Ozymandias, King of Kings; Look on my Works, ye Mighty, and despair! \
+///     Nothing beside remains. Round the decay Of that colossal Wreck, boundless \
+///     and bare The lone and level sands stretch far away.";
+/// let alphabet = LowercaseString::normalize("phqgiumeaylnofdxjkrcvstzwb");
+/// let key = SubstitutionKey::from_alphabet(&alphabet).unwrap();
+/// let encrypted = substitution::encrypt(text, &key);
+/// let known = [('e', 'i'), ('t', 'c'), ('a', 'p')];
+/// let (plaintext, _) = substitution::partial_solve_with_known_pairs(&encrypted, &known).unwrap();
+/// assert!(plaintext.starts_with("imetatraveller"));
+/// ```
+#[must_use]
+pub fn partial_solve_with_known_pairs(
+    text: &str,
+    known: &[(char, char)],
+) -> Option<(String, SubstitutionKey)> {
+    let index = |c: char| {
+        c.is_ascii_alphabetic()
+            .then(|| c.to_ascii_lowercase() as u8 - b'a')
+    };
+    let fixed = known
+        .iter()
+        .map(|&(plain, cipher)| Some((index(plain)?, index(cipher)?)))
+        .collect::<Option<Vec<_>>>()?;
+    let key = SubstitutionKey::with_fixed_positions(&fixed)?;
+    let free: Vec<_> = (0..26)
+        .filter(|&letter| fixed.iter().all(|&(plain, _)| usize::from(plain) != letter))
+        .collect();
+
+    let text = LowercaseString::normalize(text);
+    let mut rng = Rng::new(SEED);
+    let key = anneal(&text, key, &free, &mut rng);
+    Some((text.apply_substitution_inverse(&key).to_string(), key))
+}
+
+/// A hill-climbing solver for substitution ciphers that can be run a step
+/// at a time, so that the search can be inspected, steered with known
+/// letters, and resumed.
+///
+/// Each step swaps two letters of the key, keeping the swap only if it makes
+/// the decryption more English-like according to
+/// [`utils::bigram_english_score`].
+///
+/// # Example
+/// ```
+/// use cipher_solver::substitution::SubstitutionSolver;
+///
+/// let mut solver = SubstitutionSolver::new("itssgvgksr");
+/// let initial_score = solver.score();
+/// solver.solve_for_steps(100);
+/// assert!(solver.score() <= initial_score);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SubstitutionSolver {
+    key: SubstitutionKey,
+    text: LowercaseString,
+    score: f64,
+    rng: Rng,
+}
+
+impl SubstitutionSolver {
+    /// Starts from a random key. Punctuation and whitespace are removed.
+    #[must_use]
+    pub fn new(text: &str) -> Self {
+        let mut rng = Rng::new(SEED);
+        let mut key = SubstitutionKey::identity();
+        rng.shuffle(&mut key.0);
+        let mut solver = Self {
+            key,
+            text: LowercaseString::normalize(text),
+            score: 0.0,
+            rng,
+        };
+        solver.score = solver.score_key(&solver.key);
+        solver
+    }
+
+    fn score_key(&self, key: &SubstitutionKey) -> f64 {
+        utils::bigram_english_score(&self.text.apply_substitution_inverse(key))
+    }
+
+    /// Tries swapping two random letters of the key, returning whether the
+    /// swap was kept.
+    pub fn step(&mut self) -> bool {
+        let mut candidate = self.key.clone();
+        candidate.swap(self.rng.below(26), self.rng.below(26));
+        let score = self.score_key(&candidate);
+        if score < self.score {
+            self.key = candidate;
+            self.score = score;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn solve_for_steps(&mut self, n: u32) {
+        for _ in 0..n {
+            self.step();
+        }
+    }
+
+    /// Runs until 2000 steps in a row bring no improvement, or `max_steps`
+    /// steps have been taken.
+    pub fn solve_until_stable(&mut self, max_steps: u32) {
+        let mut since_improvement = 0;
+        for _ in 0..max_steps {
+            if self.step() {
+                since_improvement = 0;
+            } else {
+                since_improvement += 1;
+                if since_improvement == STABLE_STEPS {
+                    break;
+                }
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn key(&self) -> &SubstitutionKey {
+        &self.key
+    }
+
+    /// Replaces the current key, for example with one fixing known letters.
+    pub fn set_key(&mut self, key: SubstitutionKey) {
+        self.score = self.score_key(&key);
+        self.key = key;
+    }
+
+    /// The score of the current key. Lower scores are more English-like.
+    #[must_use]
+    pub fn score(&self) -> f64 {
+        self.score
+    }
+
+    #[must_use]
+    pub fn current_plaintext(&self) -> String {
+        self.text.apply_substitution_inverse(&self.key).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_data::{random_substitution_key, OZYMANDIAS};
+
+    #[test]
+    fn test_from_alphabet() {
+        let alphabet = LowercaseString::normalize("abcdefghijklmnopqrstuvwxyz");
+        assert_eq!(
+            SubstitutionKey::from_alphabet(&alphabet),
+            Some(SubstitutionKey::identity())
+        );
+
+        let short = LowercaseString::normalize("abc");
+        assert_eq!(SubstitutionKey::from_alphabet(&short), None);
+
+        let duplicate = LowercaseString::normalize("abcdefghijklmnopqrstuvwxya");
+        assert_eq!(SubstitutionKey::from_alphabet(&duplicate), None);
+    }
+
+    #[test]
+    fn test_fixed_points() {
+        let identity = SubstitutionKey::identity();
+        assert_eq!(identity.fixed_points(), (0..26).collect::<Vec<_>>());
+        assert_eq!(identity.hamming_distance_from_identity(), 0);
+
+        let alphabet = LowercaseString::normalize("qbcdefghijklmnoparstuvwxyz");
+        let key = SubstitutionKey::from_alphabet(&alphabet).unwrap();
+        assert_eq!(key.hamming_distance_from_identity(), 2);
+        assert!(!key.fixed_points().contains(&0));
+        assert!(!key.fixed_points().contains(&16));
+
+        let mut rng = Rng::new(3);
+        for _ in 0..100 {
+            let key = random_substitution_key(&mut rng);
+            let fixed = key.fixed_points();
+            assert!(fixed.iter().all(|&i| key[i] as usize == i));
+            assert_eq!(fixed.len() + key.hamming_distance_from_identity(), 26);
+        }
+
+        let reversed = LowercaseString::normalize("zyxwvutsrqponmlkjihgfedcba");
+        let key = SubstitutionKey::from_alphabet(&reversed).unwrap();
+        assert_eq!(key.hamming_distance_from_identity(), 26);
+    }
+
+    #[test]
+    fn test_from_frequency_ranks() {
+        let text = LowercaseString::normalize(OZYMANDIAS);
+        let ranks = text.frequency_rank();
+        let mut sorted = ranks;
+        sorted.sort_unstable();
+        assert_eq!(sorted, std::array::from_fn(|i| u8::try_from(i).unwrap()));
+        assert_eq!(ranks[4], 0);
+
+        let key = SubstitutionKey::from_frequency_ranks(&ranks, Language::English);
+        // Rank order matches English for the commonest letters of the text.
+        assert_eq!(key[4], 4);
+
+        let mut rng = Rng::new(5);
+        let secret = random_substitution_key(&mut rng);
+        let encrypted = text.apply_substitution(&secret);
+        let guess =
+            SubstitutionKey::from_frequency_ranks(&encrypted.frequency_rank(), Language::English);
+        assert_eq!(guess[4], secret[4]);
+
+        let ranks: [u8; 26] = std::array::from_fn(|i| u8::try_from(i).unwrap());
+        assert_eq!(
+            SubstitutionKey::from_frequency_ranks(&ranks, Language::English).to_string(),
+            "ctljapqhewvknfdsyigbmuoxrz"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "ranks must be a permutation")]
+    fn test_from_frequency_ranks_repeated() {
+        let _ = SubstitutionKey::from_frequency_ranks(&[0; 26], Language::English);
+    }
+
+    #[test]
+    fn test_from_word_pattern_constraints() {
+        let key = random_substitution_key(&mut Rng::new(3));
+        let mut constraints: Vec<_> = (0..22).map(|i| (i, key[usize::from(i)])).collect();
+        constraints.push(constraints[5]);
+        let keys = SubstitutionKey::from_word_pattern_constraints(&constraints, 100);
+        // Four letters are left free.
+        assert_eq!(keys.len(), 24);
+        assert!(keys.contains(&key));
+        assert!(keys.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for completion in &keys {
+            assert!(SubstitutionKey::from_alphabet(&completion.to_alphabet()).is_some());
+            assert!(constraints
+                .iter()
+                .all(|&(plain, cipher)| completion[usize::from(plain)] == cipher));
+        }
+
+        assert_eq!(
+            SubstitutionKey::from_word_pattern_constraints(&constraints, 5).len(),
+            5
+        );
+        let keys = SubstitutionKey::from_word_pattern_constraints(&[], 3);
+        assert_eq!(keys[0], SubstitutionKey::identity());
+        assert_eq!(keys[2].to_string(), "abcdefghijklmnopqrstuvwyxz");
+
+        assert!(SubstitutionKey::from_word_pattern_constraints(&[(0, 1), (0, 2)], 10).is_empty());
+        assert!(SubstitutionKey::from_word_pattern_constraints(&[(0, 1), (2, 1)], 10).is_empty());
+        assert!(SubstitutionKey::from_word_pattern_constraints(&[(0, 1)], 0).is_empty());
+    }
+
+    #[test]
+    fn test_inverse() {
+        let alphabet = LowercaseString::normalize("qwertyuiopasdfghjklzxcvbnm");
+        let key = SubstitutionKey::from_alphabet(&alphabet).unwrap();
+        assert_eq!(key.inverse().inverse(), key);
+        assert_eq!(key.inverse()[16], 0);
+    }
+
+    #[test]
+    fn test_permutation_index() {
+        assert_eq!(
+            SubstitutionKey::from_permutation_index(0),
+            SubstitutionKey::identity()
+        );
+        let reversed = LowercaseString::normalize("zyxwvutsrqponmlkjihgfedcba");
+        let last = SubstitutionKey::from_alphabet(&reversed).unwrap();
+        assert_eq!(last.to_permutation_index(), FACTORIALS[26] - 1);
+        assert_eq!(
+            SubstitutionKey::from_permutation_index(FACTORIALS[26] - 1),
+            last
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "permutation index must be below 26!")]
+    fn test_permutation_index_out_of_range() {
+        let _ = SubstitutionKey::from_permutation_index(FACTORIALS[26]);
+    }
+
+    #[test]
+    fn test_solver() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        let alphabet = LowercaseString::normalize("phqgiumeaylnofdxjkrcvstzwb");
+        let key = SubstitutionKey::from_alphabet(&alphabet).unwrap();
+        let encrypted = encrypt(OZYMANDIAS, &key);
+
+        let mut solver = SubstitutionSolver::new(&encrypted);
+        let mut score = solver.score();
+        for _ in 0..10 {
+            solver.solve_for_steps(100);
+            assert!(solver.score() <= score);
+            score = solver.score();
+        }
+        solver.solve_until_stable(100_000);
+        let correct = solver
+            .current_plaintext()
+            .chars()
+            .zip(normalized.chars())
+            .filter(|(a, b)| a == b)
+            .count();
+        assert!(correct * 3 >= normalized.len());
+        assert_eq!(
+            decrypt(&encrypted, solver.key()),
+            solver.current_plaintext()
+        );
+
+        solver.set_key(key);
+        assert_eq!(solver.current_plaintext(), normalized);
+        let score = solver.score();
+        solver.solve_until_stable(100_000);
+        assert!(solver.score() <= score);
+    }
+
+    #[test]
+    fn test_with_fixed_positions() {
+        let fixed = [(0, 25), (1, 24), (7, 7)];
+        let key = SubstitutionKey::with_fixed_positions(&fixed).unwrap();
+        assert!(SubstitutionKey::from_alphabet(&key.to_alphabet()).is_some());
+        for (plain, cipher) in fixed {
+            assert_eq!(key[usize::from(plain)], cipher);
+        }
+        assert_eq!(
+            Some(key.clone()),
+            SubstitutionKey::with_fixed_positions(&fixed)
+        );
+        assert_ne!(Some(key), SubstitutionKey::with_fixed_positions(&[]));
+
+        let all: Vec<_> = (0..26).map(|i| (i, 25 - i)).collect();
+        let key = SubstitutionKey::with_fixed_positions(&all).unwrap();
+        assert_eq!(key.to_string(), "zyxwvutsrqponmlkjihgfedcba");
+
+        // Repeating a pair is allowed.
+        assert!(SubstitutionKey::with_fixed_positions(&[(0, 1), (0, 1)]).is_some());
+        assert_eq!(
+            SubstitutionKey::with_fixed_positions(&[(0, 1), (2, 1)]),
+            None
+        );
+        assert_eq!(
+            SubstitutionKey::with_fixed_positions(&[(0, 1), (0, 2)]),
+            None
+        );
+        assert_eq!(SubstitutionKey::with_fixed_positions(&[(26, 1)]), None);
+    }
+
+    #[test]
+    fn test_partial_solve_with_known_pairs() {
+        let text = format!("{OZYMANDIAS} The quick brown fox jumps over the lazy dog");
+        let normalized = LowercaseString::normalize(&text).to_string();
+        let alphabet = LowercaseString::normalize("phqgiumeaylnofdxjkrcvstzwb");
+        let key = SubstitutionKey::from_alphabet(&alphabet).unwrap();
+        let encrypted = encrypt(&text, &key);
+
+        // 'x' and 'z' occur too rarely to place without a crib.
+        let known = [('E', 'i'), ('t', 'c'), ('a', 'p'), ('z', 'b'), ('x', 'z')];
+        let (plaintext, solved) = partial_solve_with_known_pairs(&encrypted, &known).unwrap();
+        assert_eq!(plaintext, normalized);
+        assert_eq!(solved, key);
+
+        assert_eq!(
+            partial_solve_with_known_pairs(&encrypted, &[('e', '1')]),
+            None
+        );
+        let conflict = [('e', 'i'), ('t', 'i')];
+        assert_eq!(partial_solve_with_known_pairs(&encrypted, &conflict), None);
+    }
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let original = "The quick brown fox jumps over the lazy dog";
+        let normalized = LowercaseString::normalize(original);
+        let alphabet = LowercaseString::normalize("phqgiumeaylnofdxjkrcvstzwb");
+        let key = SubstitutionKey::from_alphabet(&alphabet).unwrap();
+        let encrypted = encrypt(original, &key);
+        assert_ne!(encrypted, normalized.to_string());
+        let decrypted = decrypt(&encrypted, &key);
+        assert_eq!(decrypted, normalized.to_string());
+    }
+}
+
+#[cfg(test)]
+mod prop_roundtrip {
+    use super::*;
+    use crate::test_data::{random_substitution_key, random_text};
+    use crate::utils::Rng;
+
+    #[test]
+    fn encrypt_decrypt() {
+        let mut rng = Rng::new(3);
+        for _ in 0..1000 {
+            let plain = random_text(&mut rng, 50);
+            let key = random_substitution_key(&mut rng);
+            let encrypted = encrypt(&plain, &key);
+            assert_eq!(
+                decrypt(&encrypted, &key),
+                LowercaseString::normalize(&plain).to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn permutation_index() {
+        let mut rng = Rng::new(5);
+        for _ in 0..1000 {
+            let key = random_substitution_key(&mut rng);
+            let index = key.to_permutation_index();
+            assert_eq!(SubstitutionKey::from_permutation_index(index), key);
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::lowercase_string::LowercaseString;
+use crate::substitution::SubstitutionKey;
+use crate::utils::Rng;
+
+pub const OZYMANDIAS: &str = "I met a traveller from an antique land, \
+    Who said: Two vast and trunkless legs of stone \
+    Stand in the desert. Near them, on the sand, \
+    Half sunk a shattered visage lies, whose frown, \
+    And wrinkled lip, and sneer of cold command, \
+    Tell that its sculptor well those passions read \
+    Which yet survive, stamped on these lifeless things, \
+    The hand that mocked them, and the heart that fed; \
+    And on the pedestal, these words appear: \
+    My name is Ozymandias, King of Kings; \
+    Look on my Works, ye Mighty, and despair! \
+    Nothing beside remains. Round the decay \
+    Of that colossal Wreck, boundless and bare \
+    The lone and level sands stretch far away.";
+
+/// Generates random text mixing letters of both cases, digits, punctuation,
+/// whitespace and non-ASCII characters.
+pub fn random_text(rng: &mut Rng, max_len: usize) -> String {
+    const EXTRA: [char; 8] = [' ', '.', ',', '!', '7', '\n', 'é', 'ß'];
+    let len = rng.below(max_len + 1);
+    (0..len)
+        .map(|_| match rng.below(4) {
+            0 => EXTRA[rng.below(EXTRA.len())],
+            1 => char::from(b'A' + u8::try_from(rng.below(26)).unwrap()),
+            _ => char::from(b'a' + u8::try_from(rng.below(26)).unwrap()),
+        })
+        .collect()
+}
+
+/// Generates a random keyword of 1 to `max_len` letters.
+pub fn random_keyword(rng: &mut Rng, max_len: usize) -> String {
+    let len = 1 + rng.below(max_len);
+    (0..len)
+        .map(|_| char::from(b'a' + u8::try_from(rng.below(26)).unwrap()))
+        .collect()
+}
+
+/// Generates a uniformly random substitution key.
+pub fn random_substitution_key(rng: &mut Rng) -> SubstitutionKey {
+    let mut letters: Vec<u8> = (0..26).collect();
+    rng.shuffle(&mut letters);
+    SubstitutionKey::from_alphabet(&LowercaseString::from_indices(letters)).unwrap()
+}
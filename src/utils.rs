@@ -1,11 +1,297 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::cipher_kind::CipherKind;
+use crate::docs_quadgrams::DOCS_QUADGRAMS;
 use crate::lowercase_string::LowercaseString;
+use crate::ngram::NgramModel;
+use crate::substitution::SubstitutionKey;
+use crate::vigenere;
 
-const ENGLISH_FREQUENCIES: [f64; 26] = [
+pub const ENGLISH_FREQUENCIES: [f64; 26] = [
     0.08167, 0.01492, 0.02782, 0.04253, 0.12702, 0.02228, 0.02015, 0.06094, 0.06966, 0.00153,
     0.00772, 0.04025, 0.02406, 0.06749, 0.07507, 0.01929, 0.00095, 0.05987, 0.06327, 0.09056,
     0.02758, 0.00978, 0.02360, 0.00150, 0.01974, 0.00074,
 ];
 
+// Letter frequencies for other languages, renormalized over the unaccented
+// letters since normalization drops accented ones.
+pub const FRENCH_FREQUENCIES: [f64; 26] = [
+    0.07854, 0.00927, 0.03353, 0.03774, 0.15135, 0.01096, 0.00891, 0.00758, 0.07744, 0.00630,
+    0.00076, 0.05612, 0.03053, 0.07297, 0.05961, 0.02593, 0.01401, 0.06884, 0.08175, 0.07451,
+    0.06491, 0.01890, 0.00050, 0.00439, 0.00132, 0.00335,
+];
+
+pub const GERMAN_FREQUENCIES: [f64; 26] = [
+    0.06671, 0.01931, 0.02797, 0.05197, 0.16786, 0.01695, 0.03080, 0.04686, 0.06706, 0.00274,
+    0.01451, 0.03519, 0.02594, 0.10008, 0.02656, 0.00686, 0.00018, 0.07169, 0.07443, 0.06300,
+    0.04265, 0.00866, 0.01967, 0.00035, 0.00040, 0.01161,
+];
+
+pub const SPANISH_FREQUENCIES: [f64; 26] = [
+    0.11879, 0.02283, 0.04142, 0.05164, 0.12555, 0.00713, 0.01822, 0.00725, 0.06439, 0.00508,
+    0.00011, 0.05119, 0.03254, 0.06918, 0.08950, 0.02587, 0.00904, 0.07082, 0.08222, 0.04774,
+    0.03017, 0.01173, 0.00018, 0.00222, 0.01039, 0.00481,
+];
+
+/// A plaintext language, selecting the letter frequencies solvers compare
+/// against.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Language {
+    English,
+    French,
+    German,
+    Spanish,
+}
+
+impl Language {
+    #[must_use]
+    pub fn frequencies(self) -> &'static [f64; 26] {
+        match self {
+            Self::English => &ENGLISH_FREQUENCIES,
+            Self::French => &FRENCH_FREQUENCIES,
+            Self::German => &GERMAN_FREQUENCIES,
+            Self::Spanish => &SPANISH_FREQUENCIES,
+        }
+    }
+}
+
+/// Relative frequencies of letter pairs in English, where
+/// `ENGLISH_BIGRAM_FREQUENCIES[a][b]` is the frequency of `a` followed by `b`.
+/// Counted over running text with spaces and punctuation removed, so pairs
+/// spanning word boundaries are included.
+#[allow(clippy::unreadable_literal)]
+pub const ENGLISH_BIGRAM_FREQUENCIES: [[f64; 26]; 26] = [
+    [
+        0.000112, 0.002292, 0.003498, 0.002774, 0.000048, 0.001167, 0.001454, 0.000097, 0.003006,
+        0.000035, 0.001063, 0.007728, 0.004443, 0.014400, 0.000101, 0.002263, 0.000012, 0.007812,
+        0.005772, 0.014788, 0.001245, 0.002273, 0.000597, 0.000222, 0.000978, 0.000013,
+    ],
+    [
+        0.000264, 0.000030, 0.000016, 0.000009, 0.003451, 0.000003, 0.000000, 0.000001, 0.000612,
+        0.000223, 0.000000, 0.002345, 0.000007, 0.000000, 0.001245, 0.000009, 0.000000, 0.000910,
+        0.000200, 0.000017, 0.001144, 0.000006, 0.000004, 0.000000, 0.001016, 0.000000,
+    ],
+    [
+        0.005794, 0.000107, 0.000519, 0.000057, 0.003854, 0.000077, 0.000014, 0.004207, 0.001189,
+        0.000000, 0.001263, 0.001281, 0.000516, 0.000004, 0.007425, 0.000080, 0.000020, 0.002019,
+        0.000193, 0.005614, 0.001302, 0.000048, 0.000057, 0.000000, 0.000206, 0.000000,
+    ],
+    [
+        0.002634, 0.000823, 0.000580, 0.001060, 0.006468, 0.000552, 0.000144, 0.000364, 0.004276,
+        0.000017, 0.000020, 0.000949, 0.000387, 0.000368, 0.003059, 0.000460, 0.000014, 0.000807,
+        0.001987, 0.003285, 0.001113, 0.000331, 0.001093, 0.000003, 0.000442, 0.000003,
+    ],
+    [
+        0.009480, 0.001651, 0.009516, 0.007729, 0.003282, 0.005661, 0.000790, 0.001160, 0.004763,
+        0.000084, 0.000319, 0.004160, 0.005049, 0.011788, 0.002943, 0.003153, 0.000600, 0.016627,
+        0.015423, 0.014210, 0.001012, 0.002896, 0.004067, 0.002551, 0.001164, 0.000007,
+    ],
+    [
+        0.001294, 0.000074, 0.000322, 0.000132, 0.002631, 0.000810, 0.000020, 0.000112, 0.003994,
+        0.000001, 0.000006, 0.000187, 0.000110, 0.000043, 0.003241, 0.000091, 0.000000, 0.001438,
+        0.000291, 0.002934, 0.002712, 0.000084, 0.000468, 0.000000, 0.000673, 0.000000,
+    ],
+    [
+        0.001866, 0.000196, 0.000432, 0.000186, 0.002969, 0.000402, 0.000132, 0.000910, 0.001190,
+        0.000014, 0.000007, 0.000477, 0.000383, 0.000535, 0.001058, 0.000184, 0.000003, 0.001368,
+        0.000790, 0.002377, 0.001196, 0.000072, 0.000599, 0.000000, 0.000091, 0.000000,
+    ],
+    [
+        0.010564, 0.000070, 0.000172, 0.000097, 0.023603, 0.000129, 0.000064, 0.000042, 0.005075,
+        0.000006, 0.000012, 0.000083, 0.000286, 0.000097, 0.004040, 0.000112, 0.000001, 0.001221,
+        0.000290, 0.001747, 0.000142, 0.000078, 0.000319, 0.000000, 0.000172, 0.000001,
+    ],
+    [
+        0.001377, 0.001000, 0.003077, 0.001365, 0.001181, 0.002577, 0.001190, 0.000001, 0.000023,
+        0.000001, 0.000509, 0.004723, 0.002728, 0.022629, 0.005974, 0.000645, 0.000065, 0.001702,
+        0.008663, 0.008741, 0.000001, 0.000967, 0.000022, 0.000152, 0.000000, 0.000259,
+    ],
+    [
+        0.000009, 0.000000, 0.000000, 0.000000, 0.000496, 0.000000, 0.000000, 0.000000, 0.000001,
+        0.000000, 0.000001, 0.000000, 0.000000, 0.000000, 0.000093, 0.000000, 0.000000, 0.000000,
+        0.000001, 0.000000, 0.000261, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+    ],
+    [
+        0.000531, 0.000048, 0.000043, 0.000026, 0.001979, 0.000116, 0.000013, 0.000019, 0.000689,
+        0.000007, 0.000004, 0.000104, 0.000030, 0.000373, 0.000210, 0.000019, 0.000000, 0.000046,
+        0.000545, 0.000265, 0.000041, 0.000003, 0.000151, 0.000000, 0.000028, 0.000000,
+    ],
+    [
+        0.002327, 0.000403, 0.000491, 0.002015, 0.009451, 0.000375, 0.000144, 0.000246, 0.006342,
+        0.000012, 0.000132, 0.006293, 0.000180, 0.000245, 0.003596, 0.000390, 0.000004, 0.000339,
+        0.001441, 0.001874, 0.002731, 0.000215, 0.000445, 0.000000, 0.002370, 0.000000,
+    ],
+    [
+        0.003894, 0.000613, 0.000145, 0.000080, 0.008426, 0.000090, 0.000012, 0.000049, 0.001650,
+        0.000003, 0.000003, 0.000281, 0.000955, 0.000054, 0.002218, 0.003157, 0.000000, 0.000061,
+        0.000686, 0.000694, 0.001125, 0.000048, 0.000228, 0.000000, 0.000039, 0.000000,
+    ],
+    [
+        0.004933, 0.000565, 0.005846, 0.007874, 0.005921, 0.000826, 0.010243, 0.000464, 0.003554,
+        0.000028, 0.000155, 0.001493, 0.000603, 0.000903, 0.003756, 0.000457, 0.000007, 0.001054,
+        0.004998, 0.014468, 0.001396, 0.000620, 0.001448, 0.000000, 0.001316, 0.000007,
+    ],
+    [
+        0.001252, 0.000877, 0.002213, 0.004457, 0.000974, 0.005568, 0.001251, 0.000388, 0.001368,
+        0.000280, 0.000642, 0.001629, 0.004192, 0.012110, 0.001528, 0.002061, 0.000006, 0.008999,
+        0.002461, 0.004470, 0.007377, 0.001089, 0.004482, 0.000054, 0.000113, 0.000001,
+    ],
+    [
+        0.003219, 0.000065, 0.000078, 0.000126, 0.004439, 0.000090, 0.000012, 0.000119, 0.001302,
+        0.000000, 0.000001, 0.003093, 0.000049, 0.000003, 0.001744, 0.000657, 0.000000, 0.003379,
+        0.000259, 0.001474, 0.000823, 0.000012, 0.000125, 0.000000, 0.000081, 0.000000,
+    ],
+    [
+        0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+        0.000000, 0.000000, 0.000001, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+        0.000000, 0.000000, 0.000786, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+    ],
+    [
+        0.007396, 0.000351, 0.001825, 0.001110, 0.016889, 0.000619, 0.000929, 0.000210, 0.004655,
+        0.000020, 0.000690, 0.000986, 0.001144, 0.002083, 0.005882, 0.000628, 0.000003, 0.001857,
+        0.003724, 0.004001, 0.003409, 0.000338, 0.000968, 0.000000, 0.001700, 0.000003,
+    ],
+    [
+        0.006229, 0.000852, 0.002429, 0.000716, 0.007835, 0.001263, 0.000188, 0.002296, 0.006314,
+        0.000062, 0.000290, 0.001447, 0.000884, 0.001119, 0.004428, 0.001764, 0.000023, 0.001405,
+        0.003936, 0.015941, 0.002087, 0.000210, 0.002332, 0.000000, 0.001177, 0.000006,
+    ],
+    [
+        0.007322, 0.000807, 0.001903, 0.000760, 0.011262, 0.000983, 0.000203, 0.034101, 0.012671,
+        0.000038, 0.000101, 0.001097, 0.000834, 0.000546, 0.011552, 0.001076, 0.000012, 0.004159,
+        0.005085, 0.005340, 0.003002, 0.000277, 0.002882, 0.000023, 0.003059, 0.000007,
+    ],
+    [
+        0.000919, 0.000331, 0.001912, 0.000291, 0.002803, 0.000026, 0.000529, 0.000122, 0.000549,
+        0.000010, 0.000028, 0.003159, 0.001435, 0.003999, 0.000049, 0.000800, 0.000000, 0.005253,
+        0.006904, 0.003854, 0.000039, 0.000088, 0.000212, 0.000017, 0.000001, 0.000001,
+    ],
+    [
+        0.003630, 0.000001, 0.000006, 0.000000, 0.005056, 0.000003, 0.000001, 0.000003, 0.000992,
+        0.000000, 0.000000, 0.000001, 0.000000, 0.000000, 0.000126, 0.000004, 0.000000, 0.000006,
+        0.000004, 0.000003, 0.000009, 0.000000, 0.000006, 0.000000, 0.000000, 0.000000,
+    ],
+    [
+        0.001644, 0.000051, 0.000122, 0.000043, 0.006309, 0.000055, 0.000009, 0.003280, 0.004205,
+        0.000003, 0.000016, 0.000284, 0.000080, 0.000954, 0.002061, 0.000109, 0.000001, 0.000578,
+        0.000577, 0.000696, 0.000101, 0.000075, 0.000203, 0.000000, 0.000057, 0.000000,
+    ],
+    [
+        0.000719, 0.000012, 0.000065, 0.000012, 0.000235, 0.000025, 0.000010, 0.000022, 0.000322,
+        0.000000, 0.000001, 0.000022, 0.000012, 0.000001, 0.000032, 0.000736, 0.000000, 0.000007,
+        0.000035, 0.000671, 0.000010, 0.000012, 0.000054, 0.000000, 0.000009, 0.000000,
+    ],
+    [
+        0.000732, 0.000294, 0.000632, 0.000320, 0.000293, 0.000252, 0.000086, 0.000209, 0.000812,
+        0.000017, 0.000026, 0.000235, 0.000203, 0.000681, 0.003483, 0.002298, 0.000014, 0.000373,
+        0.000968, 0.001635, 0.000270, 0.000110, 0.000752, 0.000000, 0.000084, 0.000006,
+    ],
+    [
+        0.000054, 0.000000, 0.000000, 0.000000, 0.000230, 0.000000, 0.000000, 0.000000, 0.000022,
+        0.000000, 0.000000, 0.000001, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+        0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000009, 0.000001,
+    ],
+];
+
+const BIGRAM_FLOOR: f64 = 1e-7;
+
+/// [`ENGLISH_BIGRAM_FREQUENCIES`] flattened so that `ab` is at index
+/// `26 * a + b`, with frequencies raised to [`BIGRAM_FLOOR`] so that none
+/// is zero.
+const ENGLISH_BIGRAM_FREQUENCIES_FLAT: [f64; 676] = {
+    let mut flat = [0.0; 676];
+    let mut i = 0;
+    while i < 676 {
+        flat[i] = ENGLISH_BIGRAM_FREQUENCIES[i / 26][i % 26].max(BIGRAM_FLOOR);
+        i += 1;
+    }
+    flat
+};
+
+/// A function giving the relative frequency of an n-gram of letter indices,
+/// as returned by [`english_ngram_frequency`].
+pub type NgramFrequency = Box<dyn Fn(&[u8]) -> f64>;
+
+/// Reads an n-gram of letter indices as a number in base 26.
+fn ngram_index(ngram: &[u8]) -> usize {
+    ngram
+        .iter()
+        .fold(0, |index, &letter| index * 26 + usize::from(letter))
+}
+
+/// Returns the relative frequencies of English trigrams (`n = 3`) or
+/// quadgrams (`n = 4`) from [`DOCS_QUADGRAMS`], indexed by
+/// [`ngram_index`]. Trigram counts are the counts of the quadgrams they
+/// start.
+#[allow(clippy::cast_possible_truncation)]
+fn quadgram_table(n: usize) -> Vec<f64> {
+    let mut table = vec![0.0; 26usize.pow(n as u32)];
+    let mut total = 0.0;
+    for &(quadgram, count) in &DOCS_QUADGRAMS {
+        let ngram: Vec<_> = quadgram.bytes().take(n).map(|c| c - b'a').collect();
+        table[ngram_index(&ngram)] += f64::from(count);
+        total += f64::from(count);
+    }
+    for frequency in &mut table {
+        *frequency /= total;
+    }
+    table
+}
+
+/// Returns a function giving the relative frequency in English of an
+/// n-gram of letter indices (a = 0), for n from 1 to 4. Unigrams use
+/// [`ENGLISH_FREQUENCIES`] and bigrams [`ENGLISH_BIGRAM_FREQUENCIES`], while
+/// trigrams and quadgrams come from the quadgrams of
+/// [`NgramModel::english_quadgrams`](crate::ngram::NgramModel::english_quadgrams),
+/// so they share its bias towards technical prose and rarer ones have a
+/// frequency of 0. Only the table for `n` is built. For
+/// other `n`, every n-gram gets the uniform frequency `26^-n`.
+///
+/// The returned function panics if the n-gram is not `n` letters long.
+///
+/// # Example
+/// ```
+/// use cipher_solver::utils;
+///
+/// let trigrams = utils::english_ngram_frequency(3);
+/// assert!(trigrams(&[19, 7, 4]) > trigrams(&[16, 25, 23]));
+/// let unigrams = utils::english_ngram_frequency(1);
+/// assert!((unigrams(&[4]) - utils::ENGLISH_FREQUENCIES[4]).abs() < 1e-10);
+/// ```
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+pub fn english_ngram_frequency(n: usize) -> NgramFrequency {
+    let check = move |ngram: &[u8]| {
+        assert_eq!(ngram.len(), n, "n-gram length must be {n}");
+    };
+    match n {
+        1 => Box::new(move |ngram| {
+            check(ngram);
+            ENGLISH_FREQUENCIES[usize::from(ngram[0])]
+        }),
+        2 => Box::new(move |ngram| {
+            check(ngram);
+            ENGLISH_BIGRAM_FREQUENCIES[usize::from(ngram[0])][usize::from(ngram[1])]
+        }),
+        3 | 4 => {
+            let table = quadgram_table(n);
+            Box::new(move |ngram| {
+                check(ngram);
+                table[ngram_index(ngram)]
+            })
+        }
+        _ => {
+            let uniform = 26f64.powi(-(n as i32));
+            Box::new(move |ngram| {
+                check(ngram);
+                uniform
+            })
+        }
+    }
+}
+
+#[must_use]
 pub fn chi_squared<const N: usize>(observed: &[f64; N], expected: &[f64; N]) -> f64 {
     observed
         .iter()
@@ -17,14 +303,983 @@ pub fn chi_squared<const N: usize>(observed: &[f64; N], expected: &[f64; N]) ->
         .sum()
 }
 
+/// Ranks the letters by descending frequency (0 = most frequent), with ties
+/// broken alphabetically. `result[i]` is the rank of letter `i`.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn frequency_ranks(frequencies: &[f64; 26]) -> [u8; 26] {
+    let mut letters: Vec<usize> = (0..26).collect();
+    letters.sort_by(|&a, &b| frequencies[b].total_cmp(&frequencies[a]));
+    let mut ranks = [0; 26];
+    for (rank, &letter) in letters.iter().enumerate() {
+        ranks[letter] = rank as u8;
+    }
+    ranks
+}
+
+/// The expected frequency used by [`kl_divergence`] in place of zero.
+const KL_FLOOR: f64 = 1e-6;
+
+/// Computes the Kullback–Leibler divergence of the observed distribution `p`
+/// from the expected distribution `q`. Letters never observed contribute
+/// nothing, and expected frequencies of zero are raised to a small floor.
+///
+/// # Example
+/// ```
+/// use cipher_solver::utils::{self, ENGLISH_FREQUENCIES};
+///
+/// assert!(utils::kl_divergence(&ENGLISH_FREQUENCIES, &ENGLISH_FREQUENCIES).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn kl_divergence(p: &[f64; 26], q: &[f64; 26]) -> f64 {
+    p.iter()
+        .zip(q)
+        .filter(|&(&p, _)| p > 0.0)
+        .map(|(&p, &q)| p * (p / q.max(KL_FLOOR)).ln())
+        .sum()
+}
+
+#[must_use]
 pub fn chi_squared_english_score(text: &LowercaseString) -> f64 {
     let observed = text.letter_frequencies();
     chi_squared(&observed, &ENGLISH_FREQUENCIES)
 }
 
+/// Scores how English-like a text is by the average negative log probability
+/// of its bigrams. Lower scores are more English-like.
+#[must_use]
+pub fn bigram_english_score(text: &LowercaseString) -> f64 {
+    let indices = text.to_indices();
+    if indices.len() < 2 {
+        return -BIGRAM_FLOOR.ln();
+    }
+    let total: f64 = indices
+        .windows(2)
+        .map(|pair| {
+            -ENGLISH_BIGRAM_FREQUENCIES[pair[0] as usize][pair[1] as usize]
+                .max(BIGRAM_FLOOR)
+                .ln()
+        })
+        .sum();
+    #[allow(clippy::cast_precision_loss)]
+    let count = (indices.len() - 1) as f64;
+    total / count
+}
+
+/// Scores how English-like a text is by the chi-squared statistic of its
+/// overlapping bigram frequencies against English. Lower scores are more
+/// English-like. Unlike [`chi_squared_english_score`], this rejects texts
+/// made of common letters in uncommon orders.
+///
+/// # Example
+/// ```
+/// use cipher_solver::{utils, LowercaseString};
+///
+/// let english = LowercaseString::normalize("the quick brown fox");
+/// let reversed = LowercaseString::normalize("xof nworb kciuq eht");
+/// assert!(utils::chi_squared_bigram_english_score(&english) < utils::chi_squared_bigram_english_score(&reversed));
+/// ```
+#[must_use]
+pub fn chi_squared_bigram_english_score(text: &LowercaseString) -> f64 {
+    let indices = text.to_indices();
+    let mut observed = [0.0; 676];
+    for pair in indices.windows(2) {
+        observed[usize::from(pair[0]) * 26 + usize::from(pair[1])] += 1.0;
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let count = indices.len().saturating_sub(1).max(1) as f64;
+    for frequency in &mut observed {
+        *frequency /= count;
+    }
+    chi_squared(&observed, &ENGLISH_BIGRAM_FREQUENCIES_FLAT)
+}
+
+/// Renders the plaintext, repeating key and ciphertext in aligned rows, one
+/// letter per column. Shorter rows are padded with spaces.
+///
+/// # Example
+/// ```
+/// use cipher_solver::utils;
+///
+/// let grid = utils::display_alignment("hello", "rijvs", "key");
+/// assert_eq!(grid, "plain: h e l l o\nkey:   k e y k e\ncipher:r i j v s\n");
+/// ```
+#[must_use]
+pub fn display_alignment(plain: &str, cipher: &str, key: &str) -> String {
+    let plain = LowercaseString::normalize(plain).to_string();
+    let cipher = LowercaseString::normalize(cipher).to_string();
+    let key = LowercaseString::normalize(key).to_string();
+    let width = plain.len().max(cipher.len());
+    let key: String = key.chars().cycle().take(width).collect();
+
+    let mut output = String::new();
+    for (label, row) in [("plain:", &plain), ("key:", &key), ("cipher:", &cipher)] {
+        let letters: Vec<_> = row.chars().map(String::from).collect();
+        let padded = format!(
+            "{:width$}",
+            letters.join(" "),
+            width = (2 * width).saturating_sub(1)
+        );
+        writeln!(output, "{label:7}{padded}").unwrap();
+    }
+    output
+}
+
+/// A small deterministic pseudo-random number generator (`SplitMix64`), used
+/// by the randomised solvers so that results are reproducible.
+#[derive(Debug, Clone)]
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in `0..n`.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Returns a value in `[0, 1)`.
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Shuffles the items uniformly with the Fisher–Yates algorithm.
+    pub(crate) fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Parameters for the simulated annealing solvers. The temperature starts at
+/// `initial_temperature` and is multiplied by `cooling_rate` after each of
+/// the `steps` steps. Candidates are scored with `model`, which must have
+/// n-grams of at most 4 letters, or with [`NgramModel::english_quadgrams`]
+/// if it is `None`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolverConfig {
+    pub initial_temperature: f64,
+    pub cooling_rate: f64,
+    pub steps: u32,
+    pub model: Option<NgramModel>,
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        Self {
+            initial_temperature: 10.0,
+            cooling_rate: 0.999_998,
+            steps: 300_000,
+            model: None,
+        }
+    }
+}
+
+impl SolverConfig {
+    /// Returns the n-gram length of the scoring model and its
+    /// [`NgramModel::log_prob_table`].
+    pub(crate) fn scoring_table(&self) -> (usize, Vec<f64>) {
+        let model = self
+            .model
+            .clone()
+            .unwrap_or_else(NgramModel::english_quadgrams);
+        assert!(
+            model.n() <= 4,
+            "the scoring model's n-grams must be at most 4 letters long"
+        );
+        (model.n(), model.log_prob_table())
+    }
+}
+
+/// Computes the average index of coincidence of the interleaved columns for
+/// every key length from 1 to `max_key_len`.
+///
+/// For a Vigenère ciphertext the true key length (and its multiples) stands
+/// out with an IC close to that of English.
+#[must_use]
+pub fn ic_profile(text: &LowercaseString, max_key_len: usize) -> Vec<(usize, f64)> {
+    let means = mean_ic_by_key_length(text, 1..=max_key_len);
+    (1..=max_key_len)
+        .map(|key_len| (key_len, means[&key_len]))
+        .collect()
+}
+
+/// Computes the average index of coincidence of the interleaved columns for
+/// each of the given key lengths, as [`ic_profile`] does.
+///
+/// Letters are counted per column without building the columns, and the
+/// counts are shared between lengths: lengths are handled longest first,
+/// and column `r` of a length that divides one already counted is the sum
+/// of that length's columns congruent to `r`. Only lengths with no counted
+/// multiple take a pass over the text, so for lengths 1 to `n` only those
+/// above `n / 2` do. Repeated lengths are only computed once.
+///
+/// # Example
+/// ```
+/// use cipher_solver::{utils, vigenere, LowercaseString};
+///
+/// let text = "I met a traveller from an antique land, who said: Two vast and \
+///     trunkless legs of stone stand in the desert. Near them, on the sand, \
+///     half sunk a shattered visage lies, whose frown";
+/// let encrypted = LowercaseString::normalize(&vigenere::encrypt(text, "dog").unwrap());
+/// let means = utils::mean_ic_by_key_length(&encrypted, [2, 3, 4].into_iter());
+/// assert!(means[&3] > means[&2] && means[&3] > means[&4]);
+/// ```
+#[must_use]
+pub fn mean_ic_by_key_length(
+    text: &LowercaseString,
+    lengths: impl Iterator<Item = usize>,
+) -> HashMap<usize, f64> {
+    let mut lengths: Vec<_> = lengths
+        .inspect(|&key_len| assert!(key_len > 0, "key length must be positive"))
+        .collect();
+    lengths.sort_unstable_by(|a, b| b.cmp(a));
+    lengths.dedup();
+
+    let indices = text.to_indices();
+    let mut counted: Vec<(usize, Vec<[usize; 26]>)> = Vec::new();
+    let mut means = HashMap::new();
+    for key_len in lengths {
+        let mut counts = vec![[0usize; 26]; key_len];
+        // The most recently counted multiple is the shortest.
+        match counted.iter().rev().find(|(len, _)| len % key_len == 0) {
+            Some((_, multiple)) => {
+                for (i, column) in multiple.iter().enumerate() {
+                    for (total, &n) in counts[i % key_len].iter_mut().zip(column) {
+                        *total += n;
+                    }
+                }
+            }
+            None => {
+                for (i, &c) in indices.iter().enumerate() {
+                    counts[i % key_len][c as usize] += 1;
+                }
+            }
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let sum: f64 = counts
+            .iter()
+            .map(|column| {
+                let total: usize = column.iter().sum();
+                if total < 2 {
+                    return 0.0;
+                }
+                let matching: usize = column.iter().map(|&n| n * n.saturating_sub(1)).sum();
+                matching as f64 / (total * (total - 1)) as f64
+            })
+            .sum();
+        #[allow(clippy::cast_precision_loss)]
+        means.insert(key_len, sum / key_len as f64);
+        counted.push((key_len, counts));
+    }
+    means
+}
+
+/// Finds the distances between consecutive occurrences of every repeated
+/// n-gram, sorted ascending. This is the core of Kasiski examination: in a
+/// Vigenère ciphertext most distances are multiples of the key length.
+///
+/// # Example
+/// ```
+/// use cipher_solver::{utils, LowercaseString};
+///
+/// let text = LowercaseString::normalize("abcxxabcyyyabc");
+/// assert_eq!(utils::repetition_distances(&text, 3), [5, 6]);
+/// ```
+#[must_use]
+pub fn repetition_distances(text: &LowercaseString, n: usize) -> Vec<usize> {
+    assert!(n >= 2, "n-grams must have at least 2 letters");
+    let mut distances: Vec<_> = text
+        .repeated_substrings_of_len(n)
+        .into_values()
+        .flat_map(|positions| {
+            positions
+                .windows(2)
+                .map(|pair| pair[1] - pair[0])
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    distances.sort_unstable();
+    distances
+}
+
+/// Computes the greatest common divisor with Euclid's algorithm.
+fn gcd_usize(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd_usize(b, a % b)
+    }
+}
+
+/// Computes the greatest common divisor of two numbers. `gcd(0, 0)` is 0.
+///
+/// # Example
+/// ```
+/// use cipher_solver::utils;
+///
+/// assert_eq!(utils::gcd(12, 18), 6);
+/// assert_eq!(utils::gcd(7, 26), 1);
+/// ```
+#[must_use]
+pub fn gcd(a: u8, b: u8) -> u8 {
+    u8::try_from(gcd_usize(a.into(), b.into())).unwrap()
+}
+
+/// Runs the extended Euclidean algorithm, returning `(gcd, x, y)` such that
+/// `a * x + b * y == gcd`. The gcd is never negative.
+///
+/// # Example
+/// ```
+/// use cipher_solver::utils;
+///
+/// let (gcd, x, y) = utils::extended_gcd(7, 26);
+/// assert_eq!(gcd, 1);
+/// assert_eq!(7 * x + 26 * y, 1);
+/// ```
+#[must_use]
+pub fn extended_gcd(a: i32, b: i32) -> (i32, i32, i32) {
+    // Each remainder is a * x + b * y for its pair of coefficients.
+    let (mut previous, mut remainder) = ((a, 1, 0), (b, 0, 1));
+    while remainder.0 != 0 {
+        let quotient = previous.0 / remainder.0;
+        let next = (
+            previous.0 - quotient * remainder.0,
+            previous.1 - quotient * remainder.1,
+            previous.2 - quotient * remainder.2,
+        );
+        (previous, remainder) = (remainder, next);
+    }
+    let (gcd, x, y) = previous;
+    if gcd < 0 {
+        (-gcd, -x, -y)
+    } else {
+        (gcd, x, y)
+    }
+}
+
+/// Returns the inverse of `a` modulo `modulus`, or `None` if they are not
+/// coprime.
+///
+/// # Example
+/// ```
+/// use cipher_solver::utils;
+///
+/// assert_eq!(utils::modular_inverse(7, 26), Some(15));
+/// assert_eq!(utils::modular_inverse(13, 26), None);
+/// ```
+#[must_use]
+pub fn modular_inverse(a: u8, modulus: u8) -> Option<u8> {
+    if modulus == 0 {
+        return None;
+    }
+    let (gcd, x, _) = extended_gcd(a.into(), modulus.into());
+    (gcd == 1).then(|| u8::try_from(x.rem_euclid(modulus.into())).unwrap())
+}
+
+/// Returns the greatest common divisor of the [`repetition_distances`] of
+/// the n-grams of the given length, the Kasiski estimate of the Vigenère key
+/// length, or `None` if no n-gram repeats.
+///
+/// A single repeat by chance can bring the result down to 1, so longer
+/// n-grams give more reliable estimates.
+///
+/// # Example
+/// ```
+/// use cipher_solver::{utils, LowercaseString};
+///
+/// let text = LowercaseString::normalize("abcdefabcghijklabc");
+/// assert_eq!(utils::repeated_substring_gcd(&text, 3), Some(3));
+/// ```
+#[must_use]
+pub fn repeated_substring_gcd(text: &LowercaseString, ngram_len: usize) -> Option<usize> {
+    repetition_distances(text, ngram_len)
+        .into_iter()
+        .reduce(gcd_usize)
+}
+
+/// Computes the determinant of a 3×3 matrix modulo 26.
+///
+/// # Example
+/// ```
+/// use cipher_solver::utils;
+///
+/// assert_eq!(utils::det_mod26([[6, 24, 1], [13, 16, 10], [20, 17, 15]]), 25);
+/// ```
+#[must_use]
+pub fn det_mod26(matrix: [[u8; 3]; 3]) -> u8 {
+    let m = matrix.map(|row| row.map(i64::from));
+    let det = m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+    u8::try_from(det.rem_euclid(26)).unwrap()
+}
+
+/// Computes the adjugate (the transposed matrix of cofactors) of a 3×3
+/// matrix modulo 26. Multiplying it by the inverse of the determinant gives
+/// the inverse of the matrix.
+///
+/// # Example
+/// ```
+/// use cipher_solver::utils;
+///
+/// let adjugate = utils::adjugate_mod26([[6, 24, 1], [13, 16, 10], [20, 17, 15]]);
+/// assert_eq!(adjugate, [[18, 21, 16], [5, 18, 5], [5, 14, 18]]);
+/// ```
+#[must_use]
+pub fn adjugate_mod26(matrix: [[u8; 3]; 3]) -> [[u8; 3]; 3] {
+    let m = matrix.map(|row| row.map(i64::from));
+    // Taking the other rows and columns cyclically gives each cofactor its
+    // sign.
+    let cofactor = |row: usize, column: usize| {
+        let (r1, r2) = ((row + 1) % 3, (row + 2) % 3);
+        let (c1, c2) = ((column + 1) % 3, (column + 2) % 3);
+        let minor = m[r1][c1] * m[r2][c2] - m[r1][c2] * m[r2][c1];
+        u8::try_from(minor.rem_euclid(26)).unwrap()
+    };
+    std::array::from_fn(|i| std::array::from_fn(|j| cofactor(j, i)))
+}
+
+/// Inverts a 3×3 matrix modulo 26: the adjugate multiplied by the inverse of
+/// the determinant. Returns `None` if the determinant shares a factor with
+/// 26.
+///
+/// # Example
+/// ```
+/// use cipher_solver::utils;
+///
+/// let inverse = utils::matrix_inverse_mod26_3x3([[6, 24, 1], [13, 16, 10], [20, 17, 15]]);
+/// assert_eq!(inverse, Some([[8, 5, 10], [21, 8, 21], [21, 12, 8]]));
+/// assert_eq!(utils::matrix_inverse_mod26_3x3([[2, 0, 0], [0, 1, 0], [0, 0, 1]]), None);
+/// ```
+#[must_use]
+pub fn matrix_inverse_mod26_3x3(matrix: [[u8; 3]; 3]) -> Option<[[u8; 3]; 3]> {
+    let matrix = matrix.map(|row| row.map(|x| x % 26));
+    let inverse = u32::from(modular_inverse(det_mod26(matrix), 26)?);
+    #[allow(clippy::cast_possible_truncation)]
+    let entry = |x: u8| (u32::from(x) * inverse % 26) as u8;
+    Some(adjugate_mod26(matrix).map(|row| row.map(entry)))
+}
+
+/// Friedman's phi (kappa) test: the chance that two letters drawn from the
+/// text without replacement are the same. It equals the index of
+/// coincidence, and is about 0.065 for English and 0.038 for random text.
+#[must_use]
+pub fn phi_test(text: &LowercaseString) -> f64 {
+    text.index_of_coincidence()
+}
+
+/// Averages [`phi_test`] over the `n_cols` interleaved columns of a text.
+/// For a Vigenère ciphertext, a key length of `n_cols` gives a value close
+/// to that of English. This is the value [`mean_ic_by_key_length`] gives
+/// for a key length of `n_cols`.
+///
+/// # Panics
+/// Panics if `n_cols` is 0.
+#[must_use]
+pub fn phi_test_for_columns(text: &LowercaseString, n_cols: usize) -> f64 {
+    mean_ic_by_key_length(text, std::iter::once(n_cols))[&n_cols]
+}
+
+/// Computes the mutual index of coincidence of two texts: the probability
+/// that a letter drawn from each is the same. Returns 0 if either is empty.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn mutual_ic(a: &LowercaseString, b: &LowercaseString) -> f64 {
+    let total = a.to_indices().len() * b.to_indices().len();
+    if total == 0 {
+        return 0.0;
+    }
+    let matching: usize = a
+        .letter_counts()
+        .iter()
+        .zip(b.letter_counts())
+        .map(|(&n, m)| n * m)
+        .sum();
+    matching as f64 / total as f64
+}
+
+/// Compares a text with a copy of itself shifted by `shift` letters,
+/// returning the fraction of overlapping positions with the same letter,
+/// scaled by 26. Random text gives about 1 and English about 1.7.
+///
+/// This is [`LowercaseString::autocorrelation`] on the scale of the
+/// normalized index of coincidence. For a Vigenère ciphertext it peaks when
+/// `shift` is a multiple of the key length.
+///
+/// # Example
+/// ```
+/// use cipher_solver::{utils, LowercaseString};
+///
+/// let text = LowercaseString::normalize("abcabd");
+/// assert!((utils::coincidence_index_of_shift(&text, 3) - 26.0 * 2.0 / 3.0).abs() < 1e-10);
+/// ```
+#[must_use]
+pub fn coincidence_index_of_shift(text: &LowercaseString, shift: usize) -> f64 {
+    26.0 * text.autocorrelation(shift)
+}
+
+/// Builds a mixed alphabet from a keyword, as
+/// [`LowercaseString::keyword_mixed_alphabet`] does.
+///
+/// # Example
+/// ```
+/// use cipher_solver::utils;
+///
+/// let alphabet = utils::keyword_alphabet("zebras");
+/// assert_eq!(alphabet.to_string(), "zebrascdfghijklmnopqtuvwxy");
+/// ```
+#[must_use]
+pub fn keyword_alphabet(keyword: &str) -> LowercaseString {
+    LowercaseString::keyword_mixed_alphabet(keyword)
+}
+
+/// Builds the substitution key whose cipher alphabet is
+/// [`keyword_alphabet`] of the keyword.
+#[must_use]
+pub fn keyword_alphabet_permutation(keyword: &str) -> SubstitutionKey {
+    SubstitutionKey::from_alphabet(&keyword_alphabet(keyword)).unwrap()
+}
+
+/// Aligns a probable plaintext word against every position of a
+/// monoalphabetic substitution ciphertext, keeping the positions where the
+/// implied partial key is consistent: each letter of the word maps to one
+/// cipher letter, and no two letters to the same one. Returns each such
+/// position with its partial key, where `key[i]` is the cipher letter of
+/// plaintext letter `i`, if the word fixes it.
+///
+/// # Example
+/// ```
+/// use cipher_solver::{utils, LowercaseString};
+///
+/// let ciphertext = LowercaseString::normalize("xqzzqy");
+/// let word = LowercaseString::normalize("hello");
+/// let matches = utils::probable_word_attack(&ciphertext, &word);
+/// assert_eq!(matches.len(), 0);
+///
+/// let word = LowercaseString::normalize("see");
+/// let matches = utils::probable_word_attack(&ciphertext, &word);
+/// assert_eq!(matches.len(), 1);
+/// let (position, key) = matches[0];
+/// assert_eq!(position, 1);
+/// assert_eq!((key[18], key[4]), (Some(16), Some(25)));
+/// ```
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn probable_word_attack(
+    ciphertext: &LowercaseString,
+    word: &LowercaseString,
+) -> Vec<(usize, [Option<u8>; 26])> {
+    let word = word.to_indices();
+    if word.is_empty() {
+        return Vec::new();
+    }
+    ciphertext
+        .to_indices()
+        .windows(word.len())
+        .enumerate()
+        .filter_map(|(position, window)| {
+            let mut key = [None; 26];
+            let mut inverse = [None; 26];
+            for (&plain, &cipher) in word.iter().zip(window) {
+                let (p, c) = (plain as usize, cipher as usize);
+                match (key[p], inverse[c]) {
+                    (None, None) => {
+                        key[p] = Some(cipher);
+                        inverse[c] = Some(plain);
+                    }
+                    (Some(k), Some(_)) if k == cipher => {}
+                    _ => return None,
+                }
+            }
+            Some((position, key))
+        })
+        .collect()
+}
+
+/// Finds the Caesar shift that was most likely used to encrypt `text`,
+/// returning it with the chi-squared score of the decryption.
+pub(crate) fn best_shift(text: &LowercaseString) -> (u8, f64) {
+    (0..26u8)
+        .map(|shift| {
+            let decrypted = text.caesar_shift(-i32::from(shift));
+            (shift, chi_squared_english_score(&decrypted))
+        })
+        .min_by(|(_, score1), (_, score2)| score1.total_cmp(score2))
+        .unwrap()
+}
+
+/// Scores `trials` uniformly random texts of `len` letters, returning the
+/// mean and standard deviation of the scores.
+fn baseline<F: Fn(&LowercaseString) -> f64>(
+    len: usize,
+    trials: u32,
+    seed: u64,
+    scorer: F,
+) -> (f64, f64) {
+    assert!(trials > 0, "trials must be positive");
+    let mut rng = Rng::new(seed);
+    let results: Vec<_> = (0..trials)
+        .map(|_| {
+            #[allow(clippy::cast_possible_truncation)]
+            let text = (0..len).map(|_| rng.below(26) as u8).collect();
+            scorer(&LowercaseString::from_indices(text))
+        })
+        .collect();
+    let trials = f64::from(trials);
+    let mean = results.iter().sum::<f64>() / trials;
+    let variance = results.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / trials;
+    (mean, variance.sqrt())
+}
+
+/// Calibration utility: returns the mean and standard deviation of
+/// [`chi_squared_english_score`] over `trials` random texts of `len`
+/// letters, generated deterministically from `seed`.
+///
+/// A decryption scoring below `mean - 2 * std_dev` is very unlikely to be
+/// random, and so very likely correct.
+///
+/// # Panics
+/// Panics if `trials` is 0.
+///
+/// # Example
+/// ```
+/// use cipher_solver::{utils, LowercaseString};
+///
+/// let text = LowercaseString::normalize("the quick brown fox jumps over the lazy dog");
+/// let (mean, std_dev) = utils::baseline_chi_squared(35, 100, 1);
+/// assert!(utils::chi_squared_english_score(&text) < mean - std_dev);
+/// ```
+#[must_use]
+pub fn baseline_chi_squared(len: usize, trials: u32, seed: u64) -> (f64, f64) {
+    baseline(len, trials, seed, chi_squared_english_score)
+}
+
+/// Calibration utility: returns the mean and standard deviation of the
+/// index of coincidence over `trials` random texts of `len` letters,
+/// generated deterministically from `seed`.
+///
+/// # Panics
+/// Panics if `trials` is 0.
+#[must_use]
+pub fn baseline_ic(len: usize, trials: u32, seed: u64) -> (f64, f64) {
+    baseline(len, trials, seed, LowercaseString::index_of_coincidence)
+}
+
+/// What the letter frequencies of a text suggest about how it was
+/// encrypted, as found by [`letter_distribution_test`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DistributionType {
+    /// English letter frequencies: no cipher, or a transposition.
+    NearEnglish,
+    /// Letters close to equally likely: a Vigenère cipher with a long key,
+    /// or a one-time pad.
+    NearUniform,
+    /// The index of coincidence of English with the wrong letters, such as
+    /// a Caesar or substitution cipher.
+    Monoalphabetic,
+    /// Too few letters, or an index of coincidence between the others.
+    Unknown,
+}
+
+/// Above this index of coincidence, a text is treated as monoalphabetic.
+const MONOALPHABETIC_IC: f64 = 0.050;
+
+/// Below this index of coincidence, a text is treated as polyalphabetic.
+const POLYALPHABETIC_IC: f64 = 0.045;
+
+/// Below this [`chi_squared_english_score`], a monoalphabetic text already
+/// has English letter frequencies.
+const ENGLISH_CHI_SQUARED: f64 = 0.5;
+
+/// Makes a first guess at the kind of cipher behind a text. An index of
+/// coincidence above 0.050 means each letter always encrypts to the same
+/// letter, and then [`chi_squared_english_score`] tells English, which
+/// includes transpositions, from a monoalphabetic cipher. One below 0.045,
+/// close to the 0.038 of random text, means letters are nearly uniform.
+///
+/// # Example
+/// ```
+/// use cipher_solver::utils::{self, DistributionType};
+/// use cipher_solver::{caesar, LowercaseString};
+///
+/// let text = "I met a traveller from an antique land, who said: Two vast and \
+///     trunkless legs of stone stand in the desert. Near them, on the sand";
+/// let english = LowercaseString::normalize(text);
+/// let shifted = LowercaseString::normalize(&caesar::encrypt(text, 3));
+/// assert_eq!(utils::letter_distribution_test(&english), DistributionType::NearEnglish);
+/// assert_eq!(utils::letter_distribution_test(&shifted), DistributionType::Monoalphabetic);
+/// ```
+#[must_use]
+pub fn letter_distribution_test(text: &LowercaseString) -> DistributionType {
+    if text.to_indices().len() < 2 {
+        return DistributionType::Unknown;
+    }
+    let ic = text.index_of_coincidence();
+    if ic > MONOALPHABETIC_IC {
+        if chi_squared_english_score(text) < ENGLISH_CHI_SQUARED {
+            DistributionType::NearEnglish
+        } else {
+            DistributionType::Monoalphabetic
+        }
+    } else if ic < POLYALPHABETIC_IC {
+        DistributionType::NearUniform
+    } else {
+        DistributionType::Unknown
+    }
+}
+
+/// A candidate cipher for a ciphertext, with a confidence between 0 and 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CipherGuess {
+    pub cipher: CipherKind,
+    pub confidence: f64,
+}
+
+/// Identifies which cipher was most likely used to produce a ciphertext.
+pub struct Classifier;
+
+impl Classifier {
+    const MAX_KEY_LEN: usize = 20;
+
+    /// Returns candidate ciphers, with estimated keys, ranked by descending
+    /// confidence.
+    ///
+    /// An index of coincidence above 0.060 points to a monoalphabetic cipher
+    /// (a shift of 0 meaning no cipher at all), while one below 0.045 points
+    /// to a polyalphabetic cipher. Each candidate is then weighted by how
+    /// English-like its decryption is. A monoalphabetic text that no Caesar
+    /// shift makes English-like is guessed to be a general substitution,
+    /// with the key matching letters by frequency rank, which is only a
+    /// starting point for a solver such as
+    /// [`SubstitutionSolver`](crate::substitution::SubstitutionSolver).
+    ///
+    /// ADFGVX ciphertexts are not recognized, as the crate has no ADFGVX
+    /// cipher to guess.
+    ///
+    /// # Example
+    /// ```
+    /// use cipher_solver::cipher_kind::CipherKind;
+    /// use cipher_solver::utils::Classifier;
+    /// use cipher_solver::caesar;
+    ///
+    /// let text = "I met a traveller from an antique land who said two vast and \
+    ///     trunkless legs of stone stand in the desert";
+    /// let guesses = Classifier::classify(&caesar::encrypt(text, 5));
+    /// assert_eq!(guesses[0].cipher, CipherKind::Caesar(5));
+    /// ```
+    #[must_use]
+    pub fn classify(text: &str) -> Vec<CipherGuess> {
+        let text = LowercaseString::normalize(text);
+        let monoalphabetic =
+            ((text.index_of_coincidence() - 0.045) / (0.060 - 0.045)).clamp(0.0, 1.0);
+        let fit = |score: f64| 1.0 / (1.0 + score);
+
+        let (shift, caesar_score) = best_shift(&text);
+        let mut guesses = vec![CipherGuess {
+            cipher: CipherKind::Caesar(i32::from(shift)),
+            confidence: monoalphabetic * fit(caesar_score),
+        }];
+
+        let key = SubstitutionKey::from_frequency_ranks(&text.frequency_rank(), Language::English);
+        let score = chi_squared_english_score(&text.apply_substitution_inverse(&key));
+        guesses.push(CipherGuess {
+            cipher: CipherKind::Substitution(key),
+            confidence: monoalphabetic * fit(score) * (caesar_score / ENGLISH_CHI_SQUARED).min(1.0),
+        });
+
+        if let Some(key) = vigenere::estimate_key(&text, Self::MAX_KEY_LEN) {
+            if key.to_indices().len() > 1 {
+                let decrypted = vigenere::decrypt(text.to_string(), key.to_string()).unwrap();
+                let score = chi_squared_english_score(&LowercaseString::normalize(&decrypted));
+                guesses.push(CipherGuess {
+                    cipher: CipherKind::Vigenere(key.to_string()),
+                    confidence: (1.0 - monoalphabetic) * fit(score),
+                });
+            }
+        }
+
+        guesses.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        guesses
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::columnar;
+    use crate::test_data::{random_substitution_key, OZYMANDIAS};
+    use crate::vigenere;
+
+    #[test]
+    fn test_english_ngram_frequency() {
+        let unigrams = english_ngram_frequency(1);
+        assert!((unigrams(&[25]) - ENGLISH_FREQUENCIES[25]).abs() < 1e-10);
+        let bigrams = english_ngram_frequency(2);
+        assert!((bigrams(&[19, 7]) - ENGLISH_BIGRAM_FREQUENCIES[19][7]).abs() < 1e-10);
+
+        let trigrams = english_ngram_frequency(3);
+        let quadgrams = english_ngram_frequency(4);
+        let tion = [19, 8, 14, 13];
+        let total: u32 = DOCS_QUADGRAMS.iter().map(|&(_, count)| count).sum();
+        assert!((quadgrams(&tion) - 16292.0 / f64::from(total)).abs() < 1e-10);
+        // "the" starts "ther", "thes", "thec", ...
+        assert!(trigrams(&[19, 7, 4]) > quadgrams(&[19, 7, 4, 17]));
+        assert!(quadgrams(&[16, 16, 16, 16]).abs() < 1e-10);
+        let trigram_total: f64 = (0..26 * 26 * 26)
+            .map(|i: u16| {
+                let ngram =
+                    [i / 676, i / 26 % 26, i % 26].map(|letter| u8::try_from(letter).unwrap());
+                trigrams(&ngram)
+            })
+            .sum();
+        assert!((trigram_total - 1.0).abs() < 1e-10);
+
+        assert!((english_ngram_frequency(5)(&[0; 5]) - 26f64.powi(-5)).abs() < 1e-20);
+        assert!((english_ngram_frequency(0)(&[]) - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    #[should_panic(expected = "n-gram length must be 2")]
+    fn test_english_ngram_frequency_wrong_length() {
+        let _ = english_ngram_frequency(2)(&[0, 1, 2]);
+    }
+
+    #[test]
+    fn test_baseline() {
+        let pangram = LowercaseString::normalize("the quick brown fox jumps over the lazy dog");
+        let (mean, std_dev) = baseline_chi_squared(35, 200, 7);
+        assert!(chi_squared_english_score(&pangram) < mean);
+        assert!(std_dev > 0.0);
+        assert_eq!(baseline_chi_squared(35, 200, 7), (mean, std_dev));
+
+        let (mean, std_dev) = baseline_ic(500, 200, 7);
+        assert!((mean - 1.0 / 26.0).abs() < 0.002);
+        assert!(std_dev < 0.005);
+    }
+
+    #[test]
+    #[should_panic(expected = "trials must be positive")]
+    fn test_baseline_no_trials() {
+        let _ = baseline_ic(10, 0, 7);
+    }
+
+    #[test]
+    fn test_letter_distribution_test() {
+        let english = LowercaseString::normalize(OZYMANDIAS);
+        let transposed =
+            LowercaseString::normalize(&columnar::encrypt(OZYMANDIAS, "zebras").unwrap());
+        let substituted = english.apply_substitution(&random_substitution_key(&mut Rng::new(195)));
+        let polyalphabetic = LowercaseString::normalize(
+            &vigenere::encrypt(OZYMANDIAS, "thelongestkeyinworld").unwrap(),
+        );
+        for (text, expected) in [
+            (&english, DistributionType::NearEnglish),
+            (&transposed, DistributionType::NearEnglish),
+            (&english.caesar_shift(1), DistributionType::Monoalphabetic),
+            (&substituted, DistributionType::Monoalphabetic),
+            (&polyalphabetic, DistributionType::NearUniform),
+        ] {
+            assert_eq!(letter_distribution_test(text), expected);
+        }
+
+        // Every letter equally often, and then enough extra 'e's to lift the
+        // index of coincidence to 0.048, between the thresholds.
+        let alphabet = "abcdefghijklmnopqrstuvwxyz";
+        let uniform = LowercaseString::normalize(&alphabet.repeat(10));
+        assert_eq!(
+            letter_distribution_test(&uniform),
+            DistributionType::NearUniform
+        );
+        let between = LowercaseString::normalize(&(alphabet.repeat(4) + &"e".repeat(16)));
+        assert_eq!(
+            letter_distribution_test(&between),
+            DistributionType::Unknown
+        );
+        assert_eq!(
+            letter_distribution_test(&LowercaseString::normalize("e")),
+            DistributionType::Unknown
+        );
+    }
+
+    #[test]
+    fn test_language_frequencies() {
+        for language in [
+            Language::English,
+            Language::French,
+            Language::German,
+            Language::Spanish,
+        ] {
+            let total: f64 = language.frequencies().iter().sum();
+            assert!((total - 1.0).abs() < 0.001);
+        }
+    }
+
+    #[test]
+    fn test_repetition_distances() {
+        let encrypted = vigenere::encrypt(OZYMANDIAS, "crown").unwrap();
+        let distances = repetition_distances(&LowercaseString::normalize(&encrypted), 3);
+        let multiples = distances.iter().filter(|&&d| d % 5 == 0).count();
+        assert!(multiples * 2 > distances.len());
+
+        let unique = LowercaseString::normalize("abcdefghijklmnopqrstuvwxyz");
+        assert_eq!(repetition_distances(&unique, 2), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_phi_test() {
+        let text = LowercaseString::normalize(OZYMANDIAS);
+        assert!((phi_test(&text) - text.index_of_coincidence()).abs() < 1e-10);
+        assert!(phi_test(&text) > 0.06);
+
+        let encrypted =
+            LowercaseString::normalize(&vigenere::encrypt(OZYMANDIAS, "pharaoh").unwrap());
+        assert!(phi_test(&encrypted) < 0.05);
+        assert!(phi_test_for_columns(&encrypted, 7) > 0.06);
+        assert!((phi_test_for_columns(&encrypted, 1) - phi_test(&encrypted)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_mutual_ic() {
+        let alphabet = LowercaseString::normalize("abcdefghijklmnopqrstuvwxyz");
+        assert!((mutual_ic(&alphabet, &alphabet) - 1.0 / 26.0).abs() < 1e-10);
+        let a = LowercaseString::normalize("aaaa");
+        let ab = LowercaseString::normalize("abab");
+        assert!((mutual_ic(&a, &ab) - 0.5).abs() < 1e-10);
+        assert!(mutual_ic(&a, &LowercaseString::normalize("")).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_coincidence_index_of_shift() {
+        let encrypted =
+            LowercaseString::normalize(&vigenere::encrypt(OZYMANDIAS, "crown").unwrap());
+        let (peaks, others): (Vec<_>, Vec<_>) = (1..=20)
+            .map(|shift| (shift, coincidence_index_of_shift(&encrypted, shift)))
+            .partition(|&(shift, _)| shift % 5 == 0);
+        // Single shifts are noisy on a text this short, so compare averages.
+        #[allow(clippy::cast_precision_loss)]
+        let mean = |values: &[(usize, f64)]| {
+            values.iter().map(|&(_, ic)| ic).sum::<f64>() / values.len() as f64
+        };
+        assert!(mean(&peaks) > 1.3);
+        assert!(mean(&others) < 1.1);
+        assert!(others[..4].iter().all(|&(_, ic)| ic < peaks[0].1));
+
+        let text = LowercaseString::normalize("abc");
+        assert!((coincidence_index_of_shift(&text, 0) - 26.0).abs() < 1e-10);
+        assert!(coincidence_index_of_shift(&text, 3).abs() < 1e-10);
+    }
 
     #[test]
     fn test_chi_squared() {
@@ -43,4 +1298,288 @@ mod tests {
         let gibberish_result = chi_squared_english_score(&gibberish);
         assert!(english_result < gibberish_result);
     }
+
+    #[test]
+    fn test_bigram_english_score() {
+        let english = LowercaseString::normalize("the quick brown fox jumps over the lazy dog");
+        let gibberish = LowercaseString::normalize("qzxjvkqpzwxjqvbkzq");
+        assert!(bigram_english_score(&english) < bigram_english_score(&gibberish));
+
+        let total: f64 = ENGLISH_BIGRAM_FREQUENCIES.iter().flatten().sum();
+        assert!((total - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_chi_squared_bigram_english_score() {
+        let english = LowercaseString::normalize("the quick brown fox jumps over the lazy dog");
+        let gibberish = LowercaseString::normalize("thxqz");
+        let word = LowercaseString::normalize("thing");
+        assert!(
+            chi_squared_bigram_english_score(&english)
+                < chi_squared_bigram_english_score(&gibberish)
+        );
+        assert!(
+            chi_squared_bigram_english_score(&word) * 100.0
+                < chi_squared_bigram_english_score(&gibberish)
+        );
+
+        // Shifting every other letter keeps the letters plausible on their
+        // own but breaks up the bigrams.
+        let text = LowercaseString::normalize(OZYMANDIAS);
+        let mixed = text.shift_columns(&[0, 13]);
+        assert!(chi_squared_bigram_english_score(&text) < chi_squared_bigram_english_score(&mixed));
+
+        // A text with no bigrams scores the total expected frequency.
+        let expected: f64 = ENGLISH_BIGRAM_FREQUENCIES_FLAT.iter().sum();
+        let empty = chi_squared_bigram_english_score(&LowercaseString::normalize("a"));
+        assert!((empty - expected).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_rng_deterministic() {
+        let mut rng1 = Rng::new(42);
+        let mut rng2 = Rng::new(42);
+        for _ in 0..100 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+            assert!(rng1.below(26) < 26);
+            let x = rng2.next_f64();
+            assert!((0.0..1.0).contains(&x));
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        let guesses = Classifier::classify(OZYMANDIAS);
+        assert_eq!(guesses[0].cipher, CipherKind::Caesar(0));
+        assert!(guesses[0].confidence > 0.5);
+
+        let encrypted = crate::caesar::encrypt(OZYMANDIAS, 17);
+        let guesses = Classifier::classify(&encrypted);
+        assert_eq!(guesses[0].cipher, CipherKind::Caesar(17));
+
+        let encrypted = vigenere::encrypt(OZYMANDIAS, "sonnet").unwrap();
+        let guesses = Classifier::classify(&encrypted);
+        assert_eq!(
+            guesses[0].cipher,
+            CipherKind::Vigenere("sonnet".to_string())
+        );
+        assert!(guesses
+            .windows(2)
+            .all(|w| w[0].confidence >= w[1].confidence));
+
+        let key = random_substitution_key(&mut Rng::new(18));
+        let encrypted = crate::substitution::encrypt(OZYMANDIAS, &key);
+        let guesses = Classifier::classify(&encrypted);
+        let CipherKind::Substitution(guessed_key) = &guesses[0].cipher else {
+            panic!("expected a substitution guess, got {:?}", guesses[0].cipher);
+        };
+        let decrypted =
+            LowercaseString::normalize(&encrypted).apply_substitution_inverse(guessed_key);
+        assert!(chi_squared_english_score(&decrypted) < ENGLISH_CHI_SQUARED);
+        // Caesar text is still taken for Caesar.
+        let encrypted = crate::caesar::encrypt(OZYMANDIAS, 9);
+        assert_eq!(
+            Classifier::classify(&encrypted)[0].cipher,
+            CipherKind::Caesar(9)
+        );
+    }
+
+    #[test]
+    fn test_ic_profile() {
+        let encrypted = vigenere::encrypt(OZYMANDIAS, "sonet").unwrap();
+        let profile = ic_profile(&LowercaseString::normalize(&encrypted), 12);
+        assert_eq!(profile.len(), 12);
+        assert_eq!(profile[0].0, 1);
+
+        let (best, _) = profile
+            .iter()
+            .max_by(|(_, ic1), (_, ic2)| ic1.total_cmp(ic2))
+            .unwrap();
+        assert_eq!(best % 5, 0);
+        assert!(profile[4].1 > profile[3].1 && profile[4].1 > profile[5].1);
+
+        assert!(ic_profile(&LowercaseString::normalize(&encrypted), 0).is_empty());
+    }
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(0, 9), 9);
+        assert_eq!(gcd(255, 85), 85);
+        assert_eq!(extended_gcd(0, 0), (0, 1, 0));
+        assert_eq!(extended_gcd(-4, 6).0, 2);
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+    }
+
+    #[test]
+    fn test_modular_inverse() {
+        let invertible: Vec<_> = (0..26)
+            .filter(|&a| modular_inverse(a, 26).is_some())
+            .collect();
+        assert_eq!(invertible, [1, 3, 5, 7, 9, 11, 15, 17, 19, 21, 23, 25]);
+        assert_eq!(modular_inverse(25, 26), Some(25));
+        assert_eq!(modular_inverse(3, 1), Some(0));
+        assert_eq!(modular_inverse(3, 0), None);
+        assert_eq!(modular_inverse(200, 251), Some(187));
+    }
+
+    #[test]
+    fn test_repeated_substring_gcd() {
+        // Consecutive repeats of "abcdefg" are 7 and 14 letters apart.
+        let text = LowercaseString::normalize("abcdefgabcdefghijklmnabcdefgopqrstuabcdefg");
+        let distances = repetition_distances(&text, 3);
+        assert_eq!(distances.len(), 15);
+        assert!(distances.iter().all(|d| d % 7 == 0));
+        assert_eq!(repeated_substring_gcd(&text, 3), Some(7));
+        assert_eq!(repeated_substring_gcd(&text, 7), Some(7));
+
+        let unique = LowercaseString::normalize("abcdefghijklmnopqrstuvwxyz");
+        assert_eq!(repeated_substring_gcd(&unique, 2), None);
+    }
+
+    #[test]
+    fn test_probable_word_attack() {
+        let mut rng = Rng::new(4);
+        let key = random_substitution_key(&mut rng);
+        // Every window but "the" repeats a letter, so cannot match it.
+        let plaintext = LowercaseString::normalize("Tthee, eeettt");
+        let ciphertext = plaintext.apply_substitution(&key);
+
+        let matches = probable_word_attack(&ciphertext, &LowercaseString::normalize("the"));
+        assert_eq!(matches.len(), 1);
+        let (position, partial) = matches[0];
+        assert_eq!(position, 1);
+        for (letter, &cipher) in partial.iter().enumerate() {
+            if [19, 7, 4].contains(&letter) {
+                assert_eq!(cipher, Some(key[letter]));
+            } else {
+                assert_eq!(cipher, None);
+            }
+        }
+
+        let text = LowercaseString::normalize(OZYMANDIAS).apply_substitution(&key);
+        let matches = probable_word_attack(&text, &LowercaseString::normalize("colossal"));
+        assert!(!matches.is_empty());
+        assert!(matches
+            .iter()
+            .all(|(_, partial)| partial[14] == Some(key[14])));
+        assert!(probable_word_attack(&text, &LowercaseString::normalize("")).is_empty());
+    }
+
+    #[test]
+    fn test_kl_divergence() {
+        let mut uniform = [1.0 / 26.0; 26];
+        assert!(kl_divergence(&uniform, &uniform).abs() < 1e-12);
+        assert!(kl_divergence(&uniform, &ENGLISH_FREQUENCIES) > 0.0);
+
+        let mut only_a = [0.0; 26];
+        only_a[0] = 1.0;
+        assert!((kl_divergence(&only_a, &uniform) - 26f64.ln()).abs() < 1e-12);
+        uniform[0] = 0.0;
+        assert!((kl_divergence(&only_a, &uniform) - 1e6f64.ln()).abs() < 1e-9);
+        assert!(kl_divergence(&[0.0; 26], &ENGLISH_FREQUENCIES).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_mean_ic_by_key_length() {
+        let encrypted =
+            LowercaseString::normalize(&vigenere::encrypt(OZYMANDIAS, "sonet").unwrap());
+        // 1, 3 and 5 are summed from the counts of 30.
+        let means = mean_ic_by_key_length(&encrypted, [1, 5, 7, 5, 30, 3].into_iter());
+        assert_eq!(means.len(), 5);
+        for (&key_len, &mean) in &means {
+            let columns = encrypted.columns(key_len);
+            #[allow(clippy::cast_precision_loss)]
+            let naive = columns
+                .iter()
+                .map(LowercaseString::index_of_coincidence)
+                .sum::<f64>()
+                / key_len as f64;
+            assert!((mean - naive).abs() < 1e-12);
+        }
+        assert!(means[&5] > means[&7]);
+
+        let short = LowercaseString::normalize("abc");
+        assert!(mean_ic_by_key_length(&short, [3, 4].into_iter())[&4].abs() < 1e-12);
+        assert!(mean_ic_by_key_length(&short, std::iter::empty()).is_empty());
+    }
+
+    #[test]
+    fn test_keyword_alphabet() {
+        assert_eq!(
+            keyword_alphabet("playfair").to_string(),
+            "playfirbcdeghjkmnoqstuvwxz"
+        );
+        assert_eq!(
+            keyword_alphabet("").to_string(),
+            "abcdefghijklmnopqrstuvwxyz"
+        );
+        assert_eq!(
+            keyword_alphabet("Key!").to_string(),
+            "keyabcdfghijlmnopqrstuvwxz"
+        );
+    }
+
+    #[test]
+    fn test_keyword_alphabet_permutation() {
+        assert_eq!(keyword_alphabet_permutation(""), SubstitutionKey::identity());
+        let key = keyword_alphabet_permutation("zebras");
+        assert_eq!(key.to_string(), "zebrascdfghijklmnopqtuvwxy");
+    }
+
+    #[test]
+    fn test_display_alignment() {
+        let encrypted = vigenere::encrypt("hello world", "key").unwrap();
+        assert_eq!(
+            display_alignment("hello world", &encrypted, "key"),
+            "plain: h e l l o w o r l d\n\
+             key:   k e y k e y k e y k\n\
+             cipher:r i j v s u y v j n\n"
+        );
+
+        assert_eq!(
+            display_alignment("hello", "abc", ""),
+            "plain: h e l l o\n\
+             key:            \n\
+             cipher:a b c    \n"
+        );
+    }
+}
+
+#[cfg(test)]
+mod prop_roundtrip {
+    use super::*;
+
+    #[test]
+    fn bezout_identity() {
+        let mut rng = Rng::new(12);
+        for _ in 0..1000 {
+            let a = i32::try_from(rng.below(20_001)).unwrap() - 10_000;
+            let b = i32::try_from(rng.below(20_001)).unwrap() - 10_000;
+            let (gcd, x, y) = extended_gcd(a, b);
+            assert_eq!(a * x + b * y, gcd);
+            assert!(gcd >= 0);
+            if gcd != 0 {
+                assert_eq!((a % gcd, b % gcd), (0, 0));
+            }
+        }
+    }
+
+    #[test]
+    fn modular_inverse_inverts() {
+        let mut rng = Rng::new(13);
+        for _ in 0..1000 {
+            let a = u8::try_from(rng.below(256)).unwrap();
+            let modulus = u8::try_from(rng.below(255)).unwrap() + 1;
+            match modular_inverse(a, modulus) {
+                Some(inverse) => {
+                    assert_eq!(gcd(a, modulus), 1);
+                    assert!(inverse < modulus);
+                    let product = u32::from(a) * u32::from(inverse) % u32::from(modulus);
+                    assert_eq!(product, 1 % u32::from(modulus));
+                }
+                None => assert_ne!(gcd(a, modulus), 1),
+            }
+        }
+    }
 }
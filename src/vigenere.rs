@@ -1,67 +1,1236 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter, Write};
+
+use crate::caesar;
 use crate::lowercase_string::LowercaseString;
+use crate::utils::{self, Rng};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum VigenereError {
+    DuplicateAlphabetChar(char),
+    EmptyKeyword,
+    /// The text is too short for statistical analysis. Carries the minimum
+    /// recommended length.
+    TextTooShort(usize),
+    NoRepeatedSubstrings,
+    /// Two key lengths that are not multiples of one another fit equally
+    /// well. Carries the top two `(key length, index of coincidence)` pairs.
+    AmbiguousKeyLength((usize, f64), (usize, f64)),
+}
+
+impl Display for VigenereError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateAlphabetChar(c) => write!(f, "alphabet contains {c:?} more than once"),
+            Self::EmptyKeyword => write!(f, "keyword contains no alphabet characters"),
+            Self::TextTooShort(min_len) => {
+                write!(
+                    f,
+                    "text is too short to solve, use at least {min_len} letters"
+                )
+            }
+            Self::NoRepeatedSubstrings => write!(f, "text has no repeated substrings"),
+            Self::AmbiguousKeyLength((len1, ic1), (len2, ic2)) => write!(
+                f,
+                "key length is ambiguous between {len1} (IC {ic1:.4}) and {len2} (IC {ic2:.4})"
+            ),
+        }
+    }
+}
+
+impl Error for VigenereError {}
+
+/// Minimum text length for the index of coincidence to be meaningful.
+const MIN_SOLVE_LEN: usize = 60;
+
+/// The tabula recta, where `TABULA_RECTA[k][c]` is the encryption of letter
+/// `c` under key letter `k`.
+#[allow(clippy::cast_possible_truncation)]
+const TABULA_RECTA: [[u8; 26]; 26] = {
+    let mut table = [[0; 26]; 26];
+    let mut k = 0;
+    while k < 26 {
+        let mut c = 0;
+        while c < 26 {
+            table[k][c] = ((k + c) % 26) as u8;
+            c += 1;
+        }
+        k += 1;
+    }
+    table
+};
+
+/// Encrypts or decrypts a normalized message using a Vigenère cipher with a
+/// given key. Returns an empty string if the key is empty.
+///
+/// # Example
+/// ```
+/// use cipher_solver::{vigenere, LowercaseString};
+///
+/// let text = LowercaseString::normalize("hello world");
+/// let key = LowercaseString::normalize("key");
+/// let encrypted = vigenere::apply_vigenere_key(&text, &key, false);
+/// assert_eq!(encrypted.to_string(), "rijvsuyvjn");
+/// assert_eq!(vigenere::apply_vigenere_key(&encrypted, &key, true), text);
+/// ```
+#[must_use]
+pub fn apply_vigenere_key(
+    text: &LowercaseString,
+    key: &LowercaseString,
+    decrypt: bool,
+) -> LowercaseString {
+    let key_indices = key.to_indices();
+    if key_indices.is_empty() {
+        return LowercaseString::from_indices(Vec::new());
+    }
+
+    let shifts: Vec<_> = key_indices
+        .iter()
+        .map(|&k| if decrypt { -i32::from(k) } else { i32::from(k) })
+        .collect();
+    text.shift_columns(&shifts)
+}
+
+/// Encrypts a normalized message using a Vigenère cipher with a given key.
+/// Returns an empty string if the key is empty.
+///
+/// # Example
+/// ```
+/// use cipher_solver::{vigenere, LowercaseString};
+///
+/// let text = LowercaseString::normalize("hello world");
+/// let key = LowercaseString::normalize("key");
+/// let encrypted = vigenere::encrypt_ls(&text, &key);
+/// assert_eq!(encrypted.to_string(), "rijvsuyvjn");
+/// ```
+#[must_use]
+pub fn encrypt_ls(text: &LowercaseString, key: &LowercaseString) -> LowercaseString {
+    apply_vigenere_key(text, key, false)
+}
+
+/// Decrypts a normalized message using a Vigenère cipher with a given key.
+/// Returns an empty string if the key is empty.
+///
+/// # Example
+/// ```
+/// use cipher_solver::{vigenere, LowercaseString};
+///
+/// let text = LowercaseString::normalize("rijvsuyvjn");
+/// let key = LowercaseString::normalize("key");
+/// let decrypted = vigenere::decrypt_ls(&text, &key);
+/// assert_eq!(decrypted.to_string(), "helloworld");
+/// ```
+#[must_use]
+pub fn decrypt_ls(text: &LowercaseString, key: &LowercaseString) -> LowercaseString {
+    apply_vigenere_key(text, key, true)
+}
+
+/// Encrypts a message using a Vigenère cipher with a given keyword.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let text = "hello world";
+/// let encrypted = vigenere::encrypt(text, "key").unwrap();
+/// assert_eq!(encrypted, "rijvsuyvjn");
+/// ```
+#[must_use]
+pub fn encrypt(text: impl AsRef<str>, keyword: impl AsRef<str>) -> Option<String> {
+    let keyword = LowercaseString::normalize(keyword.as_ref());
+    if keyword.to_indices().is_empty() {
+        return None;
+    }
+    Some(
+        apply_vigenere_key(&LowercaseString::normalize(text.as_ref()), &keyword, false).to_string(),
+    )
+}
+
+/// Decrypts a message using a Vigenère cipher with a given keyword.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let text = "rijvsuyvjn";
+/// let decrypted = vigenere::decrypt(text, "key").unwrap();
+/// assert_eq!(decrypted, "helloworld");
+/// ```
+#[must_use]
+pub fn decrypt(text: impl AsRef<str>, keyword: impl AsRef<str>) -> Option<String> {
+    let keyword = LowercaseString::normalize(keyword.as_ref());
+    if keyword.to_indices().is_empty() {
+        return None;
+    }
+    Some(apply_vigenere_key(&LowercaseString::normalize(text.as_ref()), &keyword, true).to_string())
+}
+
+/// Applies `cipher` to each word of the text with the keys in rotation,
+/// restarting the key at the start of each word, and joins the words with
+/// single spaces. Keys and words without letters are skipped.
+fn apply_polyalphabetic(
+    text: &str,
+    keys: &[&str],
+    cipher: fn(&LowercaseString, &LowercaseString) -> LowercaseString,
+) -> String {
+    let keys: Vec<_> = keys
+        .iter()
+        .map(|key| LowercaseString::normalize(key))
+        .filter(|key| !key.to_indices().is_empty())
+        .collect();
+    text.split_whitespace()
+        .map(LowercaseString::normalize)
+        .filter(|word| !word.to_indices().is_empty())
+        .enumerate()
+        .map(|(i, word)| match keys.get(i % keys.len().max(1)) {
+            Some(key) => cipher(&word, key).to_string(),
+            None => word.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Encrypts a message with several keys in rotation, one per word: the
+/// first word is encrypted with the first key, the second word with the
+/// second key, and so on, cycling back to the first key. Each word starts
+/// at the beginning of its key. Keys without letters are skipped, and the
+/// words are left unchanged if no key has any.
+/// Punctuation is removed, and the words are separated by single spaces so
+/// that they can be decrypted.
+///
+/// Unlike a Vigenère cipher with the keys concatenated, the key position
+/// depends on the word boundaries.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let encrypted = vigenere::encrypt_polyalphabetic("hello world", &["key", "ab"]);
+/// assert_eq!(encrypted, "rijvs wprmd");
+/// assert_eq!(vigenere::encrypt("hello world", "keyab").unwrap(), "rijlpgsple");
+/// ```
+#[must_use]
+pub fn encrypt_polyalphabetic(text: &str, keys: &[&str]) -> String {
+    apply_polyalphabetic(text, keys, encrypt_ls)
+}
+
+/// Decrypts a message encrypted with [`encrypt_polyalphabetic`], which must
+/// keep its word boundaries.
+/// Punctuation is removed, and the words are separated by single spaces.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let decrypted = vigenere::decrypt_polyalphabetic("rijvs wprmd", &["key", "ab"]);
+/// assert_eq!(decrypted, "hello world");
+/// ```
+#[must_use]
+pub fn decrypt_polyalphabetic(text: &str, keys: &[&str]) -> String {
+    apply_polyalphabetic(text, keys, decrypt_ls)
+}
+
+/// Generates `len` letters of keystream for [`encrypt_ctr`]. The key for
+/// position `i` is the counter `nonce + i`, written as a block of base-26
+/// digits as long as the keyword and Vigenère-encrypted with it. The block
+/// is mixed down to a single letter with a `SplitMix64` step, since any
+/// linear combination of its letters would repeat with a short period.
+fn ctr_keystream(keyword: &LowercaseString, nonce: u64, len: usize) -> LowercaseString {
+    let width = keyword.to_indices().len();
+    let letters = (0u64..).take(len).map(|i| {
+        let counter = LowercaseString::from_base26_number(nonce.wrapping_add(i), width);
+        // Only the lowest digits of a counter too large for the block.
+        let digits = &counter.to_indices()[counter.to_indices().len() - width..];
+        let block = encrypt_ls(&LowercaseString::from_indices(digits.to_vec()), keyword);
+        let value = block.to_indices().iter().fold(0u64, |n, &letter| {
+            n.wrapping_mul(26).wrapping_add(u64::from(letter))
+        });
+        u8::try_from(Rng::new(value).next_u64() % 26).unwrap()
+    });
+    LowercaseString::from_indices(letters.collect())
+}
+
+/// Encrypts a message in an educational analog of counter (CTR) mode: the
+/// keystream is not the repeated keyword but one letter per position,
+/// derived from the counter `nonce + i` Vigenère-encrypted with the
+/// keyword. The counter is written in as many base-26 digits as the keyword
+/// has letters, so the keystream only repeats after 26^n letters for a
+/// keyword of n letters, and the columns at the keyword length look like
+/// random text rather than Caesar shifts of English.
+/// Punctuation and whitespace are removed.
+///
+/// # Panics
+/// Panics if the keyword has no letters, rather than returning the text
+/// unencrypted.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let encrypted = vigenere::encrypt_ctr("hello world", "key", 0);
+/// assert_eq!(encrypted, "ispquyrvwp");
+/// assert_ne!(encrypted, vigenere::encrypt_ctr("hello world", "key", 1));
+/// ```
+#[must_use]
+pub fn encrypt_ctr(text: &str, keyword: &str, nonce: u64) -> String {
+    let text = LowercaseString::normalize(text);
+    let keyword = LowercaseString::normalize(keyword);
+    assert!(
+        !keyword.to_indices().is_empty(),
+        "keyword must contain a letter"
+    );
+    let keystream = ctr_keystream(&keyword, nonce, text.to_indices().len());
+    encrypt_ls(&text, &keystream).to_string()
+}
+
+/// Decrypts a message encrypted with [`encrypt_ctr`] using the same keyword
+/// and nonce.
+/// Punctuation and whitespace are removed.
+///
+/// # Panics
+/// Panics if the keyword has no letters.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let decrypted = vigenere::decrypt_ctr("ispquyrvwp", "key", 0);
+/// assert_eq!(decrypted, "helloworld");
+/// ```
+#[must_use]
+pub fn decrypt_ctr(text: &str, keyword: &str, nonce: u64) -> String {
+    let text = LowercaseString::normalize(text);
+    let keyword = LowercaseString::normalize(keyword);
+    assert!(
+        !keyword.to_indices().is_empty(),
+        "keyword must contain a letter"
+    );
+    let keystream = ctr_keystream(&keyword, nonce, text.to_indices().len());
+    decrypt_ls(&text, &keystream).to_string()
+}
+
+/// Encrypts or decrypts a long text in chunks, carrying the position in the
+/// key from one chunk to the next, so that the output matches processing
+/// the whole text at once.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere::{self, VigenereStream};
+///
+/// let mut stream = VigenereStream::new("key").unwrap();
+/// let encrypted = stream.encrypt_chunk("hello") + &stream.encrypt_chunk(" world");
+/// assert_eq!(Some(encrypted), vigenere::encrypt("hello world", "key"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VigenereStream {
+    key: LowercaseString,
+    position: usize,
+}
+
+impl VigenereStream {
+    /// Returns `None` if the keyword has no letters.
+    #[must_use]
+    pub fn new(keyword: &str) -> Option<Self> {
+        let key = LowercaseString::normalize(keyword);
+        if key.to_indices().is_empty() {
+            return None;
+        }
+        Some(Self { key, position: 0 })
+    }
+
+    /// Returns the key rotated to start at the current position, and moves
+    /// the position past a chunk of `len` letters.
+    fn advance(&mut self, len: usize) -> LowercaseString {
+        let key = self.key.to_indices();
+        let rotated = [&key[self.position..], &key[..self.position]].concat();
+        self.position = (self.position + len) % key.len();
+        LowercaseString::from_indices(rotated)
+    }
 
-fn apply(text: &str, keyword: &str, decrypt: bool) -> Option<String> {
+    /// Punctuation and whitespace are removed.
+    pub fn encrypt_chunk(&mut self, chunk: &str) -> String {
+        let chunk = LowercaseString::normalize(chunk);
+        let key = self.advance(chunk.to_indices().len());
+        encrypt_ls(&chunk, &key).to_string()
+    }
+
+    /// Punctuation and whitespace are removed.
+    pub fn decrypt_chunk(&mut self, chunk: &str) -> String {
+        let chunk = LowercaseString::normalize(chunk);
+        let key = self.advance(chunk.to_indices().len());
+        decrypt_ls(&chunk, &key).to_string()
+    }
+
+    /// Moves back to the start of the key.
+    pub fn reset(&mut self) {
+        self.position = 0;
+    }
+}
+
+fn apply_custom_alphabet(
+    text: &str,
+    keyword: &str,
+    alphabet: &str,
+    decrypt: bool,
+) -> Result<String, VigenereError> {
+    let alphabet: Vec<char> = alphabet.chars().collect();
+    let mut positions = HashMap::new();
+    for (i, &c) in alphabet.iter().enumerate() {
+        if positions.insert(c, i).is_some() {
+            return Err(VigenereError::DuplicateAlphabetChar(c));
+        }
+    }
+
+    let key_indices: Vec<usize> = keyword
+        .chars()
+        .filter_map(|c| positions.get(&c).copied())
+        .collect();
+    if key_indices.is_empty() {
+        return Err(VigenereError::EmptyKeyword);
+    }
+
+    let len = alphabet.len();
+    Ok(text
+        .chars()
+        .filter_map(|c| positions.get(&c).copied())
+        .enumerate()
+        .map(|(i, c)| {
+            let k = key_indices[i % key_indices.len()];
+            let shift = if decrypt { len - k } else { k };
+            alphabet[(c + shift) % len]
+        })
+        .collect())
+}
+
+/// Encrypts a message using a Vigenère cipher with a given keyword, looking
+/// each letter up in the tabula recta as in textbook descriptions of the
+/// cipher. Gives the same result as [`encrypt`].
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let text = "hello world";
+/// let encrypted = vigenere::encrypt_table(text, "key").unwrap();
+/// assert_eq!(encrypted, "rijvsuyvjn");
+/// ```
+#[must_use]
+pub fn encrypt_table(text: &str, keyword: &str) -> Option<String> {
     let text = LowercaseString::normalize(text);
     let keyword = LowercaseString::normalize(keyword);
-    let text_indices = text.to_indices();
     let key_indices = keyword.to_indices();
-    let key_len = key_indices.len();
 
-    if key_len == 0 {
+    if key_indices.is_empty() {
         return None;
     }
 
     Some(
         LowercaseString::from_indices(
-            text_indices
+            text.to_indices()
                 .iter()
-                .enumerate()
-                .map(|(i, &c)| {
-                    let k = key_indices[i % key_len];
-                    let shift = if decrypt { 26 - k } else { k };
-                    (c + shift) % 26
-                })
+                .zip(key_indices.iter().cycle())
+                .map(|(&c, &k)| TABULA_RECTA[k as usize][c as usize])
                 .collect(),
         )
         .to_string(),
     )
 }
 
-/// Encrypts a message using a Vigenère cipher with a given keyword.
-/// Punctuation and whitespace are removed.
+/// Renders the tabula recta as a 26×26 grid of letters, headed by the
+/// plaintext alphabet. Rows are labelled with their key letter, starting
+/// with the letters of the keyword as ordered by
+/// [`utils::keyword_alphabet`].
 ///
 /// # Example
 /// ```
 /// use cipher_solver::vigenere;
 ///
-/// let text = "hello world";
+/// let tableau = vigenere::display_tableau("key");
+/// let lines: Vec<_> = tableau.lines().collect();
+/// assert_eq!(lines[0], "  a b c d e f g h i j k l m n o p q r s t u v w x y z");
+/// assert_eq!(lines[1], "k k l m n o p q r s t u v w x y z a b c d e f g h i j");
+/// ```
+#[must_use]
+pub fn display_tableau(keyword: &str) -> String {
+    let letters = |row: &[u8]| -> Vec<String> {
+        row.iter()
+            .map(|&c| char::from(b'a' + c).to_string())
+            .collect()
+    };
+    let header: Vec<u8> = (0..26).collect();
+    let mut output = String::new();
+    writeln!(output, "  {}", letters(&header).join(" ")).unwrap();
+    for &key in utils::keyword_alphabet(keyword).to_indices() {
+        let row = letters(&TABULA_RECTA[key as usize]);
+        writeln!(output, "{} {}", char::from(b'a' + key), row.join(" ")).unwrap();
+    }
+    output
+}
+
+/// Encrypts a message using a Vigenère cipher over a custom alphabet.
+/// Characters of the text and keyword that are not in the alphabet are removed.
+///
+/// # Errors
+/// Returns [`VigenereError::DuplicateAlphabetChar`] if the alphabet contains a
+/// character more than once, or [`VigenereError::EmptyKeyword`] if the keyword
+/// has no characters from the alphabet.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let alphabet = "0123456789";
+/// let encrypted = vigenere::encrypt_custom_alphabet("2024-06-01", "31", alphabet).unwrap();
+/// assert_eq!(encrypted, "51553732");
+/// ```
+pub fn encrypt_custom_alphabet(
+    text: &str,
+    keyword: &str,
+    alphabet: &str,
+) -> Result<String, VigenereError> {
+    apply_custom_alphabet(text, keyword, alphabet, false)
+}
+
+/// Decrypts a message using a Vigenère cipher over a custom alphabet.
+/// Characters of the text and keyword that are not in the alphabet are removed.
+///
+/// # Errors
+/// Returns [`VigenereError::DuplicateAlphabetChar`] if the alphabet contains a
+/// character more than once, or [`VigenereError::EmptyKeyword`] if the keyword
+/// has no characters from the alphabet.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let alphabet = "0123456789";
+/// let decrypted = vigenere::decrypt_custom_alphabet("51553732", "31", alphabet).unwrap();
+/// assert_eq!(decrypted, "20240601");
+/// ```
+pub fn decrypt_custom_alphabet(
+    text: &str,
+    keyword: &str,
+    alphabet: &str,
+) -> Result<String, VigenereError> {
+    apply_custom_alphabet(text, keyword, alphabet, true)
+}
+
+/// Recovers the part of a Vigenère key covering a known crib.
+///
+/// `offset` is the position in the normalized ciphertext where the crib
+/// starts. The returned fragment starts at key position `offset` modulo the
+/// key length. Returns an empty string if the crib runs past the end of the
+/// ciphertext.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let encrypted = vigenere::encrypt("attack at dawn", "lemon").unwrap();
+/// let fragment = vigenere::key_from_crib(&encrypted, "attack", 0);
+/// assert_eq!(fragment.to_string(), "lemonl");
+/// ```
+#[must_use]
+pub fn key_from_crib(ciphertext: &str, plaintext_crib: &str, offset: usize) -> LowercaseString {
+    let ciphertext = LowercaseString::normalize(ciphertext);
+    let crib = LowercaseString::normalize(plaintext_crib);
+    let cipher_indices = ciphertext.to_indices();
+    let crib_indices = crib.to_indices();
+
+    if offset
+        .checked_add(crib_indices.len())
+        .is_none_or(|end| end > cipher_indices.len())
+    {
+        return LowercaseString::from_indices(vec![]);
+    }
+
+    let covered = &cipher_indices[offset..offset + crib_indices.len()];
+    LowercaseString::from_indices(covered.to_vec())
+        .diff(&crib)
+        .unwrap()
+}
+
+/// Finds the repeated substrings of at least `min_len` letters used in
+/// Kasiski examination, with all of their start positions in the normalized
+/// text. Distances between repeats tend to be multiples of the key length.
+///
+/// See [`LowercaseString::repeated_substrings`].
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let repeats = vigenere::kasiski_repeats("abcxyzabc", 3);
+/// assert_eq!(repeats.get("abc"), Some(&vec![0, 6]));
+/// ```
+#[must_use]
+pub fn kasiski_repeats(text: &str, min_len: usize) -> HashMap<String, Vec<usize>> {
+    LowercaseString::normalize(text)
+        .repeated_substrings(min_len)
+        .into_iter()
+        .map(|(substring, positions)| {
+            (
+                LowercaseString::from_indices(substring).to_string(),
+                positions,
+            )
+        })
+        .collect()
+}
+
+/// An index of coincidence above this is English-like enough to mark a
+/// plausible key length, however high the best in the profile is.
+const LIKELY_KEY_IC: f64 = 0.060;
+
+/// Returns the key lengths whose index of coincidence is close to the best
+/// in the profile, shortest first. Multiples of the true key length score
+/// just as well as the key length itself, and on short texts the columns of
+/// long multiples can score higher by chance.
+pub(crate) fn likely_key_lengths(
+    profile: &[(usize, f64)],
+) -> impl Iterator<Item = (usize, f64)> + '_ {
+    let best_ic = profile.iter().map(|&(_, ic)| ic).fold(0.0, f64::max);
+    let threshold = (0.9 * best_ic).min(LIKELY_KEY_IC);
+    profile
+        .iter()
+        .copied()
+        .filter(move |&(_, ic)| ic >= threshold)
+}
+
+/// Splits a text into the `key_len` groups of letters encrypted with the
+/// same key letter, where group `k` holds the letters at positions `k`,
+/// `k + key_len`, `k + 2 * key_len`, ... This is
+/// [`LowercaseString::columns`].
+///
+/// # Example
+/// ```
+/// use cipher_solver::{vigenere, LowercaseString};
+///
+/// let text = LowercaseString::normalize("abcdefgh");
+/// let groups = vigenere::split_by_key_position(&text, 3);
+/// assert_eq!(groups[0].to_string(), "adg");
+/// assert_eq!(groups[2].to_string(), "cf");
+/// ```
+#[must_use]
+pub fn split_by_key_position(text: &LowercaseString, key_len: usize) -> Vec<LowercaseString> {
+    text.columns(key_len)
+}
+
+/// Finds each key letter as the Caesar shift that makes its column most
+/// English-like.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn key_for_length(text: &LowercaseString, key_len: usize) -> LowercaseString {
+    LowercaseString::from_indices(
+        caesar::solve_per_column(text, key_len)
+            .into_iter()
+            .map(|shift| shift as u8)
+            .collect(),
+    )
+}
+
+/// Estimates the key of a Vigenère ciphertext, trying key lengths up to
+/// `max_key_len`.
+pub(crate) fn estimate_key(text: &LowercaseString, max_key_len: usize) -> Option<LowercaseString> {
+    let profile = utils::ic_profile(text, max_key_len.min(text.to_indices().len() / 2));
+    let (key_len, _) = likely_key_lengths(&profile).next()?;
+    Some(key_for_length(text, key_len))
+}
+
+/// Solves a Vigenère cipher using statistical analysis, trying key lengths
+/// from 1 to `max_key_len`. Returns the plaintext and the key.
+///
+/// # Errors
+/// Returns [`VigenereError::TextTooShort`] if the text has fewer than 60
+/// letters, [`VigenereError::NoRepeatedSubstrings`] if no trigram repeats,
+/// and [`VigenereError::AmbiguousKeyLength`] if key lengths that are not
+/// multiples of one another fit equally well.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let text = "I met a traveller from an antique land, who said: Two vast and \
+///     trunkless legs of stone stand in the desert. Near them, on the sand, \
+///     half sunk a shattered visage lies, whose frown, and wrinkled lip, and \
+///     sneer of cold command, tell that its sculptor well those passions read";
 /// let encrypted = vigenere::encrypt(text, "key").unwrap();
-/// assert_eq!(encrypted, "rijvsuyvjn");
+/// let (_, key) = vigenere::solve(&encrypted, 10).unwrap();
+/// assert_eq!(key, "key");
+/// ```
+pub fn solve(text: &str, max_key_len: usize) -> Result<(String, String), VigenereError> {
+    solve_with_profile(text, max_key_len, utils::ic_profile)
+}
+
+/// Solves a Vigenère cipher as [`solve`] does, computing the IC profile with
+/// `profile`.
+fn solve_with_profile(
+    text: &str,
+    max_key_len: usize,
+    profile: impl Fn(&LowercaseString, usize) -> Vec<(usize, f64)>,
+) -> Result<(String, String), VigenereError> {
+    let text = LowercaseString::normalize(text);
+    let len = text.to_indices().len();
+    if len < MIN_SOLVE_LEN {
+        return Err(VigenereError::TextTooShort(MIN_SOLVE_LEN));
+    }
+    if text.repeated_substrings(3).is_empty() {
+        return Err(VigenereError::NoRepeatedSubstrings);
+    }
+
+    let profile = profile(&text, max_key_len.clamp(1, len / 2));
+    let mut candidates = likely_key_lengths(&profile);
+    let (key_len, ic) = candidates.next().unwrap();
+    if let Some(other) = candidates.find(|&(other_len, _)| other_len % key_len != 0) {
+        return Err(VigenereError::AmbiguousKeyLength((key_len, ic), other));
+    }
+
+    let key = key_for_length(&text, key_len);
+    Ok((decrypt_ls(&text, &key).to_string(), key.to_string()))
+}
+
+/// Computes the same profile as [`utils::ic_profile`], splitting the key
+/// lengths between threads.
+#[cfg(feature = "parallel")]
+fn parallel_ic_profile(text: &LowercaseString, max_key_len: usize) -> Vec<(usize, f64)> {
+    let threads = std::thread::available_parallelism().map_or(1, usize::from);
+    let lengths: Vec<_> = (1..=max_key_len).collect();
+    let chunk_len = max_key_len.div_ceil(threads).max(1);
+    let means: HashMap<_, _> = std::thread::scope(|scope| {
+        let handles: Vec<_> = lengths
+            .chunks(chunk_len)
+            .map(|chunk| {
+                scope.spawn(move || utils::mean_ic_by_key_length(text, chunk.iter().copied()))
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+    lengths
+        .into_iter()
+        .map(|key_len| (key_len, means[&key_len]))
+        .collect()
+}
+
+/// Solves a Vigenère cipher as [`solve`] does, but computes the index of
+/// coincidence for each key length on a separate thread. Returns the
+/// plaintext and the key, or `None` where [`solve`] returns an error.
+///
+/// # Example
 /// ```
+/// use cipher_solver::vigenere;
+///
+/// let text = "I met a traveller from an antique land, who said: Two vast and \
+///     trunkless legs of stone stand in the desert. Near them, on the sand, \
+///     half sunk a shattered visage lies, whose frown, and wrinkled lip, and \
+///     sneer of cold command, tell that its sculptor well those passions read";
+/// let encrypted = vigenere::encrypt(text, "key").unwrap();
+/// let (_, key) = vigenere::solve_with_parallel_ic(&encrypted, 10).unwrap();
+/// assert_eq!(key, "key");
+/// ```
+#[cfg(feature = "parallel")]
 #[must_use]
-pub fn encrypt(text: &str, keyword: &str) -> Option<String> {
-    apply(text, keyword, false)
+pub fn solve_with_parallel_ic(text: &str, max_key_len: usize) -> Option<(String, String)> {
+    solve_with_profile(text, max_key_len, parallel_ic_profile).ok()
 }
 
-/// Decrypts a message using a Vigenère cipher with a given keyword.
+/// Solves a Vigenère cipher with a key of known length, such as one found
+/// with [`utils::repeated_substring_gcd`] or [`utils::ic_profile`]. Each of
+/// the `key_len` interleaved columns is solved as a Caesar cipher with
+/// [`LowercaseString::caesar_shift_to_match_english`]. Returns the plaintext
+/// and the key, or `None` if `key_len` is 0.
+///
+/// Unlike [`solve`], this never guesses the key length, so it also works on
+/// texts too short for the key length to be found reliably, as long as
+/// each column still has enough letters to solve.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let text = "I met a traveller from an antique land, who said: Two vast and \
+///     trunkless legs of stone stand in the desert. Near them, on the sand";
+/// let encrypted = vigenere::encrypt(text, "key").unwrap();
+/// let (_, key) = vigenere::known_key_length_solve(&encrypted, 3).unwrap();
+/// assert_eq!(key, "key");
+/// ```
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn known_key_length_solve(text: &str, key_len: usize) -> Option<(String, String)> {
+    if key_len == 0 {
+        return None;
+    }
+    let text = LowercaseString::normalize(text);
+    let key = LowercaseString::from_indices(
+        split_by_key_position(&text, key_len)
+            .iter()
+            .map(|column| ((26 - column.caesar_shift_to_match_english()) % 26) as u8)
+            .collect(),
+    );
+    Some((decrypt_ls(&text, &key).to_string(), key.to_string()))
+}
+
+/// The longest key length tried by [`solve_with_hints`] when neither hint
+/// gives the length.
+const MAX_HINTED_KEY_LEN: usize = 20;
+
+/// Parses a partial key of letters and `'?'`s, where each `'?'` is an
+/// unknown key letter.
+fn parse_partial_key(partial: &str) -> Option<Vec<Option<u8>>> {
+    partial
+        .chars()
+        .map(|c| match c {
+            '?' => Some(None),
+            c if c.is_ascii_alphabetic() => Some(Some(c.to_ascii_lowercase() as u8 - b'a')),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Solves a Vigenère cipher using whatever the analyst already knows. The
+/// key length is `key_len_hint` if given, otherwise the length of
+/// `partial_key`, and otherwise found from the index of coincidence (up to
+/// 20). Letters of `partial_key` fix those key positions, and only its
+/// `'?'` positions are solved, each as a Caesar cipher. Returns the
+/// plaintext and the key, or `None` if the hints disagree on the length,
+/// `partial_key` has characters other than letters and `'?'`, or no key
+/// length is found.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let text = "I met a traveller from an antique land, who said: Two vast and \
+///     trunkless legs of stone stand in the desert. Near them, on the sand";
+/// let encrypted = vigenere::encrypt(text, "secret").unwrap();
+/// let (_, key) = vigenere::solve_with_hints(&encrypted, Some(6), Some("sec??t")).unwrap();
+/// assert_eq!(key, "secret");
+/// ```
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn solve_with_hints(
+    text: &str,
+    key_len_hint: Option<usize>,
+    partial_key: Option<&str>,
+) -> Option<(String, String)> {
+    let text = LowercaseString::normalize(text);
+    let partial_key = match partial_key {
+        Some(partial) => Some(parse_partial_key(partial)?),
+        None => None,
+    };
+
+    let key_len = match (key_len_hint, &partial_key) {
+        (Some(len), Some(partial)) if partial.len() != len => return None,
+        (Some(len), _) => len,
+        (None, Some(partial)) => partial.len(),
+        (None, None) => {
+            let max_key_len = MAX_HINTED_KEY_LEN.min(text.to_indices().len() / 2);
+            likely_key_lengths(&utils::ic_profile(&text, max_key_len))
+                .next()?
+                .0
+        }
+    };
+    if key_len == 0 {
+        return None;
+    }
+
+    let key = LowercaseString::from_indices(
+        split_by_key_position(&text, key_len)
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                partial_key
+                    .as_ref()
+                    .and_then(|partial| partial[i])
+                    .unwrap_or_else(|| ((26 - column.caesar_shift_to_match_english()) % 26) as u8)
+            })
+            .collect(),
+    );
+    Some((decrypt_ls(&text, &key).to_string(), key.to_string()))
+}
+
+/// Solves a Vigenère cipher whose key is one of the words in `wordlist`.
+/// The likely key lengths are found from the index of coincidence, and each
+/// word of one of those lengths is tried as the key, keeping the decryption
+/// with the lowest chi-squared score. Returns the plaintext and the key, or
+/// `None` if no word has a likely length.
+///
+/// This is far more reliable than [`solve`] on short texts, where the
+/// columns are too short to solve one at a time.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let text = "I met a traveller from an antique land, who said: Two vast and \
+///     trunkless legs of stone";
+/// let encrypted = vigenere::encrypt(text, "ruins").unwrap();
+/// let wordlist = ["ruins", "sands", "stone", "king", "desert"];
+/// let (_, key) = vigenere::solve_with_wordlist(&encrypted, &wordlist).unwrap();
+/// assert_eq!(key, "ruins");
+/// ```
+#[must_use]
+pub fn solve_with_wordlist(text: &str, wordlist: &[&str]) -> Option<(String, String)> {
+    let text = LowercaseString::normalize(text);
+    let words: Vec<_> = wordlist
+        .iter()
+        .map(|word| LowercaseString::normalize(word))
+        .filter(|word| !word.to_indices().is_empty())
+        .collect();
+    let max_key_len = words.iter().map(|word| word.to_indices().len()).max()?;
+
+    let half_len = text.to_indices().len() / 2;
+    let profile = utils::ic_profile(&text, max_key_len.min(half_len).max(1));
+    let lengths: Vec<_> = likely_key_lengths(&profile).map(|(len, _)| len).collect();
+    words
+        .iter()
+        .filter(|word| lengths.contains(&word.to_indices().len()))
+        .map(|key| {
+            let decrypted = decrypt_ls(&text, key);
+            (utils::chi_squared_english_score(&decrypted), decrypted, key)
+        })
+        .min_by(|(score1, ..), (score2, ..)| score1.total_cmp(score2))
+        .map(|(_, plaintext, key)| (plaintext.to_string(), key.to_string()))
+}
+
+/// Friedman's coincidence test for two intercepted messages: the fraction
+/// of aligned positions, up to the end of the shorter text, where both have
+/// the same letter. Returns 0 if either text has no letters.
+///
+/// If both were encrypted with the same Vigenère key starting at the same
+/// key position, aligned letters share a key letter, so this is about the
+/// index of coincidence of English (0.065). At a different key position it
+/// falls to about that of random text (0.038). Unlike
+/// [`utils::mutual_ic`], which compares overall letter frequencies, this
+/// depends on the alignment.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let p1 = "I met a traveller from an antique land, who said: Two vast and \
+///     trunkless legs of stone stand in the desert. Near them, on the sand";
+/// let p2 = "Half sunk a shattered visage lies, whose frown, and wrinkled lip, \
+///     and sneer of cold command, tell that its sculptor well those";
+/// let c1 = vigenere::encrypt(p1, "lemon").unwrap();
+/// let c2 = vigenere::encrypt(p2, "lemon").unwrap();
+/// let c3 = vigenere::encrypt(p2, "emonl").unwrap();
+/// assert!(vigenere::coincidence_test(&c1, &c2) > vigenere::coincidence_test(&c1, &c3));
+/// ```
+#[must_use]
+pub fn coincidence_test(c1: &str, c2: &str) -> f64 {
+    let c1 = LowercaseString::normalize(c1);
+    let c2 = LowercaseString::normalize(c2);
+    let positions = c1.to_indices().len().min(c2.to_indices().len());
+    if positions == 0 {
+        return 0.0;
+    }
+    let matching = c1
+        .to_indices()
+        .iter()
+        .zip(c2.to_indices())
+        .filter(|(a, b)| a == b)
+        .count();
+    #[allow(clippy::cast_precision_loss)]
+    let fraction = matching as f64 / positions as f64;
+    fraction
+}
+
+/// Below this, the best key length found for [`two_time_attack`] is no more
+/// English-like than random text could be by chance.
+const MIN_KEY_IC: f64 = 0.052;
+
+/// Splits two ciphertexts encrypted with the same key into `key_len`
+/// columns, where column `k` holds the letters of both texts encrypted with
+/// key letter `k`.
+fn joint_columns(
+    c1: &LowercaseString,
+    c2: &LowercaseString,
+    key_len: usize,
+) -> Vec<LowercaseString> {
+    split_by_key_position(c1, key_len)
+        .into_iter()
+        .zip(split_by_key_position(c2, key_len))
+        .map(|(a, b)| LowercaseString::from_indices([a.to_indices(), b.to_indices()].concat()))
+        .collect()
+}
+
+/// Computes the mean index of coincidence of the [`joint_columns`] of two
+/// ciphertexts for each key length from 1 to `max_key_len`.
+fn joint_ic_profile(
+    c1: &LowercaseString,
+    c2: &LowercaseString,
+    max_key_len: usize,
+) -> Vec<(usize, f64)> {
+    (1..=max_key_len)
+        .map(|key_len| {
+            #[allow(clippy::cast_precision_loss)]
+            let mean = joint_columns(c1, c2, key_len)
+                .iter()
+                .map(LowercaseString::index_of_coincidence)
+                .sum::<f64>()
+                / key_len as f64;
+            (key_len, mean)
+        })
+        .collect()
+}
+
+/// Recovers two messages encrypted with the same Vigenère key, trying key
+/// lengths from 1 to `max_key_len`. Returns both plaintexts and the key, or
+/// `None` if the ciphertexts have different lengths or no key length stands
+/// out.
+///
+/// Subtracting the ciphertexts cancels the key, so each key letter can be
+/// chosen to make both plaintexts English-like at once. The key letters are
+/// first estimated from the letter frequencies of both texts, then refined
+/// with [`utils::bigram_english_score`] of the two decryptions.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let p1 = "I met a traveller from an antique land, who said: Two vast and \
+///     trunkless legs of stone stand in the desert. Near them, on the sand";
+/// let p2 = "Half sunk a shattered visage lies, whose frown, and wrinkled lip, \
+///     and sneer of cold command, tell that its sculptor well those";
+/// let c1 = vigenere::encrypt(p1, "lemon").unwrap();
+/// let c2 = vigenere::encrypt(p2, "lemon").unwrap();
+/// let (_, _, key) = vigenere::two_time_attack(&c1, &c2, 10).unwrap();
+/// assert_eq!(key, "lemon");
+/// ```
+#[must_use]
+pub fn two_time_attack(c1: &str, c2: &str, max_key_len: usize) -> Option<(String, String, String)> {
+    let c1 = LowercaseString::normalize(c1);
+    let c2 = LowercaseString::normalize(c2);
+    let len = c1.to_indices().len();
+    if len != c2.to_indices().len() || len < 2 {
+        return None;
+    }
+
+    let profile = joint_ic_profile(&c1, &c2, max_key_len.clamp(1, len / 2));
+    let (key_len, ic) = likely_key_lengths(&profile).next()?;
+    if ic < MIN_KEY_IC {
+        return None;
+    }
+
+    let mut key: Vec<u8> = joint_columns(&c1, &c2, key_len)
+        .iter()
+        .map(|column| utils::best_shift(column).0)
+        .collect();
+    let score = |key: &[u8]| {
+        let key = LowercaseString::from_indices(key.to_vec());
+        utils::bigram_english_score(&decrypt_ls(&c1, &key))
+            + utils::bigram_english_score(&decrypt_ls(&c2, &key))
+    };
+    for i in 0..key_len {
+        let mut candidate = key.clone();
+        key[i] = (0..26)
+            .map(|letter| {
+                candidate[i] = letter;
+                (letter, score(&candidate))
+            })
+            .min_by(|(_, score1), (_, score2)| score1.total_cmp(score2))
+            .map(|(letter, _)| letter)
+            .unwrap();
+    }
+
+    let key = LowercaseString::from_indices(key);
+    Some((
+        decrypt_ls(&c1, &key).to_string(),
+        decrypt_ls(&c2, &key).to_string(),
+        key.to_string(),
+    ))
+}
+
+/// Recovers the key shared by two Vigenère ciphertexts, given a fragment of
+/// the plaintext at the start of `c1`. The fragment gives the first key
+/// letters through [`key_from_crib`], and the key length is the shortest
+/// likely one (up to 20) from the index of coincidence of both texts
+/// together that repeats consistently through those letters. Any key
+/// letters past the end of the fragment are solved as Caesar shifts on the
+/// letters of both texts. Returns `None` if the fragment has no letters or
+/// runs past the end of `c1`, or if no likely key length agrees with it.
+///
+/// Decrypting `c2` with the key recovers its plaintext.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let p1 = "I met a traveller from an antique land, who said: Two vast and \
+///     trunkless legs of stone stand in the desert. Near them, on the sand";
+/// let p2 = "Half sunk a shattered visage lies, whose frown, and wrinkled lip, \
+///     and sneer of cold command, tell that its sculptor well those";
+/// let c1 = vigenere::encrypt(p1, "sphinx").unwrap();
+/// let c2 = vigenere::encrypt(p2, "sphinx").unwrap();
+/// let key = vigenere::key_from_two_ciphertexts(&c1, "I met a traveller", &c2).unwrap();
+/// assert_eq!(key, "sphinx");
+/// assert!(vigenere::decrypt(&c2, &key).unwrap().starts_with("halfsunk"));
+/// ```
+#[must_use]
+pub fn key_from_two_ciphertexts(c1: &str, p1_fragment: &str, c2: &str) -> Option<String> {
+    let partial = key_from_crib(c1, p1_fragment, 0);
+    let partial = partial.to_indices();
+    if partial.is_empty() {
+        return None;
+    }
+
+    let c1 = LowercaseString::normalize(c1);
+    let c2 = LowercaseString::normalize(c2);
+    let half_len = usize::midpoint(c1.to_indices().len(), c2.to_indices().len());
+    let profile = joint_ic_profile(&c1, &c2, MAX_HINTED_KEY_LEN.min(half_len).max(1));
+    let (key_len, _) = likely_key_lengths(&profile).find(|&(key_len, _)| {
+        partial
+            .iter()
+            .zip(&partial[key_len.min(partial.len())..])
+            .all(|(a, b)| a == b)
+    })?;
+
+    let key: Vec<u8> = joint_columns(&c1, &c2, key_len)
+        .iter()
+        .enumerate()
+        .map(|(i, column)| {
+            partial
+                .get(i)
+                .copied()
+                .unwrap_or_else(|| utils::best_shift(column).0)
+        })
+        .collect();
+    Some(LowercaseString::from_indices(key).to_string())
+}
+
+fn autokey_decrypt_indices(ciphertext: &[u8], primer: &[u8]) -> Vec<u8> {
+    let mut plaintext = Vec::with_capacity(ciphertext.len());
+    for (i, &c) in ciphertext.iter().enumerate() {
+        let k = if i < primer.len() {
+            primer[i]
+        } else {
+            plaintext[i - primer.len()]
+        };
+        plaintext.push((c + 26 - k) % 26);
+    }
+    plaintext
+}
+
+/// Encrypts a message using an autokey cipher: a Vigenère cipher whose key
+/// is the primer followed by the plaintext itself.
 /// Punctuation and whitespace are removed.
 ///
 /// # Example
 /// ```
 /// use cipher_solver::vigenere;
 ///
-/// let text = "rijvsuyvjn";
-/// let decrypted = vigenere::decrypt(text, "key").unwrap();
-/// assert_eq!(decrypted, "helloworld");
+/// let encrypted = vigenere::autokey_encrypt("attack at dawn", "queen").unwrap();
+/// assert_eq!(encrypted, "qnxepktmdcgn");
+/// ```
+#[must_use]
+pub fn autokey_encrypt(text: &str, primer: &str) -> Option<String> {
+    let text = LowercaseString::normalize(text);
+    let primer = LowercaseString::normalize(primer);
+    if primer.to_indices().is_empty() {
+        return None;
+    }
+    let key = LowercaseString::from_indices([primer.to_indices(), text.to_indices()].concat());
+    Some(encrypt_ls(&text, &key).to_string())
+}
+
+/// Decrypts a message using an autokey cipher with a given primer.
+/// Punctuation and whitespace are removed.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let decrypted = vigenere::autokey_decrypt("qnxepktmdcgn", "queen").unwrap();
+/// assert_eq!(decrypted, "attackatdawn");
 /// ```
 #[must_use]
-pub fn decrypt(text: &str, keyword: &str) -> Option<String> {
-    apply(text, keyword, true)
+pub fn autokey_decrypt(text: &str, primer: &str) -> Option<String> {
+    let text = LowercaseString::normalize(text);
+    let primer = LowercaseString::normalize(primer);
+    if primer.to_indices().is_empty() {
+        return None;
+    }
+    let plaintext = autokey_decrypt_indices(text.to_indices(), primer.to_indices());
+    Some(LowercaseString::from_indices(plaintext).to_string())
+}
+
+/// Solves an autokey cipher, trying primer lengths from 1 to
+/// `primer_len_hint`. Returns the plaintext and the primer, or `None` if
+/// the ciphertext has no letters or the hint is 0.
+///
+/// With a primer of length `n`, every `n`th letter forms a chain in which
+/// each plaintext letter is the key for the next, so a chain is decrypted
+/// entirely by its primer letter. Each primer letter is chosen to make its
+/// chain most English-like by letter frequency, and the primer length whose
+/// plaintext has the highest
+/// [`LowercaseString::english_word_coverage`] wins.
+///
+/// # Example
+/// ```
+/// use cipher_solver::vigenere;
+///
+/// let text = "I met a traveller from an antique land, who said: Two vast and \
+///     trunkless legs of stone stand in the desert. Near them, on the sand, \
+///     half sunk a shattered visage lies, whose frown, and wrinkled lip, and \
+///     sneer of cold command, tell that its sculptor well those passions read";
+/// let encrypted = vigenere::autokey_encrypt(text, "key").unwrap();
+/// let (_, primer) = vigenere::autokey_solve(&encrypted, 10).unwrap();
+/// assert_eq!(primer, "key");
+/// ```
+#[must_use]
+pub fn autokey_solve(ciphertext: &str, primer_len_hint: usize) -> Option<(String, String)> {
+    let text = LowercaseString::normalize(ciphertext);
+    let cipher_indices = text.to_indices();
+
+    (1..=primer_len_hint.min(cipher_indices.len()))
+        .map(|primer_len| {
+            let primer: Vec<u8> = (0..primer_len)
+                .map(|start| {
+                    let chain: Vec<u8> = cipher_indices[start..]
+                        .iter()
+                        .step_by(primer_len)
+                        .copied()
+                        .collect();
+                    (0..26)
+                        .map(|k| {
+                            let decrypted = autokey_decrypt_indices(&chain, &[k]);
+                            let score = utils::chi_squared_english_score(
+                                &LowercaseString::from_indices(decrypted),
+                            );
+                            (k, score)
+                        })
+                        .min_by(|(_, score1), (_, score2)| score1.total_cmp(score2))
+                        .map(|(k, _)| k)
+                        .unwrap()
+                })
+                .collect();
+            let plaintext =
+                LowercaseString::from_indices(autokey_decrypt_indices(cipher_indices, &primer));
+            (plaintext.english_word_coverage(), plaintext, primer)
+        })
+        // Prefer the shortest primer among equally good ones.
+        .rev()
+        .max_by(|(score1, ..), (score2, ..)| score1.total_cmp(score2))
+        .map(|(_, plaintext, primer)| {
+            (
+                plaintext.to_string(),
+                LowercaseString::from_indices(primer).to_string(),
+            )
+        })
 }
 
 #[cfg(test)]
 mod tests {
+    use std::borrow::Cow;
+
     use super::*;
+    use crate::docs_words::DOCS_WORDS;
+    use crate::test_data::OZYMANDIAS;
 
     #[test]
     fn test() {
@@ -77,5 +1246,484 @@ mod tests {
 
         let decrypted_empty = decrypt(original, "");
         assert_eq!(decrypted_empty, None);
+
+        let owned = String::from(keyword);
+        assert_eq!(
+            encrypt(String::from(original), owned.clone()),
+            Some(encrypted.clone())
+        );
+        assert_eq!(encrypt(original, &owned), Some(encrypted.clone()));
+        assert_eq!(
+            decrypt(Cow::Borrowed(encrypted.as_str()), Cow::Owned(owned)),
+            Some(normalized.to_string())
+        );
+        assert_eq!(decrypt(encrypted, keyword), Some(normalized.to_string()));
+    }
+
+    #[test]
+    fn test_ls() {
+        let text = LowercaseString::normalize("The quick brown fox jumps over the lazy dog");
+        let key = LowercaseString::normalize("secret");
+        let encrypted = encrypt_ls(&text, &key);
+        assert_eq!(
+            Some(encrypted.to_string()),
+            encrypt(text.to_string(), "secret")
+        );
+        assert_eq!(decrypt_ls(&encrypted, &key), text);
+        assert_eq!(apply_vigenere_key(&text, &key, false), encrypted);
+        assert_eq!(apply_vigenere_key(&encrypted, &key, true), text);
+
+        let empty = LowercaseString::normalize("");
+        assert_eq!(encrypt_ls(&text, &empty), empty);
+        assert_eq!(decrypt_ls(&text, &empty), empty);
+    }
+
+    #[test]
+    fn test_known_key_length_solve() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        for key in ["a", "ramses", "kingofkings"] {
+            let encrypted = encrypt(OZYMANDIAS, key).unwrap();
+            assert_eq!(
+                known_key_length_solve(&encrypted, key.len()),
+                Some((normalized.clone(), key.to_string()))
+            );
+        }
+        assert_eq!(known_key_length_solve(OZYMANDIAS, 0), None);
+    }
+
+    #[test]
+    fn test_solve_with_wordlist() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        let plaintext = &normalized[..100];
+        let wordlist = &DOCS_WORDS[..2000];
+        for key in ["test", "value", "return", "example"] {
+            assert!(wordlist.contains(&key));
+            let encrypted = encrypt(plaintext, key).unwrap();
+            assert_eq!(
+                solve_with_wordlist(&encrypted, wordlist),
+                Some((plaintext.to_string(), key.to_string()))
+            );
+        }
+
+        let encrypted = encrypt(plaintext, "value").unwrap();
+        assert_eq!(solve_with_wordlist(&encrypted, &[]), None);
+        assert_eq!(solve_with_wordlist(&encrypted, &["", "123"]), None);
+    }
+
+    #[test]
+    fn test_polyalphabetic() {
+        let words: Vec<_> = OZYMANDIAS
+            .split_whitespace()
+            .map(|word| LowercaseString::normalize(word).to_string())
+            .filter(|word| !word.is_empty())
+            .collect();
+        let spaced = words.join(" ");
+        let keys = ["desert", "sand", "", "stone"];
+        let encrypted = encrypt_polyalphabetic(OZYMANDIAS, &keys);
+        assert_eq!(decrypt_polyalphabetic(&encrypted, &keys), spaced);
+        assert_eq!(encrypted.split(' ').count(), words.len());
+
+        // Each word restarts its key, so this is not the concatenated key.
+        let two_keys = encrypt_polyalphabetic(OZYMANDIAS, &["desert", "sand"]);
+        let letters = two_keys.replace(' ', "");
+        assert_ne!(Some(letters.clone()), encrypt(OZYMANDIAS, "desertsand"));
+        assert_ne!(Some(letters), encrypt(OZYMANDIAS, "desert"));
+        let first = encrypt(&words[0], "desert").unwrap();
+        let second = encrypt(&words[1], "sand").unwrap();
+        assert!(two_keys.starts_with(&format!("{first} {second} ")));
+
+        assert_eq!(encrypt_polyalphabetic(OZYMANDIAS, &[]), spaced);
+        assert_eq!(decrypt_polyalphabetic(OZYMANDIAS, &["123"]), spaced);
+        assert_eq!(encrypt_polyalphabetic("  ", &["key"]), "");
+    }
+
+    #[test]
+    fn test_ctr() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        let encrypted = encrypt_ctr(OZYMANDIAS, "desert", 42);
+        assert_eq!(decrypt_ctr(&encrypted, "desert", 42), normalized);
+        assert_ne!(decrypt_ctr(&encrypted, "desert", 43), normalized);
+
+        let keyword = LowercaseString::normalize("key");
+        let keystream = ctr_keystream(&keyword, 7, 300);
+        assert_eq!(keystream, ctr_keystream(&keyword, 7, 300));
+        assert_ne!(keystream, ctr_keystream(&keyword, 8, 300));
+        assert_eq!(keystream.to_indices().len(), 300);
+        // Consecutive positions use consecutive counters.
+        assert_eq!(
+            ctr_keystream(&keyword, 8, 299).to_indices(),
+            &keystream.to_indices()[1..]
+        );
+
+        // A counter too large for the block keeps only its lowest digits.
+        let wrapped = ctr_keystream(&keyword, u64::MAX, 6);
+        assert_eq!(wrapped.to_indices().len(), 6);
+    }
+
+    #[test]
+    fn test_ctr_hides_key_length() {
+        let text = OZYMANDIAS.repeat(3);
+        let plain = LowercaseString::normalize(&encrypt(&text, "desert").unwrap());
+        let ctr = LowercaseString::normalize(&encrypt_ctr(&text, "desert", 0));
+        assert!(utils::phi_test_for_columns(&plain, 6) > 0.06);
+        assert!(utils::phi_test_for_columns(&ctr, 6) < 0.045);
+        assert!(utils::phi_test_for_columns(&ctr, 1) < 0.045);
+    }
+
+    #[test]
+    #[should_panic(expected = "keyword must contain a letter")]
+    fn test_ctr_empty_keyword() {
+        let _ = encrypt_ctr(OZYMANDIAS, "123", 42);
+    }
+
+    #[test]
+    fn test_stream() {
+        let text = OZYMANDIAS.repeat(5);
+        let encrypted = encrypt(&text, "shelley").unwrap();
+
+        let mut stream = VigenereStream::new("shelley").unwrap();
+        let chunks: Vec<_> = text.as_bytes().chunks(1024).collect();
+        let streamed: String = chunks
+            .iter()
+            .map(|chunk| stream.encrypt_chunk(std::str::from_utf8(chunk).unwrap()))
+            .collect();
+        assert_eq!(streamed, encrypted);
+
+        stream.reset();
+        let decrypted: String = encrypted
+            .as_bytes()
+            .chunks(1000)
+            .map(|chunk| stream.decrypt_chunk(std::str::from_utf8(chunk).unwrap()))
+            .collect();
+        assert_eq!(decrypted, LowercaseString::normalize(&text).to_string());
+
+        assert_eq!(VigenereStream::new("123"), None);
+    }
+
+    #[test]
+    fn test_key_from_crib() {
+        let plaintext = "I met a traveller from an antique land";
+        let encrypted = encrypt(plaintext, "secret").unwrap();
+
+        let fragment = key_from_crib(&encrypted, "fromanantique", 14);
+        assert_eq!(fragment.to_string(), "cretsecretsec");
+
+        let fragment = key_from_crib(&encrypted, "imetat", 0);
+        assert_eq!(fragment.to_string(), "secret");
+
+        let fragment = key_from_crib(&encrypted, "travel", 5);
+        assert_eq!(fragment.to_string(), "tsecre");
+
+        let fragment = key_from_crib(&encrypted, "land", 40);
+        assert_eq!(fragment.to_string(), "");
+
+        let fragment = key_from_crib(&encrypted, "land", usize::MAX);
+        assert_eq!(fragment.to_string(), "");
+    }
+
+    #[test]
+    fn test_display_tableau() {
+        let tableau = display_tableau("Dog!");
+        let lines: Vec<_> = tableau.lines().collect();
+        assert_eq!(lines.len(), 27);
+        assert!(tableau.ends_with('\n'));
+        assert_eq!(
+            lines[1],
+            "d d e f g h i j k l m n o p q r s t u v w x y z a b c"
+        );
+        assert_eq!(
+            lines[2],
+            "o o p q r s t u v w x y z a b c d e f g h i j k l m n"
+        );
+        assert_eq!(
+            lines[3],
+            "g g h i j k l m n o p q r s t u v w x y z a b c d e f"
+        );
+        assert_eq!(
+            lines[4],
+            "a a b c d e f g h i j k l m n o p q r s t u v w x y z"
+        );
+        assert_eq!(
+            lines[26],
+            "z z a b c d e f g h i j k l m n o p q r s t u v w x y"
+        );
+    }
+
+    #[test]
+    fn test_encrypt_table() {
+        for k in 0..26 {
+            for c in 0..26 {
+                let text = LowercaseString::from_indices(vec![c]).to_string();
+                let key = LowercaseString::from_indices(vec![k]).to_string();
+                assert_eq!(encrypt_table(&text, &key), encrypt(&text, &key));
+            }
+        }
+        assert_eq!(encrypt_table("hello", ""), None);
+    }
+
+    #[test]
+    fn test_estimate_key() {
+        let encrypted = encrypt(OZYMANDIAS, "sonnet").unwrap();
+        let key = estimate_key(&LowercaseString::normalize(&encrypted), 12);
+        assert_eq!(key.map(|k| k.to_string()), Some("sonnet".to_string()));
+
+        assert_eq!(estimate_key(&LowercaseString::normalize(""), 12), None);
+    }
+
+    #[test]
+    fn test_solve() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        for key in ["sonnet", "ab", "shelley", "kingofkings"] {
+            let encrypted = encrypt(OZYMANDIAS, key).unwrap();
+            assert_eq!(
+                solve(&encrypted, 12),
+                Ok((normalized.clone(), key.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_solve_with_parallel_ic() {
+        for key in ["sonnet", "ab", "shelley", "kingofkings"] {
+            let encrypted = encrypt(OZYMANDIAS, key).unwrap();
+            for max_key_len in [1, 5, 12, 20] {
+                assert_eq!(
+                    solve_with_parallel_ic(&encrypted, max_key_len),
+                    solve(&encrypted, max_key_len).ok()
+                );
+            }
+        }
+        assert_eq!(solve_with_parallel_ic("too short", 12), None);
+
+        let text = LowercaseString::normalize(OZYMANDIAS);
+        assert_eq!(parallel_ic_profile(&text, 20), utils::ic_profile(&text, 20));
+    }
+
+    #[test]
+    fn test_split_by_key_position() {
+        let text = LowercaseString::normalize(OZYMANDIAS);
+        let len = text.to_indices().len();
+        for key_len in [1, 2, 7, 13, len - 1, len, len + 5] {
+            let groups = split_by_key_position(&text, key_len);
+            assert_eq!(groups.len(), key_len);
+            let lengths: Vec<_> = groups.iter().map(|g| g.to_indices().len()).collect();
+            let (min, max) = (lengths.iter().min(), lengths.iter().max());
+            assert!(max.unwrap() - min.unwrap() <= 1);
+            assert_eq!(lengths.iter().sum::<usize>(), len);
+            for (k, group) in groups.iter().enumerate() {
+                for (i, &letter) in group.to_indices().iter().enumerate() {
+                    assert_eq!(letter, text.to_indices()[k + i * key_len]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_with_hints() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        let encrypted = encrypt(OZYMANDIAS, "secret").unwrap();
+        let expected = Some((normalized.clone(), "secret".to_string()));
+
+        assert_eq!(solve_with_hints(&encrypted, None, None), expected);
+        assert_eq!(solve_with_hints(&encrypted, Some(6), None), expected);
+        assert_eq!(solve_with_hints(&encrypted, None, Some("se??e?")), expected);
+        assert_eq!(
+            solve_with_hints(&encrypted, Some(6), Some("SEC??T")),
+            expected
+        );
+        assert_eq!(
+            solve_with_hints(&encrypted, Some(6), Some("??????")),
+            expected
+        );
+
+        // Fixed letters are kept even when they are wrong.
+        let (plaintext, key) = solve_with_hints(&encrypted, None, Some("a?????")).unwrap();
+        assert_eq!(key, "aecret");
+        assert_ne!(plaintext, normalized);
+
+        assert_eq!(solve_with_hints(&encrypted, Some(5), Some("sec??t")), None);
+        assert_eq!(solve_with_hints(&encrypted, None, Some("sec-?t")), None);
+        assert_eq!(solve_with_hints(&encrypted, Some(0), None), None);
+        assert_eq!(solve_with_hints(&encrypted, None, Some("")), None);
+        assert_eq!(solve_with_hints("", None, None), None);
+    }
+
+    #[test]
+    fn test_two_time_attack() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        let (p1, p2) = normalized.split_at(normalized.len() / 2);
+        let p2 = &p2[..p1.len()];
+        for key in ["pharaoh", "ab", "nile"] {
+            let c1 = encrypt(p1, key).unwrap();
+            let c2 = encrypt(p2, key).unwrap();
+            let expected = (p1.to_string(), p2.to_string(), key.to_string());
+            assert_eq!(two_time_attack(&c1, &c2, 10), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_coincidence_test() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        let (p1, p2) = normalized.split_at(normalized.len() / 2);
+        for key in ["pharaoh", "nile", "desert"] {
+            let c1 = encrypt(p1, key).unwrap();
+            let aligned = coincidence_test(&c1, &encrypt(p2, key).unwrap());
+            let offsets: Vec<_> = (1..key.len())
+                .map(|shift| {
+                    let rotated = format!("{}{}", &key[shift..], &key[..shift]);
+                    coincidence_test(&c1, &encrypt(p2, rotated).unwrap())
+                })
+                .collect();
+            // Aligned letters match exactly where the plaintexts do.
+            assert!((aligned - 0.065).abs() < 0.01);
+            assert!(offsets.iter().all(|&offset| offset < aligned));
+            #[allow(clippy::cast_precision_loss)]
+            let mean = offsets.iter().sum::<f64>() / offsets.len() as f64;
+            assert!((mean - 0.038).abs() < 0.01);
+        }
+        let c1 = encrypt(p1, "nile").unwrap();
+
+        assert!((coincidence_test("abcd", "abxdef") - 0.75).abs() < 1e-10);
+        assert!(coincidence_test(&c1, "").abs() < 1e-10);
+        assert!(coincidence_test("123", &c1).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_two_time_attack_failures() {
+        let c1 = encrypt(OZYMANDIAS, "nile").unwrap();
+        assert_eq!(two_time_attack(&c1, &c1[1..], 10), None);
+
+        let mut rng = utils::Rng::new(6);
+        let random: String = (0..200)
+            .map(|_| char::from(b'a' + u8::try_from(rng.below(26)).unwrap()))
+            .collect();
+        let reversed: String = random.chars().rev().collect();
+        assert_eq!(two_time_attack(&random, &reversed, 10), None);
+    }
+
+    #[test]
+    fn test_key_from_two_ciphertexts() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        let (p1, p2) = normalized.split_at(normalized.len() / 2);
+        let c1 = encrypt(p1, "temple").unwrap();
+        let c2 = encrypt(p2, "temple").unwrap();
+        for fragment in ["I met a traveller from", "I met a", "Imet"] {
+            let key = key_from_two_ciphertexts(&c1, fragment, &c2);
+            assert_eq!(key.as_deref(), Some("temple"));
+        }
+        assert_eq!(decrypt(&c2, "temple").unwrap(), p2);
+
+        assert_eq!(key_from_two_ciphertexts(&c1, "", &c2), None);
+        assert_eq!(
+            key_from_two_ciphertexts(&c1[..5], "I met a traveller", &c2),
+            None
+        );
+        // No key length repeats the key letters from this wrong fragment.
+        assert_eq!(key_from_two_ciphertexts(&c1, &p2[..40], &c2), None);
+    }
+
+    #[test]
+    fn test_autokey() {
+        let original = "The quick brown fox jumps over the lazy dog";
+        let normalized = LowercaseString::normalize(original).to_string();
+        let encrypted = autokey_encrypt(original, "secret").unwrap();
+        assert_ne!(Some(encrypted.clone()), encrypt(original, "secret"));
+        assert_eq!(autokey_decrypt(&encrypted, "secret"), Some(normalized));
+
+        assert_eq!(autokey_encrypt(original, ""), None);
+        assert_eq!(autokey_decrypt(original, ""), None);
+    }
+
+    #[test]
+    fn test_autokey_solve() {
+        let normalized = LowercaseString::normalize(OZYMANDIAS).to_string();
+        for primer in ["k", "nile", "shelley"] {
+            let encrypted = autokey_encrypt(OZYMANDIAS, primer).unwrap();
+            let expected = (normalized.clone(), primer.to_string());
+            assert_eq!(autokey_solve(&encrypted, 10), Some(expected));
+        }
+        assert_eq!(autokey_solve("", 10), None);
+        assert_eq!(autokey_solve("abc", 0), None);
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        assert_eq!(
+            solve("The quick brown fox jumps over the lazy dog", 12),
+            Err(VigenereError::TextTooShort(60))
+        );
+
+        let no_repeats: String = (0..100u32)
+            .map(|i| char::from(b'a' + u8::try_from(i * i % 26 + i / 26).unwrap() % 26))
+            .collect();
+        assert_eq!(
+            solve(&no_repeats, 12),
+            Err(VigenereError::NoRepeatedSubstrings)
+        );
+
+        let mixed = encrypt(OZYMANDIAS, "keys").unwrap() + &encrypt(OZYMANDIAS, "fives").unwrap();
+        assert!(matches!(
+            solve(&mixed, 10),
+            Err(VigenereError::AmbiguousKeyLength((4, _), (5, _)))
+        ));
+    }
+
+    #[test]
+    fn test_custom_alphabet() {
+        let alphabet = "abcdefghijklmnopqrstuvwxyz0123456789";
+        let encrypted = encrypt_custom_alphabet("meet at 10pm", "k3y", alphabet).unwrap();
+        assert_eq!(encrypted, "w7233hbtdw");
+        let decrypted = decrypt_custom_alphabet(&encrypted, "k3y", alphabet).unwrap();
+        assert_eq!(decrypted, "meetat10pm");
+
+        let lowercase = "abcdefghijklmnopqrstuvwxyz";
+        assert_eq!(
+            encrypt_custom_alphabet("hello world", "key", lowercase),
+            encrypt("hello world", "key").ok_or(VigenereError::EmptyKeyword)
+        );
+    }
+
+    #[test]
+    fn test_custom_alphabet_errors() {
+        assert_eq!(
+            encrypt_custom_alphabet("hello", "key", "abcdefghijklmnopqrstuvwxyza"),
+            Err(VigenereError::DuplicateAlphabetChar('a'))
+        );
+        assert_eq!(
+            decrypt_custom_alphabet("12345", "key", "0123456789"),
+            Err(VigenereError::EmptyKeyword)
+        );
+    }
+}
+
+#[cfg(test)]
+mod prop_roundtrip {
+    use super::*;
+    use crate::test_data::{random_keyword, random_text};
+    use crate::utils::Rng;
+
+    #[test]
+    fn encrypt_table_matches_encrypt() {
+        let mut rng = Rng::new(5);
+        for _ in 0..1000 {
+            let plain = random_text(&mut rng, 50);
+            let keyword = random_keyword(&mut rng, 10);
+            assert_eq!(encrypt_table(&plain, &keyword), encrypt(&plain, &keyword));
+        }
+    }
+
+    #[test]
+    fn encrypt_decrypt() {
+        let mut rng = Rng::new(2);
+        for _ in 0..1000 {
+            let plain = random_text(&mut rng, 50);
+            let keyword = random_keyword(&mut rng, 10);
+            let encrypted = encrypt(&plain, &keyword).unwrap();
+            assert_eq!(
+                decrypt(&encrypted, &keyword),
+                Some(LowercaseString::normalize(&plain).to_string())
+            );
+        }
     }
 }